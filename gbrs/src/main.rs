@@ -1,20 +1,42 @@
+use std::fs;
+use std::path::Path;
+use std::process;
+use std::time::{Duration, Instant};
+
 use clap::{App, Arg};
 use winit::{
+    dpi::LogicalSize,
     event::*,
     event_loop::{ControlFlow, EventLoop},
     window::WindowBuilder,
 };
 
+use libdmg::cartridge::CartridgeHeader;
 use libdmg::cpu;
+use libdmg::model::Model;
+use libdmg::ppu::{Accuracy, Palette, SCREEN_HEIGHT, SCREEN_WIDTH};
+
+mod display;
+mod save;
+
+use display::Display;
+use save::SaveFile;
+
+// How many times bigger than the Game Boy's screen the window starts out
+const WINDOW_SCALE: u32 = 3;
+
+// How often battery backed RAM is written out while running. The loop isn't paced to the
+// emulated frame rate, so this goes by the clock rather than by frames
+const SAVE_INTERVAL: Duration = Duration::from_secs(5);
 
 pub fn main() {
     env_logger::init();
 
     // Get information from Cargo.toml
-    const NAME: &'static str = env!("CARGO_PKG_NAME");
-    const VERSION: &'static str = env!("CARGO_PKG_VERSION");
-    const AUTHORS: &'static str = env!("CARGO_PKG_AUTHORS");
-    const DESCRIPTION: &'static str = env!("CARGO_PKG_DESCRIPTION");
+    const NAME: &str = env!("CARGO_PKG_NAME");
+    const VERSION: &str = env!("CARGO_PKG_VERSION");
+    const AUTHORS: &str = env!("CARGO_PKG_AUTHORS");
+    const DESCRIPTION: &str = env!("CARGO_PKG_DESCRIPTION");
 
     // Argument parsing
     let app = App::new(NAME)
@@ -37,24 +59,148 @@ pub fn main() {
                 .short('d')
                 .long("debug")
                 .help("Display debug info"),
+        )
+        .arg(
+            Arg::with_name("model")
+                .short('m')
+                .long("model")
+                .value_name("MODEL")
+                .takes_value(true)
+                .possible_values(["dmg0", "dmg", "mgb", "sgb", "sgb2", "cgb", "agb"])
+                .default_value("dmg")
+                .help("Game Boy model to emulate"),
+        )
+        .arg(
+            Arg::with_name("boot-rom")
+                .short('b')
+                .long("boot-rom")
+                .value_name("FILE")
+                .takes_value(true)
+                .help("Boot ROM to run before the game, for the selected model"),
+        )
+        .arg(
+            Arg::with_name("pixel-fifo")
+                .long("pixel-fifo")
+                .help("Draw a pixel at a time, for games that change the picture mid-line"),
+        )
+        .arg(
+            Arg::with_name("save-dir")
+                .long("save-dir")
+                .value_name("DIR")
+                .takes_value(true)
+                .help("Directory to keep save files in, instead of next to the ROM"),
         );
 
     let matches = app.get_matches();
 
     let verbose = matches.is_present("verbose");
-    let debug = matches.is_present("debug");
+    let _debug = matches.is_present("debug");
 
-    let cpu = cpu::CPU::default();
+    let path = matches.value_of("FILE").unwrap();
+    let save_dir = matches.value_of("save-dir").map(Path::new);
+    let model = Model::from_name(matches.value_of("model").unwrap()).unwrap();
+    let rom = match fs::read(path) {
+        Ok(rom) => rom,
+        Err(error) => {
+            eprintln!("Failed to read {}: {}", path, error);
+            process::exit(1);
+        }
+    };
+
+    let header = match CartridgeHeader::parse(&rom) {
+        Ok(header) => header,
+        Err(error) => {
+            eprintln!("Failed to load {}: {}", path, error);
+            process::exit(1);
+        }
+    };
+    // Real hardware only checks the logo and header checksum, so let bad ROMs run anyway
+    if let Err(error) = header.validate(&rom) {
+        eprintln!("Warning: {}", error);
+    }
+    if verbose {
+        println!(
+            "{} ({}, {} ROM banks, {} bytes RAM)",
+            header.title,
+            header.cartridge_type,
+            header.rom_banks(),
+            header.ram_size
+        );
+    }
+
+    let mut cpu = cpu::CPU::default();
+    if let Err(error) = cpu.load_rom(rom) {
+        eprintln!("Failed to load {}: {}", path, error);
+        process::exit(1);
+    }
+    if matches.is_present("pixel-fifo") {
+        cpu.bus_mut().ppu_mut().set_accuracy(Accuracy::PixelFifo);
+    }
+    // Without a boot ROM, start at the game as if one had just run
+    match matches.value_of("boot-rom") {
+        Some(boot_rom_path) => {
+            let boot_rom = match fs::read(boot_rom_path) {
+                Ok(boot_rom) => boot_rom,
+                Err(error) => {
+                    eprintln!("Failed to read {}: {}", boot_rom_path, error);
+                    process::exit(1);
+                }
+            };
+            if let Err(error) = cpu.load_boot_rom(model, boot_rom) {
+                eprintln!("Failed to load {}: {}", boot_rom_path, error);
+                process::exit(1);
+            }
+        }
+        None => cpu.skip_boot(model),
+    }
+    // There's no force feedback in the window, so just report the motor
+    if let (true, Some(cartridge)) = (verbose, cpu.bus_mut().cartridge_mut()) {
+        cartridge.set_rumble_callback(|rumble| match rumble {
+            true => println!("Rumble on"),
+            false => println!("Rumble off"),
+        });
+    }
+
+    let mut save_file = None;
+    if let Some(cartridge) = cpu.bus_mut().cartridge_mut() {
+        if cartridge.has_battery() {
+            let mut file = SaveFile::new(Path::new(path), save_dir);
+            // A save that can't be loaded would be overwritten, so don't go any further
+            if let Err(error) = file.load(cartridge) {
+                eprintln!("Failed to load {}: {}", file.path().display(), error);
+                process::exit(1);
+            }
+            if verbose {
+                println!("Saving to {}", file.path().display());
+            }
+            save_file = Some(file);
+        }
+    }
+    let mut last_save = Instant::now();
 
     let event_loop = EventLoop::new();
-    let window = WindowBuilder::new().build(&event_loop).unwrap();
-    window.set_title("RustBoy");
+    let window = WindowBuilder::new()
+        .with_title("RustBoy")
+        .with_inner_size(LogicalSize::new(
+            SCREEN_WIDTH as u32 * WINDOW_SCALE,
+            SCREEN_HEIGHT as u32 * WINDOW_SCALE,
+        ))
+        .build(&event_loop)
+        .unwrap();
+    let mut display = match Display::new(window) {
+        Ok(display) => display,
+        Err(error) => {
+            eprintln!("Failed to set up the display: {}", error);
+            process::exit(1);
+        }
+    };
 
+    #[allow(clippy::collapsible_match)]
     event_loop.run(move |event, _, control_flow| match event {
         Event::WindowEvent {
             ref event,
             window_id,
-        } if window_id == window.id() => match event {
+        } if window_id == display.window().id() => match event {
             WindowEvent::CloseRequested
             | WindowEvent::KeyboardInput {
                 input:
@@ -65,10 +211,42 @@ pub fn main() {
                     },
                 ..
             } => *control_flow = ControlFlow::Exit,
+            WindowEvent::Resized(size) => display.resize(size.width, size.height),
+            WindowEvent::ScaleFactorChanged { new_inner_size, .. } => {
+                display.resize(new_inner_size.width, new_inner_size.height)
+            }
             _ => {}
         },
-        _ => {
-            // Main loop code goes here
+        Event::MainEventsCleared => {
+            if let Err(error) = cpu.run_cycles(cpu::CYCLES_PER_FRAME) {
+                eprintln!("{}", error);
+                *control_flow = ControlFlow::Exit;
+            }
+            // Nothing new is finished while the LCD is off, so the last picture stays up
+            if let Some(frame) = cpu.bus_mut().ppu_mut().take_frame() {
+                display.update(&frame, Palette::default());
+                display.window().request_redraw();
+            }
+            if last_save.elapsed() >= SAVE_INTERVAL {
+                flush(&mut cpu, &mut save_file);
+                last_save = Instant::now();
+            }
+        }
+        Event::RedrawRequested(window_id) if window_id == display.window().id() => {
+            if let Err(error) = display.render() {
+                eprintln!("Failed to draw the frame: {}", error);
+                *control_flow = ControlFlow::Exit;
+            }
         }
+        Event::LoopDestroyed => flush(&mut cpu, &mut save_file),
+        _ => {}
     });
 }
+
+fn flush(cpu: &mut cpu::CPU, save_file: &mut Option<SaveFile>) {
+    if let (Some(file), Some(cartridge)) = (save_file, cpu.bus_mut().cartridge_mut()) {
+        if let Err(error) = file.flush(cartridge) {
+            eprintln!("Failed to save {}: {}", file.path().display(), error);
+        }
+    }
+}
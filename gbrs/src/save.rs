@@ -0,0 +1,161 @@
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use libdmg::cartridge::Cartridge;
+
+// Where a battery backed cartridge's RAM is kept between runs, next to the ROM unless another
+// directory is given
+#[derive(Debug)]
+pub struct SaveFile {
+    path: PathBuf,
+    // What was last read or written, so unchanged saves aren't written again
+    saved: Vec<u8>,
+}
+
+impl SaveFile {
+    pub fn new(rom_path: &Path, save_dir: Option<&Path>) -> SaveFile {
+        let path = match (save_dir, rom_path.file_name()) {
+            (Some(dir), Some(name)) => dir.join(name).with_extension("sav"),
+            _ => rom_path.with_extension("sav"),
+        };
+        SaveFile {
+            path,
+            saved: Vec::new(),
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    // Loads the save into the cartridge, leaving it untouched if there isn't one yet
+    pub fn load(&mut self, cartridge: &mut Cartridge) -> Result<(), String> {
+        let data = match fs::read(&self.path) {
+            Ok(data) => data,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(()),
+            Err(error) => return Err(error.to_string()),
+        };
+        cartridge
+            .load_save_data(&data)
+            .map_err(|error| error.to_string())?;
+        self.saved = data;
+        Ok(())
+    }
+
+    // Writes the cartridge's RAM out if it has changed since it was last saved
+    pub fn flush(&mut self, cartridge: &mut Cartridge) -> io::Result<()> {
+        let data = cartridge.save_data();
+        if data == self.saved {
+            return Ok(());
+        }
+        write_atomic(&self.path, &data)?;
+        self.saved = data;
+        Ok(())
+    }
+}
+
+// Writes to a temporary file and renames it over the old save, so a crash part way through leaves
+// the old save intact rather than a truncated one
+fn write_atomic(path: &Path, data: &[u8]) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let temp_path = path.with_extension("sav.tmp");
+    let mut file = fs::File::create(&temp_path)?;
+    file.write_all(data)?;
+    file.sync_all()?;
+    fs::rename(&temp_path, path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A fresh directory for each test, removed when it's done
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> TempDir {
+            let dir = std::env::temp_dir().join(format!("gbrs-{}-{}", name, std::process::id()));
+            let _ = fs::remove_dir_all(&dir);
+            fs::create_dir_all(&dir).unwrap();
+            TempDir(dir)
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    // MBC1 with 8KiB of battery backed RAM
+    fn cartridge() -> Cartridge {
+        let mut rom = vec![0; 0x8000];
+        rom[0x0147] = 0x03;
+        rom[0x0149] = 0x02;
+        Cartridge::new(rom).unwrap()
+    }
+
+    #[test]
+    fn test_path() {
+        let rom_path = Path::new("roms/game.gb");
+        assert_eq!(
+            Path::new("roms/game.sav"),
+            SaveFile::new(rom_path, None).path()
+        );
+        assert_eq!(
+            Path::new("saves/game.sav"),
+            SaveFile::new(rom_path, Some(Path::new("saves"))).path()
+        );
+    }
+
+    #[test]
+    fn test_load_missing() {
+        let dir = TempDir::new("load-missing");
+        let mut cartridge = cartridge();
+        let mut file = SaveFile::new(&dir.0.join("game.gb"), None);
+
+        assert_eq!(Ok(()), file.load(&mut cartridge));
+        assert!(cartridge.ram().iter().all(|byte| *byte == 0));
+        assert!(!file.path().exists());
+    }
+
+    #[test]
+    fn test_flush() {
+        let dir = TempDir::new("flush");
+        let mut cartridge = cartridge();
+        let mut file = SaveFile::new(&dir.0.join("game.gb"), Some(&dir.0.join("saves")));
+
+        cartridge.ram_mut()[0] = 0xAA;
+        file.flush(&mut cartridge).unwrap();
+        assert_eq!(cartridge.ram(), fs::read(file.path()).unwrap());
+
+        // Nothing is written while the RAM stays the same
+        fs::remove_file(file.path()).unwrap();
+        file.flush(&mut cartridge).unwrap();
+        assert!(!file.path().exists());
+
+        cartridge.ram_mut()[0] = 0xBB;
+        file.flush(&mut cartridge).unwrap();
+        let mut loaded = self::cartridge();
+        SaveFile::new(&dir.0.join("game.gb"), Some(&dir.0.join("saves")))
+            .load(&mut loaded)
+            .unwrap();
+        assert_eq!(0xBB, loaded.ram()[0]);
+    }
+
+    #[test]
+    fn test_write_atomic_failure() {
+        let dir = TempDir::new("write-atomic");
+        let path = dir.0.join("game.sav");
+        write_atomic(&path, &[0xAA]).unwrap();
+        assert!(!path.with_extension("sav.tmp").exists());
+
+        // A failed write leaves the old save alone
+        fs::create_dir(path.with_extension("sav.tmp")).unwrap();
+        assert!(write_atomic(&path, &[0xBB]).is_err());
+        assert_eq!(vec![0xAA], fs::read(&path).unwrap());
+    }
+}
@@ -0,0 +1,58 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+
+use libdmg::cpu::CPU;
+
+// A loop mixing loads, ALU, CB-prefixed, stack and branch instructions
+const PROGRAM: [(u16, &[u8]); 2] = [
+    (
+        0x0000,
+        &[
+            0x31, 0xFE, 0xFF, // LD SP, 0xFFFE
+            0x21, 0x00, 0xC0, // LD HL, 0xC000
+            0x3E, 0x12, // LD A, 0x12
+            0x77, // LD (HL), A
+            0xCB, 0x37, // SWAP A
+            0x86, // ADD A, (HL)
+            0xCD, 0x20, 0x00, // CALL 0x0020
+            0x05, // DEC B
+            0x20, 0xF6, // JR NZ, -10
+            0xC3, 0x08, 0x00, // JP 0x0008
+        ],
+    ),
+    (
+        0x0020,
+        &[
+            0xC5, // PUSH BC
+            0xA9, // XOR A, C
+            0xC1, // POP BC
+            0xC9, // RET
+        ],
+    ),
+];
+
+const STEPS: u64 = 10_000;
+
+fn bench_instructions(c: &mut Criterion) {
+    let mut rom = vec![0x00; 0x8000];
+    for (start, bytes) in PROGRAM {
+        let start = start as usize;
+        rom[start..start + bytes.len()].copy_from_slice(bytes);
+    }
+
+    let mut cpu = CPU::default();
+    cpu.load_rom(rom).unwrap();
+
+    let mut group = c.benchmark_group("instructions");
+    group.throughput(Throughput::Elements(STEPS));
+    group.bench_function("step", |b| {
+        b.iter(|| {
+            for _ in 0..STEPS {
+                black_box(cpu.step().unwrap());
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_instructions);
+criterion_main!(benches);
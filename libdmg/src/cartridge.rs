@@ -0,0 +1,863 @@
+use std::fmt;
+
+use crate::data::Address;
+use crate::error::CartridgeError;
+use crate::memory::OPEN_BUS;
+
+mod huc1;
+mod huc3;
+mod mbc1;
+mod mbc2;
+mod mbc3;
+mod mbc5;
+mod mbc6;
+mod mbc7;
+mod mmm01;
+mod rom_only;
+mod rtc;
+
+use huc1::HuC1;
+use huc3::HuC3;
+use mbc1::Mbc1;
+use mbc2::Mbc2;
+use mbc3::Mbc3;
+use mbc5::Mbc5;
+use mbc6::Mbc6;
+use mbc7::Mbc7;
+use mmm01::Mmm01;
+use rom_only::RomOnly;
+pub use rtc::{Rtc, RtcClock, RTC_FOOTER_SIZE};
+
+// The header sits between the entry point and the start of the program proper
+const LOGO_START: usize = 0x0104;
+const LOGO_END: usize = 0x0134;
+const TITLE_START: usize = 0x0134;
+const MANUFACTURER_CODE_START: usize = 0x013F;
+const CGB_FLAG: usize = 0x0143;
+const NEW_LICENSEE_CODE: usize = 0x0144;
+const SGB_FLAG: usize = 0x0146;
+const CARTRIDGE_TYPE: usize = 0x0147;
+const ROM_SIZE: usize = 0x0148;
+const RAM_SIZE: usize = 0x0149;
+const OLD_LICENSEE_CODE: usize = 0x014B;
+const VERSION: usize = 0x014C;
+const HEADER_CHECKSUM: usize = 0x014D;
+const GLOBAL_CHECKSUM: usize = 0x014E;
+pub const HEADER_END: usize = 0x0150;
+
+pub const ROM_BANK_SIZE: usize = 0x4000;
+pub const RAM_BANK_SIZE: usize = 0x2000;
+
+// Old licensee code saying the new licensee code at 0x0144 should be used instead
+const USE_NEW_LICENSEE_CODE: u8 = 0x33;
+
+// The boot ROM refuses to start a cartridge unless this is at 0x0104
+pub const NINTENDO_LOGO: [u8; LOGO_END - LOGO_START] = [
+    0xCE, 0xED, 0x66, 0x66, 0xCC, 0x0D, 0x00, 0x0B, 0x03, 0x73, 0x00, 0x83, 0x00, 0x0C, 0x00, 0x0D,
+    0x00, 0x08, 0x11, 0x1F, 0x88, 0x89, 0x00, 0x0E, 0xDC, 0xCC, 0x6E, 0xE6, 0xDD, 0xDD, 0xD9, 0x99,
+    0xBB, 0xBB, 0x67, 0x63, 0x6E, 0x0E, 0xEC, 0xCC, 0xDD, 0xDC, 0x99, 0x9F, 0xBB, 0xB9, 0x33, 0x3E,
+];
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CgbSupport {
+    // Plain DMG game
+    None,
+    // Runs on both, with colour on a CGB (0x80)
+    Supported,
+    // Refuses to run on a DMG (0xC0)
+    Only,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Mapper {
+    RomOnly,
+    Mbc1,
+    Mbc2,
+    Mmm01,
+    Mbc3,
+    Mbc5,
+    Mbc6,
+    Mbc7,
+    PocketCamera,
+    Tama5,
+    HuC3,
+    HuC1,
+}
+
+impl fmt::Display for Mapper {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Mapper::RomOnly => "ROM",
+            Mapper::Mbc1 => "MBC1",
+            Mapper::Mbc2 => "MBC2",
+            Mapper::Mmm01 => "MMM01",
+            Mapper::Mbc3 => "MBC3",
+            Mapper::Mbc5 => "MBC5",
+            Mapper::Mbc6 => "MBC6",
+            Mapper::Mbc7 => "MBC7",
+            Mapper::PocketCamera => "POCKET CAMERA",
+            Mapper::Tama5 => "BANDAI TAMA5",
+            Mapper::HuC3 => "HuC3",
+            Mapper::HuC1 => "HuC1",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+// The mapper chip and whatever else is on the board, decoded from the byte at 0x0147
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CartridgeType {
+    pub code: u8,
+    pub mapper: Mapper,
+    pub ram: bool,
+    pub battery: bool,
+    pub timer: bool,
+    pub rumble: bool,
+    pub sensor: bool,
+}
+
+impl CartridgeType {
+    pub fn from_code(code: u8) -> Option<CartridgeType> {
+        // Named the same way as the cartridge type table in Pan Docs
+        let (mapper, features) = match code {
+            0x00 => (Mapper::RomOnly, ""),
+            0x01 => (Mapper::Mbc1, ""),
+            0x02 => (Mapper::Mbc1, "RAM"),
+            0x03 => (Mapper::Mbc1, "RAM+BATTERY"),
+            0x05 => (Mapper::Mbc2, ""),
+            0x06 => (Mapper::Mbc2, "BATTERY"),
+            0x08 => (Mapper::RomOnly, "RAM"),
+            0x09 => (Mapper::RomOnly, "RAM+BATTERY"),
+            0x0B => (Mapper::Mmm01, ""),
+            0x0C => (Mapper::Mmm01, "RAM"),
+            0x0D => (Mapper::Mmm01, "RAM+BATTERY"),
+            0x0F => (Mapper::Mbc3, "TIMER+BATTERY"),
+            0x10 => (Mapper::Mbc3, "TIMER+RAM+BATTERY"),
+            0x11 => (Mapper::Mbc3, ""),
+            0x12 => (Mapper::Mbc3, "RAM"),
+            0x13 => (Mapper::Mbc3, "RAM+BATTERY"),
+            0x19 => (Mapper::Mbc5, ""),
+            0x1A => (Mapper::Mbc5, "RAM"),
+            0x1B => (Mapper::Mbc5, "RAM+BATTERY"),
+            0x1C => (Mapper::Mbc5, "RUMBLE"),
+            0x1D => (Mapper::Mbc5, "RUMBLE+RAM"),
+            0x1E => (Mapper::Mbc5, "RUMBLE+RAM+BATTERY"),
+            0x20 => (Mapper::Mbc6, ""),
+            0x22 => (Mapper::Mbc7, "SENSOR+RUMBLE+RAM+BATTERY"),
+            0xFC => (Mapper::PocketCamera, "RAM+BATTERY"),
+            0xFD => (Mapper::Tama5, ""),
+            0xFE => (Mapper::HuC3, "TIMER+RAM+BATTERY"),
+            0xFF => (Mapper::HuC1, "RAM+BATTERY"),
+            _ => return None,
+        };
+
+        Some(CartridgeType {
+            code,
+            mapper,
+            ram: features.contains("RAM"),
+            battery: features.contains("BATTERY"),
+            timer: features.contains("TIMER"),
+            rumble: features.contains("RUMBLE"),
+            sensor: features.contains("SENSOR"),
+        })
+    }
+}
+
+impl fmt::Display for CartridgeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.mapper)?;
+        let features = [
+            (self.sensor, "SENSOR"),
+            (self.rumble, "RUMBLE"),
+            (self.timer, "TIMER"),
+            (self.ram, "RAM"),
+            (self.battery, "BATTERY"),
+        ];
+        for (present, name) in features {
+            if present {
+                write!(f, "+{}", name)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CartridgeHeader {
+    pub title: String,
+    // Only in later CGB games, which shortened the title to make room for it
+    pub manufacturer_code: Option<String>,
+    pub cgb_support: CgbSupport,
+    pub sgb_support: bool,
+    pub cartridge_type: CartridgeType,
+    pub rom_size_code: u8,
+    pub rom_size: usize,
+    pub ram_size_code: u8,
+    pub ram_size: usize,
+    pub old_licensee_code: u8,
+    // Only used when the old licensee code is 0x33
+    pub new_licensee_code: Option<String>,
+    pub version: u8,
+    pub header_checksum: u8,
+    pub global_checksum: u16,
+}
+
+impl CartridgeHeader {
+    // Decodes the header, failing only if it is too short or uses codes we don't know. Checksums
+    // and the logo are left to validate, since plenty of homebrew and test ROMs get them wrong
+    pub fn parse(rom: &[u8]) -> Result<CartridgeHeader, CartridgeError> {
+        if rom.len() < HEADER_END {
+            return Err(CartridgeError::Truncated { length: rom.len() });
+        }
+
+        let cartridge_type = CartridgeType::from_code(rom[CARTRIDGE_TYPE])
+            .ok_or(CartridgeError::UnknownCartridgeType(rom[CARTRIDGE_TYPE]))?;
+        let rom_size_code = rom[ROM_SIZE];
+        let rom_size =
+            rom_size(rom_size_code).ok_or(CartridgeError::UnknownRomSize(rom_size_code))?;
+        let ram_size_code = rom[RAM_SIZE];
+        let ram_size =
+            ram_size(ram_size_code).ok_or(CartridgeError::UnknownRamSize(ram_size_code))?;
+
+        let cgb_support = match rom[CGB_FLAG] {
+            0xC0 => CgbSupport::Only,
+            flag if flag & 0x80 != 0 => CgbSupport::Supported,
+            _ => CgbSupport::None,
+        };
+
+        // Early CGB games still have a 15 character title, so only treat the end of it as a
+        // manufacturer code if it looks like one
+        let manufacturer_code = &rom[MANUFACTURER_CODE_START..CGB_FLAG];
+        let (title_end, manufacturer_code) = match cgb_support {
+            CgbSupport::None => (CGB_FLAG + 1, None),
+            _ if manufacturer_code
+                .iter()
+                .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit()) =>
+            {
+                (MANUFACTURER_CODE_START, Some(ascii(manufacturer_code)))
+            }
+            _ => (CGB_FLAG, None),
+        };
+
+        let old_licensee_code = rom[OLD_LICENSEE_CODE];
+        let new_licensee_code = if old_licensee_code == USE_NEW_LICENSEE_CODE {
+            Some(ascii(&rom[NEW_LICENSEE_CODE..SGB_FLAG]))
+        } else {
+            None
+        };
+
+        Ok(CartridgeHeader {
+            title: ascii(&rom[TITLE_START..title_end]),
+            manufacturer_code,
+            cgb_support,
+            sgb_support: rom[SGB_FLAG] == 0x03,
+            cartridge_type,
+            rom_size_code,
+            rom_size,
+            ram_size_code,
+            ram_size,
+            old_licensee_code,
+            new_licensee_code,
+            version: rom[VERSION],
+            header_checksum: rom[HEADER_CHECKSUM],
+            global_checksum: u16::from_be_bytes([rom[GLOBAL_CHECKSUM], rom[GLOBAL_CHECKSUM + 1]]),
+        })
+    }
+
+    // Checks rom, which this header was parsed from, the way the boot ROM and header tools do
+    pub fn validate(&self, rom: &[u8]) -> Result<(), CartridgeError> {
+        if rom.len() < HEADER_END {
+            return Err(CartridgeError::Truncated { length: rom.len() });
+        }
+
+        if rom[LOGO_START..LOGO_END] != NINTENDO_LOGO {
+            return Err(CartridgeError::InvalidLogo);
+        }
+
+        let actual = header_checksum(rom);
+        if actual != self.header_checksum {
+            return Err(CartridgeError::HeaderChecksumMismatch {
+                expected: self.header_checksum,
+                actual,
+            });
+        }
+
+        if rom.len() < self.rom_size {
+            return Err(CartridgeError::RomSizeMismatch {
+                expected: self.rom_size,
+                actual: rom.len(),
+            });
+        }
+
+        let actual = global_checksum(rom);
+        if actual != self.global_checksum {
+            return Err(CartridgeError::GlobalChecksumMismatch {
+                expected: self.global_checksum,
+                actual,
+            });
+        }
+
+        Ok(())
+    }
+
+    pub fn rom_banks(&self) -> usize {
+        self.rom_size / ROM_BANK_SIZE
+    }
+}
+
+// A memory bank controller, which decodes writes to ROM as its own registers and maps banks of ROM
+// and RAM into 0x0000-0x7FFF and 0xA000-0xBFFF. Addresses are the ones on the bus
+pub(crate) trait Mbc: fmt::Debug {
+    fn read_rom(&self, addr: Address) -> u8;
+    fn write_rom(&mut self, addr: Address, data: u8);
+    fn read_ram(&self, addr: Address) -> u8;
+    fn write_ram(&mut self, addr: Address, data: u8);
+    // Called with the T-cycles passed, for controllers with a clock
+    fn tick(&mut self, _cycles: u8) {}
+    fn rtc(&self) -> Option<&Rtc> {
+        None
+    }
+    fn rtc_mut(&mut self) -> Option<&mut Rtc> {
+        None
+    }
+    // Whether the rumble motor is running
+    fn rumble(&self) -> bool {
+        false
+    }
+    // Feeds the accelerometer, in g along each axis
+    fn set_tilt(&mut self, _x: f32, _y: f32) {}
+    // The external RAM, or whatever the controller keeps saves in instead
+    fn ram(&self) -> &[u8] {
+        &[]
+    }
+    fn ram_mut(&mut self) -> &mut [u8] {
+        &mut []
+    }
+    // Anything else the controller keeps through power off, like a clock or flash, which is saved
+    // after the RAM
+    fn save_footer(&mut self) -> Vec<u8> {
+        Vec::new()
+    }
+    // Restores what save_footer wrote, given a non-empty footer. A size mismatch is reported for
+    // the footer alone
+    fn load_footer(&mut self, footer: &[u8]) -> Result<(), CartridgeError> {
+        Err(CartridgeError::SaveSizeMismatch {
+            expected: 0,
+            actual: footer.len(),
+        })
+    }
+}
+
+// Called with the new state whenever the rumble motor is switched on or off
+pub type RumbleCallback = Box<dyn FnMut(bool)>;
+
+// A ROM image along with the controller its header asks for
+pub struct Cartridge {
+    header: CartridgeHeader,
+    mbc: Box<dyn Mbc>,
+    rumble_callback: Option<RumbleCallback>,
+}
+
+impl fmt::Debug for Cartridge {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Cartridge")
+            .field("header", &self.header)
+            .field("mbc", &self.mbc)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Cartridge {
+    pub fn new(rom: Vec<u8>) -> Result<Cartridge, CartridgeError> {
+        let header = match Mmm01::header(&rom) {
+            Some(header) => header,
+            None => CartridgeHeader::parse(&rom)?,
+        };
+        let cartridge_type = header.cartridge_type;
+        let ram_size = match cartridge_type.ram {
+            true => header.ram_size,
+            false => 0,
+        };
+
+        let mbc: Box<dyn Mbc> = match cartridge_type.mapper {
+            Mapper::RomOnly => Box::new(RomOnly::new(rom, ram_size)),
+            Mapper::Mbc1 => {
+                let multicart = Mbc1::is_multicart(&rom);
+                Box::new(Mbc1::new(rom, ram_size, multicart))
+            }
+            Mapper::Mbc2 => Box::new(Mbc2::new(rom)),
+            Mapper::Mbc3 => Box::new(Mbc3::new(rom, ram_size, cartridge_type.timer)),
+            Mapper::Mbc5 => Box::new(Mbc5::new(rom, ram_size, cartridge_type.rumble)),
+            // The only MBC6 game has RAM without saying so in its cartridge type
+            Mapper::Mbc6 => Box::new(Mbc6::new(rom, header.ram_size)),
+            Mapper::Mbc7 => Box::new(Mbc7::new(rom)),
+            Mapper::Mmm01 => Box::new(Mmm01::new(rom, ram_size)),
+            Mapper::HuC1 => Box::new(HuC1::new(rom, ram_size)),
+            Mapper::HuC3 => Box::new(HuC3::new(rom, ram_size)),
+            mapper => return Err(CartridgeError::UnsupportedMapper(mapper)),
+        };
+
+        Ok(Cartridge {
+            header,
+            mbc,
+            rumble_callback: None,
+        })
+    }
+
+    pub fn header(&self) -> &CartridgeHeader {
+        &self.header
+    }
+
+    // The real time clock, on cartridges that have one
+    pub fn rtc(&self) -> Option<&Rtc> {
+        self.mbc.rtc()
+    }
+
+    pub fn rtc_mut(&mut self) -> Option<&mut Rtc> {
+        self.mbc.rtc_mut()
+    }
+
+    pub fn rumble(&self) -> bool {
+        self.mbc.rumble()
+    }
+
+    pub fn set_rumble_callback<F: FnMut(bool) + 'static>(&mut self, callback: F) {
+        self.rumble_callback = Some(Box::new(callback));
+    }
+
+    // Tilts the cartridge for controllers with an accelerometer, x and y are in g with 0 being
+    // level
+    pub fn set_tilt(&mut self, x: f32, y: f32) {
+        self.mbc.set_tilt(x, y);
+    }
+
+    // Whether a battery keeps the RAM and clock going while switched off, so they should be saved.
+    // The MBC6's flash keeps its contents without one
+    pub fn has_battery(&self) -> bool {
+        self.header.cartridge_type.battery || self.header.cartridge_type.mapper == Mapper::Mbc6
+    }
+
+    pub fn ram(&self) -> &[u8] {
+        self.mbc.ram()
+    }
+
+    pub fn ram_mut(&mut self) -> &mut [u8] {
+        self.mbc.ram_mut()
+    }
+
+    // The contents of a save file: the RAM followed by a footer for the clock or flash on
+    // cartridges with one
+    pub fn save_data(&mut self) -> Vec<u8> {
+        let mut data = self.mbc.ram().to_vec();
+        data.extend_from_slice(&self.mbc.save_footer());
+        data
+    }
+
+    // Restores a save file written by save_data or another emulator. The footer is optional, a
+    // clock carries on from its current time without it
+    pub fn load_save_data(&mut self, data: &[u8]) -> Result<(), CartridgeError> {
+        let ram_size = self.mbc.ram().len();
+        if data.len() < ram_size {
+            return Err(CartridgeError::SaveSizeMismatch {
+                expected: ram_size,
+                actual: data.len(),
+            });
+        }
+
+        let (ram, footer) = data.split_at(ram_size);
+        if !footer.is_empty() {
+            self.mbc.load_footer(footer).map_err(|error| match error {
+                CartridgeError::SaveSizeMismatch { expected, actual } => {
+                    CartridgeError::SaveSizeMismatch {
+                        expected: ram_size + expected,
+                        actual: ram_size + actual,
+                    }
+                }
+                error => error,
+            })?;
+        }
+        self.mbc.ram_mut().copy_from_slice(ram);
+        Ok(())
+    }
+
+    pub(crate) fn read_rom(&self, addr: Address) -> u8 {
+        self.mbc.read_rom(addr)
+    }
+
+    pub(crate) fn write_rom(&mut self, addr: Address, data: u8) {
+        let rumble = self.mbc.rumble();
+        self.mbc.write_rom(addr, data);
+        if self.mbc.rumble() != rumble {
+            if let Some(callback) = &mut self.rumble_callback {
+                callback(!rumble);
+            }
+        }
+    }
+
+    pub(crate) fn read_ram(&self, addr: Address) -> u8 {
+        self.mbc.read_ram(addr)
+    }
+
+    pub(crate) fn write_ram(&mut self, addr: Address, data: u8) {
+        self.mbc.write_ram(addr, data);
+    }
+
+    pub(crate) fn tick(&mut self, cycles: u8) {
+        self.mbc.tick(cycles);
+    }
+}
+
+// Reads from a 16KiB ROM bank. Bank numbers past the end of the ROM wrap around, since the
+// controller's upper bank bits aren't connected to anything on smaller boards
+fn read_rom_bank(rom: &[u8], bank: usize, addr: Address) -> u8 {
+    let banks = ((rom.len() + ROM_BANK_SIZE - 1) / ROM_BANK_SIZE).max(1);
+    let offset = (bank % banks) * ROM_BANK_SIZE + (addr as usize & (ROM_BANK_SIZE - 1));
+    *rom.get(offset).unwrap_or(&OPEN_BUS)
+}
+
+// A ROM for the controller tests where every byte of a bank holds the lower byte of its number,
+// apart from the second, which holds the upper byte so banks past 0xFF can be told apart
+#[cfg(test)]
+pub(crate) fn banked_rom(banks: usize, bank_size: usize) -> Vec<u8> {
+    (0..banks)
+        .flat_map(|bank| {
+            let mut data = vec![bank as u8; bank_size];
+            data[1] = (bank >> 8) as u8;
+            data
+        })
+        .collect()
+}
+
+// Where an access to 0xA000-0xBFFF lands in RAM, which is mirrored if it's smaller than the bank
+fn ram_offset(ram: &[u8], bank: usize, addr: Address) -> Option<usize> {
+    match ram.len() {
+        0 => None,
+        len => Some((bank * RAM_BANK_SIZE + (addr as usize & (RAM_BANK_SIZE - 1))) % len),
+    }
+}
+
+fn rom_size(code: u8) -> Option<usize> {
+    match code {
+        0x00..=0x08 => Some((ROM_BANK_SIZE * 2) << code),
+        0x52 => Some(ROM_BANK_SIZE * 72),
+        0x53 => Some(ROM_BANK_SIZE * 80),
+        0x54 => Some(ROM_BANK_SIZE * 96),
+        _ => None,
+    }
+}
+
+fn ram_size(code: u8) -> Option<usize> {
+    match code {
+        0x00 => Some(0),
+        // Listed by some docs but never used by a released game
+        0x01 => Some(0x800),
+        0x02 => Some(0x2000),
+        0x03 => Some(0x8000),
+        0x04 => Some(0x20000),
+        0x05 => Some(0x10000),
+        _ => None,
+    }
+}
+
+// The boot ROM locks up unless this matches the byte at 0x014D
+fn header_checksum(rom: &[u8]) -> u8 {
+    rom[TITLE_START..HEADER_CHECKSUM]
+        .iter()
+        .fold(0u8, |sum, byte| sum.wrapping_sub(*byte).wrapping_sub(1))
+}
+
+// Sum of every byte in the ROM except the checksum itself, which nothing on the hardware checks
+fn global_checksum(rom: &[u8]) -> u16 {
+    rom.iter()
+        .enumerate()
+        .filter(|(addr, _)| *addr != GLOBAL_CHECKSUM && *addr != GLOBAL_CHECKSUM + 1)
+        .fold(0u16, |sum, (_, byte)| sum.wrapping_add(*byte as u16))
+}
+
+// Header strings are upper case ASCII padded with NULs
+fn ascii(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .take_while(|c| **c != 0)
+        .map(|c| {
+            if c.is_ascii_graphic() || *c == b' ' {
+                *c as char
+            } else {
+                '?'
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use super::*;
+
+    fn fix_checksums(rom: &mut [u8]) {
+        rom[HEADER_CHECKSUM] = header_checksum(rom);
+        let [hi, lo] = global_checksum(rom).to_be_bytes();
+        rom[GLOBAL_CHECKSUM] = hi;
+        rom[GLOBAL_CHECKSUM + 1] = lo;
+    }
+
+    fn test_rom() -> Vec<u8> {
+        let mut rom = vec![0x00; 0x8000];
+        rom[LOGO_START..LOGO_END].copy_from_slice(&NINTENDO_LOGO);
+        rom[TITLE_START..TITLE_START + 4].copy_from_slice(b"TEST");
+        rom[OLD_LICENSEE_CODE] = 0x01;
+        rom[VERSION] = 0x02;
+        fix_checksums(&mut rom);
+        rom
+    }
+
+    #[test]
+    fn test_parse() {
+        let mut rom = test_rom();
+        rom[CARTRIDGE_TYPE] = 0x13;
+        rom[ROM_SIZE] = 0x05;
+        rom[RAM_SIZE] = 0x03;
+        rom[SGB_FLAG] = 0x03;
+
+        let header = CartridgeHeader::parse(&rom).unwrap();
+        assert_eq!("TEST", header.title);
+        assert_eq!(None, header.manufacturer_code);
+        assert_eq!(CgbSupport::None, header.cgb_support);
+        assert_eq!(true, header.sgb_support);
+        assert_eq!(Mapper::Mbc3, header.cartridge_type.mapper);
+        assert_eq!(true, header.cartridge_type.ram);
+        assert_eq!(true, header.cartridge_type.battery);
+        assert_eq!(false, header.cartridge_type.timer);
+        assert_eq!("MBC3+RAM+BATTERY", header.cartridge_type.to_string());
+        assert_eq!(0x100000, header.rom_size);
+        assert_eq!(64, header.rom_banks());
+        assert_eq!(0x8000, header.ram_size);
+        assert_eq!(0x01, header.old_licensee_code);
+        assert_eq!(None, header.new_licensee_code);
+        assert_eq!(0x02, header.version);
+    }
+
+    #[test]
+    fn test_parse_cgb() {
+        let mut rom = test_rom();
+        rom[TITLE_START..CGB_FLAG].copy_from_slice(b"POKEMON_SLVAAXE");
+        rom[CGB_FLAG] = 0x80;
+        rom[OLD_LICENSEE_CODE] = USE_NEW_LICENSEE_CODE;
+        rom[NEW_LICENSEE_CODE..SGB_FLAG].copy_from_slice(b"01");
+
+        let header = CartridgeHeader::parse(&rom).unwrap();
+        assert_eq!("POKEMON_SLV", header.title);
+        assert_eq!(Some("AAXE".to_string()), header.manufacturer_code);
+        assert_eq!(CgbSupport::Supported, header.cgb_support);
+        assert_eq!(Some("01".to_string()), header.new_licensee_code);
+
+        // An older CGB title running into the flag
+        rom[TITLE_START..CGB_FLAG].copy_from_slice(b"LONG CGB TITLE!");
+        rom[CGB_FLAG] = 0xC0;
+        let header = CartridgeHeader::parse(&rom).unwrap();
+        assert_eq!("LONG CGB TITLE!", header.title);
+        assert_eq!(None, header.manufacturer_code);
+        assert_eq!(CgbSupport::Only, header.cgb_support);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Err(CartridgeError::Truncated { length: 0x100 }),
+            CartridgeHeader::parse(&[0x00; 0x100])
+        );
+
+        let mut rom = test_rom();
+        rom[CARTRIDGE_TYPE] = 0x04;
+        assert_eq!(
+            Err(CartridgeError::UnknownCartridgeType(0x04)),
+            CartridgeHeader::parse(&rom)
+        );
+
+        let mut rom = test_rom();
+        rom[ROM_SIZE] = 0x09;
+        assert_eq!(
+            Err(CartridgeError::UnknownRomSize(0x09)),
+            CartridgeHeader::parse(&rom)
+        );
+
+        let mut rom = test_rom();
+        rom[RAM_SIZE] = 0x06;
+        assert_eq!(
+            Err(CartridgeError::UnknownRamSize(0x06)),
+            CartridgeHeader::parse(&rom)
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        let rom = test_rom();
+        let header = CartridgeHeader::parse(&rom).unwrap();
+        assert_eq!(Ok(()), header.validate(&rom));
+
+        let mut bad_logo = rom.clone();
+        bad_logo[LOGO_START] = 0x00;
+        assert_eq!(Err(CartridgeError::InvalidLogo), header.validate(&bad_logo));
+
+        let mut bad_header = rom.clone();
+        bad_header[VERSION] = 0x03;
+        let header = CartridgeHeader::parse(&bad_header).unwrap();
+        assert_eq!(
+            Err(CartridgeError::HeaderChecksumMismatch {
+                expected: rom[HEADER_CHECKSUM],
+                actual: rom[HEADER_CHECKSUM].wrapping_sub(1),
+            }),
+            header.validate(&bad_header)
+        );
+
+        let mut bad_global = rom.clone();
+        bad_global[0x4000] = 0x01;
+        let header = CartridgeHeader::parse(&bad_global).unwrap();
+        let expected = header.global_checksum;
+        assert_eq!(
+            Err(CartridgeError::GlobalChecksumMismatch {
+                expected,
+                actual: expected.wrapping_add(1),
+            }),
+            header.validate(&bad_global)
+        );
+
+        let short = &rom[..0x4000];
+        assert_eq!(
+            Err(CartridgeError::RomSizeMismatch {
+                expected: 0x8000,
+                actual: 0x4000,
+            }),
+            header.validate(short)
+        );
+    }
+
+    #[test]
+    fn test_unsupported_mapper() {
+        let mut rom = test_rom();
+        rom[CARTRIDGE_TYPE] = 0xFD;
+
+        assert_eq!(
+            CartridgeError::UnsupportedMapper(Mapper::Tama5),
+            Cartridge::new(rom).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_rumble_callback() {
+        let mut rom = test_rom();
+        rom[CARTRIDGE_TYPE] = 0x1C;
+        let mut cartridge = Cartridge::new(rom).unwrap();
+
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let recorded = events.clone();
+        cartridge.set_rumble_callback(move |rumble| recorded.borrow_mut().push(rumble));
+
+        cartridge.write_rom(0x4000, 0x08);
+        cartridge.write_rom(0x4000, 0x0F);
+        assert_eq!(true, cartridge.rumble());
+        cartridge.write_rom(0x4000, 0x00);
+
+        // Only changes are reported
+        assert_eq!(vec![true, false], *events.borrow());
+    }
+
+    #[test]
+    fn test_save_data() {
+        let mut rom = test_rom();
+        rom[CARTRIDGE_TYPE] = 0x03;
+        rom[RAM_SIZE] = 0x02;
+        let mut cartridge = Cartridge::new(rom).unwrap();
+        assert_eq!(true, cartridge.has_battery());
+
+        cartridge.write_rom(0x0000, 0x0A);
+        cartridge.write_ram(0xA000, 0xAA);
+        let data = cartridge.save_data();
+        assert_eq!(RAM_BANK_SIZE, data.len());
+        assert_eq!(0xAA, data[0]);
+
+        cartridge.ram_mut().fill(0x00);
+        cartridge.load_save_data(&data).unwrap();
+        assert_eq!(0xAA, cartridge.read_ram(0xA000));
+
+        assert_eq!(
+            CartridgeError::SaveSizeMismatch {
+                expected: RAM_BANK_SIZE,
+                actual: RAM_BANK_SIZE + RTC_FOOTER_SIZE
+            },
+            cartridge
+                .load_save_data(&[0; RAM_BANK_SIZE + RTC_FOOTER_SIZE])
+                .unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_save_data_rtc() {
+        let mut rom = test_rom();
+        rom[CARTRIDGE_TYPE] = 0x10;
+        rom[RAM_SIZE] = 0x02;
+        let mut cartridge = Cartridge::new(rom).unwrap();
+
+        let data = cartridge.save_data();
+        assert_eq!(RAM_BANK_SIZE + RTC_FOOTER_SIZE, data.len());
+        cartridge.load_save_data(&data).unwrap();
+
+        // Saves without the clock are fine, but not a partial footer
+        cartridge.load_save_data(&data[..RAM_BANK_SIZE]).unwrap();
+        assert_eq!(
+            CartridgeError::InvalidRtcData { length: 4 },
+            cartridge
+                .load_save_data(&data[..RAM_BANK_SIZE + 4])
+                .unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_save_data_mbc6_flash() {
+        let mut rom = test_rom();
+        rom[CARTRIDGE_TYPE] = 0x20;
+        rom[RAM_SIZE] = 0x03;
+        let mut cartridge = Cartridge::new(rom).unwrap();
+        assert_eq!(true, cartridge.has_battery());
+
+        // The flash follows the RAM
+        let mut data = cartridge.save_data();
+        assert_eq!(0x8000 + 0x100000, data.len());
+        assert_eq!(0xFF, data[0x8000]);
+        data[0x8000] = 0x12;
+        *data.last_mut().unwrap() = 0x34;
+        cartridge.load_save_data(&data).unwrap();
+        assert_eq!(data, cartridge.save_data());
+
+        assert_eq!(
+            CartridgeError::SaveSizeMismatch {
+                expected: 0x8000 + 0x100000,
+                actual: 0x8000 + 4
+            },
+            cartridge.load_save_data(&data[..0x8000 + 4]).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_save_data_huc3_clock() {
+        let mut rom = test_rom();
+        rom[CARTRIDGE_TYPE] = 0xFE;
+        rom[RAM_SIZE] = 0x03;
+        let mut cartridge = Cartridge::new(rom).unwrap();
+
+        // Minutes, days, cycles and the clock's memory follow the RAM
+        let mut data = cartridge.save_data();
+        assert_eq!(0x8000 + 8 + 0x100, data.len());
+        data[0x8000..0x8004].copy_from_slice(&[0x2A, 0x00, 0x03, 0x00]);
+        data[0x8000 + 8] = 0x0F;
+        cartridge.load_save_data(&data).unwrap();
+        assert_eq!(data, cartridge.save_data());
+
+        // Saves without the clock leave it running
+        cartridge.load_save_data(&data[..0x8000]).unwrap();
+        assert_eq!(data, cartridge.save_data());
+    }
+}
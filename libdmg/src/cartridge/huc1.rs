@@ -0,0 +1,106 @@
+use super::{ram_offset, read_rom_bank, Mbc};
+use crate::data::Address;
+use crate::memory::OPEN_BUS;
+
+// Written to 0x0000-0x1FFF to map the infrared port over RAM
+const IR_MODE: u8 = 0x0E;
+
+// Hudson's controller, close to an MBC1 without the large ROM modes but with an infrared port.
+// There's nobody on the other end of the port, so it never sees any light
+#[derive(Debug)]
+pub struct HuC1 {
+    rom: Vec<u8>,
+    ram: Vec<u8>,
+    ir_mode: bool,
+    // Whether the game has switched its own LED on
+    ir_led: bool,
+    rom_bank: u8,
+    ram_bank: u8,
+}
+
+impl HuC1 {
+    pub fn new(rom: Vec<u8>, ram_size: usize) -> HuC1 {
+        HuC1 {
+            rom,
+            ram: vec![0; ram_size],
+            ir_mode: false,
+            ir_led: false,
+            rom_bank: 0x01,
+            ram_bank: 0x00,
+        }
+    }
+}
+
+impl Mbc for HuC1 {
+    fn read_rom(&self, addr: Address) -> u8 {
+        match addr {
+            0x0000..=0x3FFF => read_rom_bank(&self.rom, 0, addr),
+            _ => read_rom_bank(&self.rom, self.rom_bank as usize, addr),
+        }
+    }
+
+    fn write_rom(&mut self, addr: Address, data: u8) {
+        match addr {
+            // Anything else maps RAM, which is always enabled
+            0x0000..=0x1FFF => self.ir_mode = data & 0x0F == IR_MODE,
+            0x2000..=0x3FFF => self.rom_bank = (data & 0x3F).max(0x01),
+            0x4000..=0x5FFF => self.ram_bank = data & 0x03,
+            _ => {}
+        }
+    }
+
+    fn read_ram(&self, addr: Address) -> u8 {
+        if self.ir_mode {
+            // Bit 0 would be set while light is received
+            return 0xC0;
+        }
+        match ram_offset(&self.ram, self.ram_bank as usize, addr) {
+            Some(offset) => self.ram[offset],
+            None => OPEN_BUS,
+        }
+    }
+
+    fn write_ram(&mut self, addr: Address, data: u8) {
+        if self.ir_mode {
+            self.ir_led = data & 0x01 != 0;
+        } else if let Some(offset) = ram_offset(&self.ram, self.ram_bank as usize, addr) {
+            self.ram[offset] = data;
+        }
+    }
+
+    fn ram(&self) -> &[u8] {
+        &self.ram
+    }
+
+    fn ram_mut(&mut self) -> &mut [u8] {
+        &mut self.ram
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
+mod tests {
+    use super::*;
+    use crate::cartridge::{banked_rom, ROM_BANK_SIZE};
+
+    #[test]
+    fn test_huc1() {
+        let mut mbc = HuC1::new(banked_rom(64, ROM_BANK_SIZE), 0x8000);
+
+        mbc.write_rom(0x2000, 0x3F);
+        assert_eq!(0x3F, mbc.read_rom(0x4000));
+
+        mbc.write_rom(0x4000, 0x02);
+        mbc.write_ram(0xA000, 0xAA);
+        assert_eq!(0xAA, mbc.read_ram(0xA000));
+
+        // The IR port replaces RAM until switched back
+        mbc.write_rom(0x0000, IR_MODE);
+        mbc.write_ram(0xA000, 0x01);
+        assert_eq!(true, mbc.ir_led);
+        assert_eq!(0xC0, mbc.read_ram(0xA000));
+
+        mbc.write_rom(0x0000, 0x00);
+        assert_eq!(0xAA, mbc.read_ram(0xA000));
+    }
+}
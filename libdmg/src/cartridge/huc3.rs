@@ -0,0 +1,264 @@
+use super::{ram_offset, read_rom_bank, Mbc};
+use crate::data::Address;
+use crate::error::CartridgeError;
+use crate::memory::OPEN_BUS;
+
+const CYCLES_PER_MINUTE: u32 = 4_194_304 * 60;
+const MINUTES_PER_DAY: u16 = 24 * 60;
+
+// The clock's scratch memory, which also holds the game's alarm settings
+const CLOCK_MEMORY_SIZE: usize = 0x100;
+// Saved after the RAM: minutes and days as 16-bit and the cycles into the current minute as
+// 32-bit little endian values, followed by the scratch memory
+const CLOCK_FOOTER_SIZE: usize = 8 + CLOCK_MEMORY_SIZE;
+
+// What 0xA000-0xBFFF is mapped to, selected by writing to 0x0000-0x1FFF
+const MODE_RAM_READ: u8 = 0x00;
+const MODE_RAM: u8 = 0x0A;
+const MODE_RTC_COMMAND: u8 = 0x0B;
+const MODE_RTC_RESPONSE: u8 = 0x0C;
+const MODE_RTC_SEMAPHORE: u8 = 0x0D;
+const MODE_IR: u8 = 0x0E;
+
+// The clock is a separate chip the game talks to with 4-bit commands, moving time in and out
+// through a small scratch memory of 4-bit cells
+#[derive(Debug, Default)]
+struct Clock {
+    minutes: u16,
+    days: u16,
+    cycles: u32,
+    memory: Vec<u8>,
+    address: u8,
+    // The last command and the value it returned
+    command: u8,
+    response: u8,
+}
+
+impl Clock {
+    fn tick(&mut self, cycles: u8) {
+        self.cycles += cycles as u32;
+        if self.cycles >= CYCLES_PER_MINUTE {
+            self.cycles -= CYCLES_PER_MINUTE;
+            self.minutes += 1;
+            if self.minutes == MINUTES_PER_DAY {
+                self.minutes = 0;
+                self.days = (self.days + 1) & 0xFFF;
+            }
+        }
+    }
+
+    fn save(&self) -> Vec<u8> {
+        let mut footer = Vec::with_capacity(CLOCK_FOOTER_SIZE);
+        footer.extend_from_slice(&self.minutes.to_le_bytes());
+        footer.extend_from_slice(&self.days.to_le_bytes());
+        footer.extend_from_slice(&self.cycles.to_le_bytes());
+        footer.extend_from_slice(&self.memory);
+        footer
+    }
+
+    fn load(&mut self, footer: &[u8]) -> Result<(), CartridgeError> {
+        if footer.len() != CLOCK_FOOTER_SIZE {
+            return Err(CartridgeError::SaveSizeMismatch {
+                expected: CLOCK_FOOTER_SIZE,
+                actual: footer.len(),
+            });
+        }
+        self.minutes = u16::from_le_bytes([footer[0], footer[1]]) % MINUTES_PER_DAY;
+        self.days = u16::from_le_bytes([footer[2], footer[3]]) & 0xFFF;
+        self.cycles = u32::from_le_bytes(footer[4..8].try_into().unwrap()) % CYCLES_PER_MINUTE;
+        self.memory.copy_from_slice(&footer[8..]);
+        Ok(())
+    }
+
+    fn execute(&mut self, command: u8, argument: u8) {
+        self.command = command;
+        match command {
+            // Read or write the current cell and move on to the next
+            0x1 => {
+                self.response = self.memory[self.address as usize];
+                self.address = self.address.wrapping_add(1);
+            }
+            0x3 => {
+                self.memory[self.address as usize] = argument;
+                self.address = self.address.wrapping_add(1);
+            }
+            0x4 => self.address = (self.address & 0xF0) | argument,
+            0x5 => self.address = (self.address & 0x0F) | argument << 4,
+            0x6 => match argument {
+                // Copy the time to the first 6 cells as 12-bit minutes and days, least
+                // significant nibble first
+                0x0 => {
+                    let time = self.minutes as u32 | (self.days as u32) << 12;
+                    for (index, cell) in self.memory[..6].iter_mut().enumerate() {
+                        *cell = (time >> (index * 4)) as u8 & 0x0F;
+                    }
+                }
+                // And set it from them
+                0x1 => {
+                    let time = self.memory[..6]
+                        .iter()
+                        .enumerate()
+                        .fold(0, |time, (index, cell)| {
+                            time | (*cell as u32) << (index * 4)
+                        });
+                    self.minutes = (time & 0xFFF) as u16 % MINUTES_PER_DAY;
+                    self.days = (time >> 12) as u16;
+                    self.cycles = 0;
+                }
+                // Status, always ready
+                0x2 => self.response = 0x1,
+                // Anything else drives the speaker, which we don't have
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+}
+
+// Hudson's controller with a clock, an infrared port and a speaker
+#[derive(Debug)]
+pub struct HuC3 {
+    rom: Vec<u8>,
+    ram: Vec<u8>,
+    clock: Clock,
+    mode: u8,
+    rom_bank: u8,
+    ram_bank: u8,
+}
+
+impl HuC3 {
+    pub fn new(rom: Vec<u8>, ram_size: usize) -> HuC3 {
+        HuC3 {
+            rom,
+            ram: vec![0; ram_size],
+            clock: Clock {
+                memory: vec![0; CLOCK_MEMORY_SIZE],
+                ..Clock::default()
+            },
+            mode: MODE_RAM_READ,
+            rom_bank: 0x01,
+            ram_bank: 0x00,
+        }
+    }
+}
+
+impl Mbc for HuC3 {
+    fn read_rom(&self, addr: Address) -> u8 {
+        match addr {
+            0x0000..=0x3FFF => read_rom_bank(&self.rom, 0, addr),
+            _ => read_rom_bank(&self.rom, self.rom_bank as usize, addr),
+        }
+    }
+
+    fn write_rom(&mut self, addr: Address, data: u8) {
+        match addr {
+            0x0000..=0x1FFF => self.mode = data & 0x0F,
+            0x2000..=0x3FFF => self.rom_bank = data & 0x7F,
+            0x4000..=0x5FFF => self.ram_bank = data & 0x03,
+            _ => {}
+        }
+    }
+
+    fn read_ram(&self, addr: Address) -> u8 {
+        match self.mode {
+            MODE_RAM_READ | MODE_RAM => match ram_offset(&self.ram, self.ram_bank as usize, addr) {
+                Some(offset) => self.ram[offset],
+                None => OPEN_BUS,
+            },
+            MODE_RTC_RESPONSE => 0x80 | self.clock.command << 4 | self.clock.response,
+            MODE_RTC_SEMAPHORE => 0x01,
+            // Nothing is ever seen by the IR sensor
+            MODE_IR => 0xC0,
+            _ => OPEN_BUS,
+        }
+    }
+
+    fn write_ram(&mut self, addr: Address, data: u8) {
+        match self.mode {
+            MODE_RAM => {
+                if let Some(offset) = ram_offset(&self.ram, self.ram_bank as usize, addr) {
+                    self.ram[offset] = data;
+                }
+            }
+            MODE_RTC_COMMAND => self.clock.execute(data >> 4 & 0x07, data & 0x0F),
+            _ => {}
+        }
+    }
+
+    fn tick(&mut self, cycles: u8) {
+        self.clock.tick(cycles);
+    }
+
+    fn ram(&self) -> &[u8] {
+        &self.ram
+    }
+
+    fn ram_mut(&mut self) -> &mut [u8] {
+        &mut self.ram
+    }
+
+    fn save_footer(&mut self) -> Vec<u8> {
+        self.clock.save()
+    }
+
+    fn load_footer(&mut self, footer: &[u8]) -> Result<(), CartridgeError> {
+        self.clock.load(footer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn command(mbc: &mut HuC3, command: u8, argument: u8) -> u8 {
+        mbc.write_rom(0x0000, MODE_RTC_COMMAND);
+        mbc.write_ram(0xA000, command << 4 | argument);
+        mbc.write_rom(0x0000, MODE_RTC_RESPONSE);
+        mbc.read_ram(0xA000) & 0x0F
+    }
+
+    #[test]
+    fn test_ram() {
+        let mut mbc = HuC3::new(vec![0; 0x8000], 0x8000);
+
+        // Mode 0 maps RAM read only
+        mbc.write_ram(0xA000, 0xAA);
+        assert_eq!(0x00, mbc.read_ram(0xA000));
+
+        mbc.write_rom(0x0000, MODE_RAM);
+        mbc.write_rom(0x4000, 0x01);
+        mbc.write_ram(0xA000, 0xAA);
+        assert_eq!(0xAA, mbc.read_ram(0xA000));
+
+        mbc.write_rom(0x0000, MODE_IR);
+        assert_eq!(0xC0, mbc.read_ram(0xA000));
+    }
+
+    #[test]
+    fn test_clock() {
+        let mut mbc = HuC3::new(vec![0; 0x8000], 0);
+
+        // Set the time to day 2, 23:59 through the scratch memory
+        let time = (MINUTES_PER_DAY as u32 - 1) | 2 << 12;
+        command(&mut mbc, 0x4, 0x0);
+        command(&mut mbc, 0x5, 0x0);
+        for index in 0..6 {
+            command(&mut mbc, 0x3, (time >> (index * 4)) as u8 & 0x0F);
+        }
+        command(&mut mbc, 0x6, 0x1);
+
+        for _ in 0..CYCLES_PER_MINUTE / 240 {
+            mbc.tick(240);
+        }
+
+        // Then read it back
+        command(&mut mbc, 0x6, 0x0);
+        command(&mut mbc, 0x4, 0x0);
+        let cells: Vec<u8> = (0..6).map(|_| command(&mut mbc, 0x1, 0x0)).collect();
+        assert_eq!(vec![0x0, 0x0, 0x0, 0x3, 0x0, 0x0], cells);
+
+        assert_eq!(0x1, command(&mut mbc, 0x6, 0x2));
+        mbc.write_rom(0x0000, MODE_RTC_SEMAPHORE);
+        assert_eq!(0x01, mbc.read_ram(0xA000));
+    }
+}
@@ -0,0 +1,234 @@
+use super::{ram_offset, read_rom_bank, Mbc, NINTENDO_LOGO, ROM_BANK_SIZE};
+use crate::data::Address;
+use crate::memory::OPEN_BUS;
+
+// Multicarts are 1MiB with a game every 16 banks, each starting with its own header
+const MULTICART_SIZE: usize = 0x100000;
+const MULTICART_GAME_BANKS: usize = 0x10;
+
+#[derive(Debug)]
+pub struct Mbc1 {
+    rom: Vec<u8>,
+    ram: Vec<u8>,
+    ram_enabled: bool,
+    // Lower 5 bits of the ROM bank in 0x4000-0x7FFF, 0 is translated to 1 as it is written
+    bank1: u8,
+    // 2 bits used as either the RAM bank or the upper ROM bank bits
+    bank2: u8,
+    // In mode 1 bank2 also applies to 0x0000-0x3FFF and external RAM
+    mode: bool,
+    // MBC1M boards leave out bit 4 of bank1 and wire bank2 one bit lower
+    multicart: bool,
+}
+
+impl Mbc1 {
+    pub fn new(rom: Vec<u8>, ram_size: usize, multicart: bool) -> Mbc1 {
+        Mbc1 {
+            rom,
+            ram: vec![0; ram_size],
+            ram_enabled: false,
+            bank1: 0x01,
+            bank2: 0x00,
+            mode: false,
+            multicart,
+        }
+    }
+
+    // There's nothing in the header marking a multicart, but every game in one has the logo at
+    // the start of its bank so the boot menu can be checked the same way a normal game is
+    pub fn is_multicart(rom: &[u8]) -> bool {
+        if rom.len() != MULTICART_SIZE {
+            return false;
+        }
+        let logo = 0x0104..0x0104 + NINTENDO_LOGO.len();
+        (1..4).any(|game| {
+            let start = game * MULTICART_GAME_BANKS * ROM_BANK_SIZE;
+            rom[start + logo.start..start + logo.end] == NINTENDO_LOGO
+        })
+    }
+
+    fn bank2_shift(&self) -> u8 {
+        match self.multicart {
+            true => 4,
+            false => 5,
+        }
+    }
+
+    fn low_rom_bank(&self) -> usize {
+        match self.mode {
+            true => (self.bank2 << self.bank2_shift()) as usize,
+            false => 0,
+        }
+    }
+
+    fn high_rom_bank(&self) -> usize {
+        let bank1 = match self.multicart {
+            true => self.bank1 & 0x0F,
+            false => self.bank1,
+        };
+        ((self.bank2 << self.bank2_shift()) | bank1) as usize
+    }
+
+    fn ram_bank(&self) -> usize {
+        match self.mode {
+            true => self.bank2 as usize,
+            false => 0,
+        }
+    }
+}
+
+impl Mbc for Mbc1 {
+    fn read_rom(&self, addr: Address) -> u8 {
+        match addr {
+            0x0000..=0x3FFF => read_rom_bank(&self.rom, self.low_rom_bank(), addr),
+            _ => read_rom_bank(&self.rom, self.high_rom_bank(), addr),
+        }
+    }
+
+    fn write_rom(&mut self, addr: Address, data: u8) {
+        match addr {
+            0x0000..=0x1FFF => self.ram_enabled = data & 0x0F == 0x0A,
+            // Only a write of 0 to all 5 bits selects bank 1, so 0x20, 0x40 and 0x60 can't be
+            // reached through 0x4000-0x7FFF
+            0x2000..=0x3FFF => self.bank1 = (data & 0x1F).max(0x01),
+            0x4000..=0x5FFF => self.bank2 = data & 0x03,
+            _ => self.mode = data & 0x01 != 0,
+        }
+    }
+
+    fn read_ram(&self, addr: Address) -> u8 {
+        match ram_offset(&self.ram, self.ram_bank(), addr) {
+            Some(offset) if self.ram_enabled => self.ram[offset],
+            _ => OPEN_BUS,
+        }
+    }
+
+    fn write_ram(&mut self, addr: Address, data: u8) {
+        match ram_offset(&self.ram, self.ram_bank(), addr) {
+            Some(offset) if self.ram_enabled => self.ram[offset] = data,
+            _ => {}
+        }
+    }
+
+    fn ram(&self) -> &[u8] {
+        &self.ram
+    }
+
+    fn ram_mut(&mut self) -> &mut [u8] {
+        &mut self.ram
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
+mod tests {
+    use super::*;
+    use crate::cartridge::{banked_rom, ROM_BANK_SIZE};
+
+    #[test]
+    fn test_rom_banking() {
+        let mut mbc = Mbc1::new(banked_rom(32, ROM_BANK_SIZE), 0, false);
+
+        assert_eq!(0x00, mbc.read_rom(0x0000));
+        assert_eq!(0x01, mbc.read_rom(0x4000));
+
+        mbc.write_rom(0x2000, 0x05);
+        assert_eq!(0x05, mbc.read_rom(0x7FFF));
+
+        // Bank 0 is translated to bank 1
+        mbc.write_rom(0x2000, 0x00);
+        assert_eq!(0x01, mbc.read_rom(0x4000));
+
+        // Only the lower 5 bits are used
+        mbc.write_rom(0x3FFF, 0xE3);
+        assert_eq!(0x03, mbc.read_rom(0x4000));
+
+        // Banks past the end of the ROM wrap around
+        let mut mbc = Mbc1::new(banked_rom(8, ROM_BANK_SIZE), 0, false);
+        mbc.write_rom(0x2000, 0x1F);
+        assert_eq!(0x07, mbc.read_rom(0x4000));
+    }
+
+    #[test]
+    fn test_large_rom() {
+        let mut mbc = Mbc1::new(banked_rom(128, ROM_BANK_SIZE), 0, false);
+
+        // The bank 0 translation only looks at the lower 5 bits, so 0x20 becomes 0x21
+        mbc.write_rom(0x4000, 0x01);
+        mbc.write_rom(0x2000, 0x00);
+        assert_eq!(0x21, mbc.read_rom(0x4000));
+        assert_eq!(0x00, mbc.read_rom(0x0000));
+
+        // Mode 1 applies the upper bits to 0x0000-0x3FFF as well
+        mbc.write_rom(0x6000, 0x01);
+        assert_eq!(0x20, mbc.read_rom(0x0000));
+        mbc.write_rom(0x4000, 0x03);
+        assert_eq!(0x60, mbc.read_rom(0x0000));
+        assert_eq!(0x61, mbc.read_rom(0x4000));
+    }
+
+    #[test]
+    fn test_ram() {
+        let mut mbc = Mbc1::new(banked_rom(4, ROM_BANK_SIZE), 0x8000, false);
+
+        // Disabled on power up
+        mbc.write_ram(0xA000, 0xAA);
+        assert_eq!(0xFF, mbc.read_ram(0xA000));
+
+        mbc.write_rom(0x0000, 0x0A);
+        mbc.write_ram(0xA000, 0xAA);
+        assert_eq!(0xAA, mbc.read_ram(0xA000));
+
+        // The RAM bank is ignored in mode 0
+        mbc.write_rom(0x4000, 0x02);
+        assert_eq!(0xAA, mbc.read_ram(0xA000));
+
+        mbc.write_rom(0x6000, 0x01);
+        assert_eq!(0x00, mbc.read_ram(0xA000));
+        mbc.write_ram(0xBFFF, 0xBB);
+        mbc.write_rom(0x4000, 0x00);
+        assert_eq!(0xAA, mbc.read_ram(0xA000));
+        assert_eq!(0x00, mbc.read_ram(0xBFFF));
+
+        // Any value without 0xA in the lower nibble disables it again
+        mbc.write_rom(0x1FFF, 0x1B);
+        assert_eq!(0xFF, mbc.read_ram(0xA000));
+    }
+
+    #[test]
+    fn test_small_ram() {
+        let mut mbc = Mbc1::new(banked_rom(4, ROM_BANK_SIZE), 0x800, false);
+        mbc.write_rom(0x0000, 0x0A);
+
+        // 2KiB of RAM is mirrored across the whole bank
+        mbc.write_ram(0xA000, 0xAA);
+        assert_eq!(0xAA, mbc.read_ram(0xA800));
+        assert_eq!(0xAA, mbc.read_ram(0xB800));
+    }
+
+    #[test]
+    fn test_multicart() {
+        let mut rom = banked_rom(64, ROM_BANK_SIZE);
+        assert_eq!(false, Mbc1::is_multicart(&rom));
+
+        let header = 0x10 * ROM_BANK_SIZE + 0x0104;
+        rom[header..header + NINTENDO_LOGO.len()].copy_from_slice(&NINTENDO_LOGO);
+        assert_eq!(true, Mbc1::is_multicart(&rom));
+
+        let mut mbc = Mbc1::new(rom, 0, true);
+
+        // Bit 4 of the ROM bank isn't connected
+        mbc.write_rom(0x2000, 0x12);
+        assert_eq!(0x02, mbc.read_rom(0x4000));
+
+        // And bank2 picks which game's 16 banks are used
+        mbc.write_rom(0x4000, 0x02);
+        assert_eq!(0x22, mbc.read_rom(0x4000));
+        mbc.write_rom(0x6000, 0x01);
+        assert_eq!(0x20, mbc.read_rom(0x0000));
+
+        // Bank 0 translation still looks at all 5 bits
+        mbc.write_rom(0x2000, 0x10);
+        assert_eq!(0x20, mbc.read_rom(0x4000));
+    }
+}
@@ -0,0 +1,119 @@
+use super::{read_rom_bank, Mbc};
+use crate::data::Address;
+use crate::memory::OPEN_BUS;
+
+// The RAM is inside the controller, 512 cells of 4 bits each
+const RAM_SIZE: usize = 0x200;
+
+#[derive(Debug)]
+pub struct Mbc2 {
+    rom: Vec<u8>,
+    ram: Vec<u8>,
+    ram_enabled: bool,
+    rom_bank: u8,
+}
+
+impl Mbc2 {
+    pub fn new(rom: Vec<u8>) -> Mbc2 {
+        Mbc2 {
+            rom,
+            ram: vec![0; RAM_SIZE],
+            ram_enabled: false,
+            rom_bank: 0x01,
+        }
+    }
+}
+
+impl Mbc for Mbc2 {
+    fn read_rom(&self, addr: Address) -> u8 {
+        match addr {
+            0x0000..=0x3FFF => read_rom_bank(&self.rom, 0, addr),
+            _ => read_rom_bank(&self.rom, self.rom_bank as usize, addr),
+        }
+    }
+
+    fn write_rom(&mut self, addr: Address, data: u8) {
+        match addr {
+            // Both registers are anywhere in 0x0000-0x3FFF, address bit 8 picks which
+            0x0000..=0x3FFF if addr & 0x0100 == 0 => self.ram_enabled = data & 0x0F == 0x0A,
+            0x0000..=0x3FFF => self.rom_bank = (data & 0x0F).max(0x01),
+            _ => {}
+        }
+    }
+
+    // Only the lower 9 address bits are decoded, so the RAM repeats through 0xA000-0xBFFF
+    fn read_ram(&self, addr: Address) -> u8 {
+        match self.ram_enabled {
+            // The upper half of each byte isn't connected
+            true => self.ram[addr as usize & (RAM_SIZE - 1)] | 0xF0,
+            false => OPEN_BUS,
+        }
+    }
+
+    fn write_ram(&mut self, addr: Address, data: u8) {
+        if self.ram_enabled {
+            self.ram[addr as usize & (RAM_SIZE - 1)] = data & 0x0F;
+        }
+    }
+
+    // One byte per 4-bit cell, with the upper bits left clear
+    fn ram(&self) -> &[u8] {
+        &self.ram
+    }
+
+    fn ram_mut(&mut self) -> &mut [u8] {
+        &mut self.ram
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cartridge::{banked_rom, ROM_BANK_SIZE};
+
+    #[test]
+    fn test_rom_banking() {
+        let mut mbc = Mbc2::new(banked_rom(16, ROM_BANK_SIZE));
+
+        mbc.write_rom(0x2100, 0x05);
+        assert_eq!(0x05, mbc.read_rom(0x4000));
+
+        // Address bit 8 clear writes RAM enable instead
+        mbc.write_rom(0x2000, 0x07);
+        assert_eq!(0x05, mbc.read_rom(0x4000));
+
+        // Bank 0 is translated to 1, and only 4 bits are used
+        mbc.write_rom(0x0100, 0x10);
+        assert_eq!(0x01, mbc.read_rom(0x4000));
+        mbc.write_rom(0x3FFF, 0xFF);
+        assert_eq!(0x0F, mbc.read_rom(0x7FFF));
+
+        // Nothing is mapped at 0x4000-0x7FFF
+        mbc.write_rom(0x4100, 0x02);
+        assert_eq!(0x0F, mbc.read_rom(0x4000));
+    }
+
+    #[test]
+    fn test_ram() {
+        let mut mbc = Mbc2::new(banked_rom(2, ROM_BANK_SIZE));
+
+        mbc.write_ram(0xA000, 0x05);
+        assert_eq!(0xFF, mbc.read_ram(0xA000));
+
+        // Address bit 8 set writes the ROM bank instead
+        mbc.write_rom(0x0100, 0x0A);
+        assert_eq!(0xFF, mbc.read_ram(0xA000));
+
+        mbc.write_rom(0x1EFF, 0x0A);
+        mbc.write_ram(0xA000, 0xA5);
+        assert_eq!(0xF5, mbc.read_ram(0xA000));
+
+        // Mirrored every 512 bytes
+        assert_eq!(0xF5, mbc.read_ram(0xA200));
+        mbc.write_ram(0xBFFF, 0x03);
+        assert_eq!(0xF3, mbc.read_ram(0xA1FF));
+
+        mbc.write_rom(0x0000, 0x00);
+        assert_eq!(0xFF, mbc.read_ram(0xA000));
+    }
+}
@@ -0,0 +1,201 @@
+use super::rtc::Rtc;
+use super::{ram_offset, read_rom_bank, Mbc};
+use crate::data::Address;
+use crate::error::CartridgeError;
+use crate::memory::OPEN_BUS;
+
+// MBC30, only used by the Japanese Pokémon Crystal, has an extra ROM bank bit for 4MiB of ROM
+const MBC30_ROM_SIZE: usize = 0x200000;
+
+#[derive(Debug)]
+pub struct Mbc3 {
+    rom: Vec<u8>,
+    ram: Vec<u8>,
+    rtc: Option<Rtc>,
+    // Enables both the RAM and the clock registers
+    ram_enabled: bool,
+    rom_bank: u8,
+    rom_bank_mask: u8,
+    // 0x00-0x07 maps a RAM bank, 0x08-0x0C one of the clock registers
+    ram_bank: u8,
+    // Latching happens on a write of 0x00 followed by 0x01
+    latch_armed: bool,
+}
+
+impl Mbc3 {
+    pub fn new(rom: Vec<u8>, ram_size: usize, rtc: bool) -> Mbc3 {
+        let rom_bank_mask = match rom.len() > MBC30_ROM_SIZE {
+            true => 0xFF,
+            false => 0x7F,
+        };
+        Mbc3 {
+            rom,
+            ram: vec![0; ram_size],
+            rtc: if rtc { Some(Rtc::default()) } else { None },
+            ram_enabled: false,
+            rom_bank: 0x01,
+            rom_bank_mask,
+            ram_bank: 0x00,
+            latch_armed: false,
+        }
+    }
+}
+
+impl Mbc for Mbc3 {
+    fn read_rom(&self, addr: Address) -> u8 {
+        match addr {
+            0x0000..=0x3FFF => read_rom_bank(&self.rom, 0, addr),
+            _ => read_rom_bank(&self.rom, self.rom_bank as usize, addr),
+        }
+    }
+
+    fn write_rom(&mut self, addr: Address, data: u8) {
+        match addr {
+            0x0000..=0x1FFF => self.ram_enabled = data & 0x0F == 0x0A,
+            0x2000..=0x3FFF => self.rom_bank = (data & self.rom_bank_mask).max(0x01),
+            0x4000..=0x5FFF => self.ram_bank = data & 0x0F,
+            _ => {
+                if let (true, 0x01, Some(rtc)) = (self.latch_armed, data, &mut self.rtc) {
+                    rtc.latch();
+                }
+                self.latch_armed = data == 0x00;
+            }
+        }
+    }
+
+    fn read_ram(&self, addr: Address) -> u8 {
+        if !self.ram_enabled {
+            return OPEN_BUS;
+        }
+        match (self.ram_bank, &self.rtc) {
+            (0x00..=0x07, _) => match ram_offset(&self.ram, self.ram_bank as usize, addr) {
+                Some(offset) => self.ram[offset],
+                None => OPEN_BUS,
+            },
+            (0x08..=0x0C, Some(rtc)) => rtc.read(self.ram_bank),
+            _ => OPEN_BUS,
+        }
+    }
+
+    fn write_ram(&mut self, addr: Address, data: u8) {
+        if !self.ram_enabled {
+            return;
+        }
+        match (self.ram_bank, &mut self.rtc) {
+            (0x00..=0x07, _) => {
+                if let Some(offset) = ram_offset(&self.ram, self.ram_bank as usize, addr) {
+                    self.ram[offset] = data;
+                }
+            }
+            (0x08..=0x0C, Some(rtc)) => rtc.write(self.ram_bank, data),
+            _ => {}
+        }
+    }
+
+    fn tick(&mut self, cycles: u8) {
+        if let Some(rtc) = &mut self.rtc {
+            rtc.tick(cycles);
+        }
+    }
+
+    fn rtc(&self) -> Option<&Rtc> {
+        self.rtc.as_ref()
+    }
+
+    fn rtc_mut(&mut self) -> Option<&mut Rtc> {
+        self.rtc.as_mut()
+    }
+
+    fn save_footer(&mut self) -> Vec<u8> {
+        match &mut self.rtc {
+            Some(rtc) => rtc.save().to_vec(),
+            None => Vec::new(),
+        }
+    }
+
+    fn load_footer(&mut self, footer: &[u8]) -> Result<(), CartridgeError> {
+        match &mut self.rtc {
+            Some(rtc) => rtc.load(footer),
+            None => Err(CartridgeError::SaveSizeMismatch {
+                expected: 0,
+                actual: footer.len(),
+            }),
+        }
+    }
+
+    fn ram(&self) -> &[u8] {
+        &self.ram
+    }
+
+    fn ram_mut(&mut self) -> &mut [u8] {
+        &mut self.ram
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cartridge::{banked_rom, ROM_BANK_SIZE};
+
+    #[test]
+    fn test_rom_banking() {
+        let mut mbc = Mbc3::new(banked_rom(128, ROM_BANK_SIZE), 0, false);
+
+        assert_eq!(0x01, mbc.read_rom(0x4000));
+
+        // All 7 bits are used, so there's no hole at 0x20
+        mbc.write_rom(0x2000, 0x20);
+        assert_eq!(0x20, mbc.read_rom(0x4000));
+        mbc.write_rom(0x2000, 0xFF);
+        assert_eq!(0x7F, mbc.read_rom(0x4000));
+        mbc.write_rom(0x2000, 0x00);
+        assert_eq!(0x01, mbc.read_rom(0x4000));
+        assert_eq!(0x00, mbc.read_rom(0x0000));
+
+        let mut mbc = Mbc3::new(banked_rom(256, ROM_BANK_SIZE), 0, false);
+        mbc.write_rom(0x2000, 0xFF);
+        assert_eq!(0xFF, mbc.read_rom(0x4000));
+    }
+
+    #[test]
+    fn test_ram_banking() {
+        let mut mbc = Mbc3::new(banked_rom(4, ROM_BANK_SIZE), 0x8000, false);
+
+        mbc.write_ram(0xA000, 0xAA);
+        assert_eq!(0xFF, mbc.read_ram(0xA000));
+
+        mbc.write_rom(0x0000, 0x0A);
+        mbc.write_rom(0x4000, 0x03);
+        mbc.write_ram(0xA000, 0xAA);
+        assert_eq!(0xAA, mbc.read_ram(0xA000));
+        mbc.write_rom(0x4000, 0x00);
+        assert_eq!(0x00, mbc.read_ram(0xA000));
+
+        // No clock on this cartridge
+        mbc.write_rom(0x4000, 0x08);
+        assert_eq!(0xFF, mbc.read_ram(0xA000));
+    }
+
+    #[test]
+    fn test_rtc() {
+        let mut mbc = Mbc3::new(banked_rom(4, ROM_BANK_SIZE), 0x2000, true);
+        mbc.write_rom(0x0000, 0x0A);
+
+        mbc.write_rom(0x4000, 0x09);
+        mbc.write_ram(0xA000, 42);
+        // The write only shows up once latched
+        assert_eq!(0, mbc.read_ram(0xA000));
+
+        // Writing 0x01 alone doesn't latch
+        mbc.write_rom(0x6000, 0x01);
+        assert_eq!(0, mbc.read_ram(0xA000));
+
+        mbc.write_rom(0x6000, 0x00);
+        mbc.write_rom(0x6000, 0x01);
+        assert_eq!(42, mbc.read_ram(0xBFFF));
+
+        // Disabling RAM hides the clock too
+        mbc.write_rom(0x0000, 0x00);
+        assert_eq!(0xFF, mbc.read_ram(0xA000));
+    }
+}
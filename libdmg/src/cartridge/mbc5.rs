@@ -0,0 +1,147 @@
+use super::{ram_offset, read_rom_bank, Mbc};
+use crate::data::Address;
+use crate::memory::OPEN_BUS;
+
+// On rumble cartridges bit 3 of the RAM bank drives the motor instead
+const RUMBLE_MOTOR: u8 = 0x08;
+
+#[derive(Debug)]
+pub struct Mbc5 {
+    rom: Vec<u8>,
+    ram: Vec<u8>,
+    ram_enabled: bool,
+    // 9 bits, and unlike the older controllers bank 0 can be mapped into 0x4000-0x7FFF
+    rom_bank: u16,
+    ram_bank: u8,
+    has_rumble: bool,
+}
+
+impl Mbc5 {
+    pub fn new(rom: Vec<u8>, ram_size: usize, has_rumble: bool) -> Mbc5 {
+        Mbc5 {
+            rom,
+            ram: vec![0; ram_size],
+            ram_enabled: false,
+            rom_bank: 0x01,
+            ram_bank: 0x00,
+            has_rumble,
+        }
+    }
+
+    fn ram_bank(&self) -> usize {
+        match self.has_rumble {
+            true => (self.ram_bank & !RUMBLE_MOTOR) as usize,
+            false => self.ram_bank as usize,
+        }
+    }
+}
+
+impl Mbc for Mbc5 {
+    fn read_rom(&self, addr: Address) -> u8 {
+        match addr {
+            0x0000..=0x3FFF => read_rom_bank(&self.rom, 0, addr),
+            _ => read_rom_bank(&self.rom, self.rom_bank as usize, addr),
+        }
+    }
+
+    fn write_rom(&mut self, addr: Address, data: u8) {
+        match addr {
+            // Only exactly 0x0A enables RAM, not just the lower nibble
+            0x0000..=0x1FFF => self.ram_enabled = data == 0x0A,
+            0x2000..=0x2FFF => self.rom_bank = (self.rom_bank & 0x100) | data as u16,
+            0x3000..=0x3FFF => self.rom_bank = (self.rom_bank & 0xFF) | ((data & 0x01) as u16) << 8,
+            0x4000..=0x5FFF => self.ram_bank = data & 0x0F,
+            _ => {}
+        }
+    }
+
+    fn read_ram(&self, addr: Address) -> u8 {
+        match ram_offset(&self.ram, self.ram_bank(), addr) {
+            Some(offset) if self.ram_enabled => self.ram[offset],
+            _ => OPEN_BUS,
+        }
+    }
+
+    fn write_ram(&mut self, addr: Address, data: u8) {
+        match ram_offset(&self.ram, self.ram_bank(), addr) {
+            Some(offset) if self.ram_enabled => self.ram[offset] = data,
+            _ => {}
+        }
+    }
+
+    fn rumble(&self) -> bool {
+        self.has_rumble && self.ram_bank & RUMBLE_MOTOR != 0
+    }
+
+    fn ram(&self) -> &[u8] {
+        &self.ram
+    }
+
+    fn ram_mut(&mut self) -> &mut [u8] {
+        &mut self.ram
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
+mod tests {
+    use super::*;
+    use crate::cartridge::{banked_rom, ROM_BANK_SIZE};
+
+    fn read_bank(mbc: &Mbc5) -> u16 {
+        u16::from_le_bytes([mbc.read_rom(0x4000), mbc.read_rom(0x4001)])
+    }
+
+    #[test]
+    fn test_rom_banking() {
+        let mut mbc = Mbc5::new(banked_rom(512, ROM_BANK_SIZE), 0, false);
+
+        assert_eq!(1, read_bank(&mbc));
+
+        mbc.write_rom(0x2000, 0x00);
+        assert_eq!(0, read_bank(&mbc));
+
+        mbc.write_rom(0x3000, 0x01);
+        assert_eq!(0x100, read_bank(&mbc));
+        mbc.write_rom(0x2FFF, 0xFF);
+        assert_eq!(0x1FF, read_bank(&mbc));
+        mbc.write_rom(0x3FFF, 0xFE);
+        assert_eq!(0xFF, read_bank(&mbc));
+    }
+
+    #[test]
+    fn test_ram_banking() {
+        let mut mbc = Mbc5::new(banked_rom(4, ROM_BANK_SIZE), 0x20000, false);
+
+        // Only exactly 0x0A enables RAM
+        mbc.write_rom(0x0000, 0x1A);
+        mbc.write_ram(0xA000, 0xAA);
+        assert_eq!(0xFF, mbc.read_ram(0xA000));
+
+        mbc.write_rom(0x0000, 0x0A);
+        mbc.write_rom(0x4000, 0x0F);
+        mbc.write_ram(0xA000, 0xAA);
+        assert_eq!(0xAA, mbc.read_ram(0xA000));
+        mbc.write_rom(0x4000, 0x07);
+        assert_eq!(0x00, mbc.read_ram(0xA000));
+        assert_eq!(false, mbc.rumble());
+    }
+
+    #[test]
+    fn test_rumble() {
+        let mut mbc = Mbc5::new(banked_rom(4, ROM_BANK_SIZE), 0x8000, true);
+        mbc.write_rom(0x0000, 0x0A);
+
+        mbc.write_rom(0x4000, 0x01);
+        mbc.write_ram(0xA000, 0xAA);
+        assert_eq!(false, mbc.rumble());
+
+        // The motor bit isn't part of the RAM bank
+        mbc.write_rom(0x4000, 0x09);
+        assert_eq!(true, mbc.rumble());
+        assert_eq!(0xAA, mbc.read_ram(0xA000));
+
+        mbc.write_rom(0x4000, 0x01);
+        assert_eq!(false, mbc.rumble());
+    }
+}
@@ -0,0 +1,297 @@
+use super::Mbc;
+use crate::data::Address;
+use crate::error::CartridgeError;
+use crate::memory::OPEN_BUS;
+
+// Both ROM windows and both RAM windows are half the usual size and banked separately
+const HALF_ROM_BANK_SIZE: usize = 0x2000;
+const HALF_RAM_BANK_SIZE: usize = 0x1000;
+
+// 1MiB of Macronix flash, which can be mapped into either ROM window in place of the ROM
+const FLASH_SIZE: usize = 0x100000;
+const FLASH_SECTOR_SIZE: usize = 0x20000;
+const FLASH_MANUFACTURER_ID: u8 = 0xC2;
+const FLASH_DEVICE_ID: u8 = 0x81;
+
+// Commands are unlocked by writing 0xAA and 0x55 to these flash addresses
+const FLASH_UNLOCK_1: usize = 0x5555;
+const FLASH_UNLOCK_2: usize = 0x2AAA;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum FlashState {
+    Ready,
+    Unlocked1,
+    Unlocked2,
+    Program,
+    EraseSetup,
+    EraseUnlocked1,
+    EraseUnlocked2,
+    // Reads return the chip's ID instead of its contents
+    Id,
+}
+
+#[derive(Debug)]
+struct Flash {
+    data: Vec<u8>,
+    state: FlashState,
+}
+
+impl Flash {
+    fn read(&self, addr: usize) -> u8 {
+        match (self.state, addr & 0x01) {
+            (FlashState::Id, 0) => FLASH_MANUFACTURER_ID,
+            (FlashState::Id, _) => FLASH_DEVICE_ID,
+            _ => self.data[addr % FLASH_SIZE],
+        }
+    }
+
+    // Programming can only clear bits, they're set again by erasing a sector or the whole chip
+    fn write(&mut self, addr: usize, data: u8, write_enabled: bool) {
+        let command = addr & 0x7FFF;
+        self.state = match (self.state, command, data) {
+            (FlashState::Program, _, _) => {
+                if write_enabled {
+                    self.data[addr % FLASH_SIZE] &= data;
+                }
+                FlashState::Ready
+            }
+            (_, _, 0xF0) => FlashState::Ready,
+            (FlashState::Ready | FlashState::Id, FLASH_UNLOCK_1, 0xAA) => FlashState::Unlocked1,
+            (FlashState::Unlocked1, FLASH_UNLOCK_2, 0x55) => FlashState::Unlocked2,
+            (FlashState::Unlocked2, FLASH_UNLOCK_1, 0xA0) => FlashState::Program,
+            (FlashState::Unlocked2, FLASH_UNLOCK_1, 0x80) => FlashState::EraseSetup,
+            (FlashState::Unlocked2, FLASH_UNLOCK_1, 0x90) => FlashState::Id,
+            (FlashState::EraseSetup, FLASH_UNLOCK_1, 0xAA) => FlashState::EraseUnlocked1,
+            (FlashState::EraseUnlocked1, FLASH_UNLOCK_2, 0x55) => FlashState::EraseUnlocked2,
+            (FlashState::EraseUnlocked2, _, 0x30) => {
+                if write_enabled {
+                    let sector = addr % FLASH_SIZE / FLASH_SECTOR_SIZE * FLASH_SECTOR_SIZE;
+                    self.data[sector..sector + FLASH_SECTOR_SIZE].fill(0xFF);
+                }
+                FlashState::Ready
+            }
+            (FlashState::EraseUnlocked2, FLASH_UNLOCK_1, 0x10) => {
+                if write_enabled {
+                    self.data.fill(0xFF);
+                }
+                FlashState::Ready
+            }
+            (FlashState::Id, _, _) => FlashState::Id,
+            _ => FlashState::Ready,
+        }
+    }
+}
+
+// One of the two halves of 0x4000-0x7FFF
+#[derive(Clone, Copy, Debug, Default)]
+struct Window {
+    bank: u8,
+    flash: bool,
+}
+
+impl Window {
+    fn offset(&self, addr: Address) -> usize {
+        self.bank as usize * HALF_ROM_BANK_SIZE + (addr as usize & (HALF_ROM_BANK_SIZE - 1))
+    }
+}
+
+// Only used by Net de Get, with two independently banked 8KiB ROM windows and 4KiB RAM windows
+#[derive(Debug)]
+pub struct Mbc6 {
+    rom: Vec<u8>,
+    ram: Vec<u8>,
+    flash: Flash,
+    ram_enabled: bool,
+    ram_banks: [u8; 2],
+    windows: [Window; 2],
+    flash_enabled: bool,
+    flash_write_enabled: bool,
+}
+
+impl Mbc6 {
+    pub fn new(rom: Vec<u8>, ram_size: usize) -> Mbc6 {
+        Mbc6 {
+            rom,
+            ram: vec![0; ram_size],
+            flash: Flash {
+                data: vec![0xFF; FLASH_SIZE],
+                state: FlashState::Ready,
+            },
+            ram_enabled: false,
+            ram_banks: [0, 0],
+            windows: [Window::default(); 2],
+            flash_enabled: false,
+            flash_write_enabled: false,
+        }
+    }
+
+    fn window(addr: Address) -> usize {
+        (addr as usize >> 13) & 0x01
+    }
+
+    fn ram_offset(&self, addr: Address) -> Option<usize> {
+        let bank = self.ram_banks[(addr as usize >> 12) & 0x01] as usize;
+        match self.ram.len() {
+            0 => None,
+            len => {
+                Some((bank * HALF_RAM_BANK_SIZE + (addr as usize & (HALF_RAM_BANK_SIZE - 1))) % len)
+            }
+        }
+    }
+}
+
+impl Mbc for Mbc6 {
+    fn read_rom(&self, addr: Address) -> u8 {
+        if addr < 0x4000 {
+            return *self.rom.get(addr as usize).unwrap_or(&OPEN_BUS);
+        }
+        let window = self.windows[Self::window(addr)];
+        match (window.flash, self.flash_enabled) {
+            (true, true) => self.flash.read(window.offset(addr)),
+            (true, false) => OPEN_BUS,
+            _ => match self.rom.len() {
+                0 => OPEN_BUS,
+                len => self.rom[window.offset(addr) % len],
+            },
+        }
+    }
+
+    fn write_rom(&mut self, addr: Address, data: u8) {
+        match addr {
+            0x0000..=0x03FF => self.ram_enabled = data & 0x0F == 0x0A,
+            0x0400..=0x07FF => self.ram_banks[0] = data & 0x07,
+            0x0800..=0x0BFF => self.ram_banks[1] = data & 0x07,
+            0x0C00..=0x0FFF => self.flash_enabled = data & 0x01 != 0,
+            0x1000..=0x1FFF => self.flash_write_enabled = data & 0x01 != 0,
+            // Each window has a bank number register followed by a ROM or flash select
+            0x2000..=0x3FFF => {
+                let window = &mut self.windows[(addr as usize >> 12) & 0x01];
+                match addr & 0x0800 {
+                    0 => window.bank = data & 0x7F,
+                    _ => window.flash = data & 0x08 != 0,
+                }
+            }
+            _ => {
+                let window = self.windows[Self::window(addr)];
+                if window.flash && self.flash_enabled {
+                    self.flash
+                        .write(window.offset(addr), data, self.flash_write_enabled);
+                }
+            }
+        }
+    }
+
+    fn read_ram(&self, addr: Address) -> u8 {
+        match self.ram_offset(addr) {
+            Some(offset) if self.ram_enabled => self.ram[offset],
+            _ => OPEN_BUS,
+        }
+    }
+
+    fn write_ram(&mut self, addr: Address, data: u8) {
+        match self.ram_offset(addr) {
+            Some(offset) if self.ram_enabled => self.ram[offset] = data,
+            _ => {}
+        }
+    }
+
+    fn ram(&self) -> &[u8] {
+        &self.ram
+    }
+
+    fn ram_mut(&mut self) -> &mut [u8] {
+        &mut self.ram
+    }
+
+    // Net de Get keeps its save in the flash, so the whole chip goes after the RAM
+    fn save_footer(&mut self) -> Vec<u8> {
+        self.flash.data.clone()
+    }
+
+    fn load_footer(&mut self, footer: &[u8]) -> Result<(), CartridgeError> {
+        if footer.len() != FLASH_SIZE {
+            return Err(CartridgeError::SaveSizeMismatch {
+                expected: FLASH_SIZE,
+                actual: footer.len(),
+            });
+        }
+        self.flash.data.copy_from_slice(footer);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cartridge::banked_rom;
+
+    fn flash_command(mbc: &mut Mbc6, data: u8) {
+        // Bank 2 at 0x5555 and bank 1 at 0x4AAA in the first window
+        mbc.write_rom(0x2000, 0x02);
+        mbc.write_rom(0x5555, 0xAA);
+        mbc.write_rom(0x2000, 0x01);
+        mbc.write_rom(0x4AAA, 0x55);
+        mbc.write_rom(0x2000, 0x02);
+        mbc.write_rom(0x5555, data);
+    }
+
+    #[test]
+    fn test_rom_windows() {
+        let mut mbc = Mbc6::new(banked_rom(128, HALF_ROM_BANK_SIZE), 0);
+
+        mbc.write_rom(0x2000, 0x05);
+        mbc.write_rom(0x3000, 0x7F);
+        assert_eq!(0x05, mbc.read_rom(0x4000));
+        assert_eq!(0x05, mbc.read_rom(0x5FFF));
+        assert_eq!(0x7F, mbc.read_rom(0x6000));
+
+        // 0x0000-0x3FFF is always the first 16KiB
+        assert_eq!(0x00, mbc.read_rom(0x0000));
+        assert_eq!(0x01, mbc.read_rom(0x3FFF));
+    }
+
+    #[test]
+    fn test_ram_windows() {
+        let mut mbc = Mbc6::new(vec![0; 0x8000], 0x8000);
+        mbc.write_rom(0x0000, 0x0A);
+
+        mbc.write_rom(0x0400, 0x01);
+        mbc.write_rom(0x0800, 0x01);
+        mbc.write_ram(0xA000, 0xAA);
+        assert_eq!(0xAA, mbc.read_ram(0xB000));
+
+        mbc.write_rom(0x0800, 0x02);
+        assert_eq!(0x00, mbc.read_ram(0xB000));
+    }
+
+    #[test]
+    fn test_flash() {
+        let mut mbc = Mbc6::new(vec![0; 0x8000], 0);
+
+        mbc.write_rom(0x2800, 0x08);
+        mbc.write_rom(0x0C00, 0x01);
+        mbc.write_rom(0x1000, 0x01);
+        mbc.write_rom(0x2000, 0x04);
+        assert_eq!(0xFF, mbc.read_rom(0x4000));
+
+        // Programming needs the unlock sequence first
+        mbc.write_rom(0x4000, 0x00);
+        assert_eq!(0xFF, mbc.read_rom(0x4000));
+
+        flash_command(&mut mbc, 0xA0);
+        mbc.write_rom(0x2000, 0x04);
+        mbc.write_rom(0x4000, 0x5A);
+        assert_eq!(0x5A, mbc.read_rom(0x4000));
+
+        flash_command(&mut mbc, 0x90);
+        assert_eq!(FLASH_MANUFACTURER_ID, mbc.read_rom(0x4000));
+        assert_eq!(FLASH_DEVICE_ID, mbc.read_rom(0x4001));
+        mbc.write_rom(0x4000, 0xF0);
+
+        // Erasing the sector holding bank 4
+        flash_command(&mut mbc, 0x80);
+        flash_command(&mut mbc, 0x30);
+        mbc.write_rom(0x2000, 0x04);
+        assert_eq!(0xFF, mbc.read_rom(0x4000));
+    }
+}
@@ -0,0 +1,363 @@
+use super::{read_rom_bank, Mbc};
+use crate::data::Address;
+use crate::memory::OPEN_BUS;
+
+// The accelerometer reads this when level, and moves by about this much per g
+const ACCELEROMETER_CENTRE: u16 = 0x81D0;
+const ACCELEROMETER_G: f32 = 112.0;
+// What the accelerometer registers read between being erased and latched again
+const ACCELEROMETER_ERASED: u16 = 0x8000;
+
+// 93LC56 EEPROM organised as 128 16-bit words, kept as little endian bytes for saving
+const EEPROM_WORDS: usize = 128;
+const EEPROM_SIZE: usize = EEPROM_WORDS * 2;
+const EEPROM_CS: u8 = 0x80;
+const EEPROM_CLK: u8 = 0x40;
+const EEPROM_DI: u8 = 0x02;
+const EEPROM_DO: u8 = 0x01;
+// Start bit, 2 opcode bits and 8 address bits
+const EEPROM_COMMAND_BITS: u8 = 11;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum EepromState {
+    // Waiting for a start bit
+    Idle,
+    Command {
+        value: u16,
+        bits: u8,
+    },
+    Read {
+        addr: usize,
+        bit: u8,
+    },
+    // Writing to one word, or all of them
+    Write {
+        addr: Option<usize>,
+        value: u16,
+        bits: u8,
+    },
+}
+
+// The serial EEPROM, bit banged by the game through the pins in one register
+#[derive(Debug)]
+struct Eeprom {
+    data: Vec<u8>,
+    state: EepromState,
+    write_enabled: bool,
+    pins: u8,
+    data_out: bool,
+}
+
+impl Eeprom {
+    fn word(&self, addr: usize) -> u16 {
+        u16::from_le_bytes([self.data[addr * 2], self.data[addr * 2 + 1]])
+    }
+
+    fn set_word(&mut self, addr: usize, value: u16) {
+        self.data[addr * 2..addr * 2 + 2].copy_from_slice(&value.to_le_bytes());
+    }
+
+    fn read(&self) -> u8 {
+        let pins = self.pins & (EEPROM_CS | EEPROM_CLK | EEPROM_DI);
+        match self.data_out {
+            true => pins | EEPROM_DO,
+            false => pins,
+        }
+    }
+
+    fn write(&mut self, pins: u8) {
+        let rising_edge = pins & EEPROM_CLK != 0 && self.pins & EEPROM_CLK == 0;
+        self.pins = pins;
+
+        if pins & EEPROM_CS == 0 {
+            // Deselecting cancels whatever was going on and leaves DO showing ready
+            self.state = EepromState::Idle;
+            self.data_out = true;
+        } else if rising_edge {
+            self.clock(pins & EEPROM_DI != 0);
+        }
+    }
+
+    fn clock(&mut self, bit: bool) {
+        self.state = match self.state {
+            EepromState::Idle if bit => EepromState::Command {
+                value: 0x01,
+                bits: 1,
+            },
+            EepromState::Idle => EepromState::Idle,
+            EepromState::Command { value, bits } => {
+                let value = value << 1 | bit as u16;
+                match bits + 1 {
+                    EEPROM_COMMAND_BITS => self.command(value),
+                    bits => EepromState::Command { value, bits },
+                }
+            }
+            // Reads carry on into the following words for as long as the clock runs
+            EepromState::Read { addr, bit } => {
+                self.data_out = self.word(addr) >> (15 - bit) & 0x01 != 0;
+                match bit {
+                    15 => EepromState::Read {
+                        addr: (addr + 1) % EEPROM_WORDS,
+                        bit: 0,
+                    },
+                    _ => EepromState::Read { addr, bit: bit + 1 },
+                }
+            }
+            EepromState::Write { addr, value, bits } => {
+                let value = value << 1 | bit as u16;
+                if bits < 15 {
+                    EepromState::Write {
+                        addr,
+                        value,
+                        bits: bits + 1,
+                    }
+                } else {
+                    if self.write_enabled {
+                        match addr {
+                            Some(addr) => self.set_word(addr, value),
+                            None => (0..EEPROM_WORDS).for_each(|addr| self.set_word(addr, value)),
+                        }
+                    }
+                    self.data_out = true;
+                    EepromState::Idle
+                }
+            }
+        }
+    }
+
+    fn command(&mut self, value: u16) -> EepromState {
+        let addr = (value & 0x7F) as usize;
+        match value >> 8 & 0x03 {
+            // A dummy 0 comes out before the data
+            0b10 => {
+                self.data_out = false;
+                EepromState::Read { addr, bit: 0 }
+            }
+            0b01 => EepromState::Write {
+                addr: Some(addr),
+                value: 0,
+                bits: 0,
+            },
+            0b11 => {
+                if self.write_enabled {
+                    self.set_word(addr, 0xFFFF);
+                }
+                EepromState::Idle
+            }
+            // The rest are picked by the top 2 address bits
+            _ => match value >> 6 & 0x03 {
+                0b11 => {
+                    self.write_enabled = true;
+                    EepromState::Idle
+                }
+                0b00 => {
+                    self.write_enabled = false;
+                    EepromState::Idle
+                }
+                0b10 => {
+                    if self.write_enabled {
+                        self.data.fill(0xFF);
+                    }
+                    EepromState::Idle
+                }
+                _ => EepromState::Write {
+                    addr: None,
+                    value: 0,
+                    bits: 0,
+                },
+            },
+        }
+    }
+}
+
+// Used by Kirby Tilt 'n' Tumble, with an accelerometer and an EEPROM in place of RAM
+#[derive(Debug)]
+pub struct Mbc7 {
+    rom: Vec<u8>,
+    eeprom: Eeprom,
+    // RAM is only mapped while both enables are set
+    ram_enabled: [bool; 2],
+    rom_bank: u8,
+    // Where the accelerometer is now, and the values last latched for the game to read
+    tilt: (u16, u16),
+    latched: (u16, u16),
+}
+
+impl Mbc7 {
+    pub fn new(rom: Vec<u8>) -> Mbc7 {
+        Mbc7 {
+            rom,
+            eeprom: Eeprom {
+                data: vec![0xFF; EEPROM_SIZE],
+                state: EepromState::Idle,
+                write_enabled: false,
+                pins: 0,
+                data_out: true,
+            },
+            ram_enabled: [false; 2],
+            rom_bank: 0x01,
+            tilt: (ACCELEROMETER_CENTRE, ACCELEROMETER_CENTRE),
+            latched: (ACCELEROMETER_ERASED, ACCELEROMETER_ERASED),
+        }
+    }
+}
+
+impl Mbc for Mbc7 {
+    fn read_rom(&self, addr: Address) -> u8 {
+        match addr {
+            0x0000..=0x3FFF => read_rom_bank(&self.rom, 0, addr),
+            _ => read_rom_bank(&self.rom, self.rom_bank as usize, addr),
+        }
+    }
+
+    fn write_rom(&mut self, addr: Address, data: u8) {
+        match addr {
+            0x0000..=0x1FFF => self.ram_enabled[0] = data == 0x0A,
+            0x2000..=0x3FFF => self.rom_bank = data & 0x7F,
+            0x4000..=0x5FFF => self.ram_enabled[1] = data == 0x40,
+            _ => {}
+        }
+    }
+
+    // The registers repeat every 16 bytes through 0xA000-0xAFFF
+    fn read_ram(&self, addr: Address) -> u8 {
+        if self.ram_enabled != [true, true] || addr >= 0xB000 {
+            return OPEN_BUS;
+        }
+        let (x, y) = self.latched;
+        match addr >> 4 & 0x0F {
+            0x2 => x as u8,
+            0x3 => (x >> 8) as u8,
+            0x4 => y as u8,
+            0x5 => (y >> 8) as u8,
+            0x6 => 0x00,
+            0x8 => self.eeprom.read(),
+            _ => OPEN_BUS,
+        }
+    }
+
+    fn write_ram(&mut self, addr: Address, data: u8) {
+        if self.ram_enabled != [true, true] || addr >= 0xB000 {
+            return;
+        }
+        match (addr >> 4 & 0x0F, data) {
+            (0x0, 0x55) => self.latched = (ACCELEROMETER_ERASED, ACCELEROMETER_ERASED),
+            // Only latches once erased
+            (0x1, 0xAA) if self.latched.0 == ACCELEROMETER_ERASED => self.latched = self.tilt,
+            (0x8, _) => self.eeprom.write(data),
+            _ => {}
+        }
+    }
+
+    fn set_tilt(&mut self, x: f32, y: f32) {
+        let axis = |g: f32| (ACCELEROMETER_CENTRE as f32 + g * ACCELEROMETER_G) as u16;
+        self.tilt = (axis(x), axis(y));
+    }
+
+    // Saves hold the EEPROM
+    fn ram(&self) -> &[u8] {
+        &self.eeprom.data
+    }
+
+    fn ram_mut(&mut self) -> &mut [u8] {
+        &mut self.eeprom.data
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn enabled() -> Mbc7 {
+        let mut mbc = Mbc7::new(vec![0; 0x8000]);
+        mbc.write_rom(0x0000, 0x0A);
+        mbc.write_rom(0x4000, 0x40);
+        mbc
+    }
+
+    // Clocks bits into the EEPROM with CS held high, returning what DO showed after each
+    fn send(mbc: &mut Mbc7, value: u32, bits: u8) -> u32 {
+        let mut output = 0;
+        for bit in (0..bits).rev() {
+            let di = match value >> bit & 0x01 {
+                0 => 0,
+                _ => EEPROM_DI,
+            };
+            mbc.write_ram(0xA080, EEPROM_CS | di);
+            mbc.write_ram(0xA080, EEPROM_CS | EEPROM_CLK | di);
+            output = output << 1 | (mbc.read_ram(0xA080) & EEPROM_DO) as u32;
+        }
+        mbc.write_ram(0xA080, EEPROM_CS);
+        output
+    }
+
+    // Start bit, opcode and address
+    fn command(opcode: u32, addr: u32) -> u32 {
+        1 << 10 | opcode << 8 | addr
+    }
+
+    fn deselect(mbc: &mut Mbc7) {
+        mbc.write_ram(0xA080, 0x00);
+    }
+
+    #[test]
+    fn test_enable() {
+        let mut mbc = Mbc7::new(vec![0; 0x8000]);
+
+        mbc.write_rom(0x0000, 0x0A);
+        assert_eq!(0xFF, mbc.read_ram(0xA060));
+        mbc.write_rom(0x4000, 0x40);
+        assert_eq!(0x00, mbc.read_ram(0xA060));
+        assert_eq!(0xFF, mbc.read_ram(0xB060));
+    }
+
+    #[test]
+    fn test_accelerometer() {
+        let mut mbc = enabled();
+        mbc.set_tilt(1.0, -0.5);
+
+        assert_eq!(0x00, mbc.read_ram(0xA020));
+        assert_eq!(0x80, mbc.read_ram(0xA030));
+
+        mbc.write_ram(0xA000, 0x55);
+        mbc.write_ram(0xA010, 0xAA);
+        assert_eq!(
+            ACCELEROMETER_CENTRE + 112,
+            u16::from_le_bytes([mbc.read_ram(0xA020), mbc.read_ram(0xA030)])
+        );
+        assert_eq!(
+            ACCELEROMETER_CENTRE - 56,
+            u16::from_le_bytes([mbc.read_ram(0xA040), mbc.read_ram(0xA050)])
+        );
+
+        // Doesn't latch again until erased
+        mbc.set_tilt(0.0, 0.0);
+        mbc.write_ram(0xA010, 0xAA);
+        assert_eq!(0x82, mbc.read_ram(0xA030));
+    }
+
+    #[test]
+    fn test_eeprom() {
+        let mut mbc = enabled();
+
+        // EWEN, then WRITE 0x1234 to word 5
+        send(&mut mbc, command(0b00, 0xC0), 11);
+        deselect(&mut mbc);
+        send(&mut mbc, command(0b01, 0x05), 11);
+        send(&mut mbc, 0x1234, 16);
+        deselect(&mut mbc);
+        assert_eq!(0x1234, mbc.eeprom.word(5));
+
+        // READ word 5, after a dummy 0
+        assert_eq!(0, send(&mut mbc, command(0b10, 0x05), 11) & 0x01);
+        assert_eq!(0x1234, send(&mut mbc, 0, 16));
+        deselect(&mut mbc);
+
+        // EWDS protects it again
+        send(&mut mbc, command(0b00, 0x00), 11);
+        deselect(&mut mbc);
+        send(&mut mbc, command(0b11, 0x05), 11);
+        deselect(&mut mbc);
+        assert_eq!(0x1234, mbc.eeprom.word(5));
+    }
+}
@@ -0,0 +1,209 @@
+use super::{ram_offset, read_rom_bank, CartridgeHeader, Mapper, Mbc, ROM_BANK_SIZE};
+use crate::data::Address;
+use crate::memory::OPEN_BUS;
+
+// The menu lives in the last 32KiB of the ROM, which is what's mapped at power up
+const MENU_SIZE: usize = ROM_BANK_SIZE * 2;
+
+// A multicart controller that starts out showing a menu. The menu sets up which part of the ROM
+// and RAM the chosen game can see and then locks the controller, which from there on acts like
+// an MBC1 limited to that game
+#[derive(Debug)]
+pub struct Mmm01 {
+    rom: Vec<u8>,
+    ram: Vec<u8>,
+    // Set by the menu once it has picked a game, after which the game's banks are mapped and the
+    // outer bank bits and masks can't be changed
+    mapped: bool,
+    ram_enabled: bool,
+    // 9-bit ROM bank, the lower 5 bits set by the game as on an MBC1
+    rom_bank: u16,
+    // Which of bits 1-4 of the ROM bank the game can't change
+    rom_bank_mask: u8,
+    ram_bank: u8,
+    // Which of the 2 RAM bank bits the game can't change
+    ram_bank_mask: u8,
+    mode: bool,
+    mode_locked: bool,
+}
+
+impl Mmm01 {
+    pub fn new(rom: Vec<u8>, ram_size: usize) -> Mmm01 {
+        Mmm01 {
+            rom,
+            ram: vec![0; ram_size],
+            mapped: false,
+            ram_enabled: false,
+            rom_bank: 0x00,
+            rom_bank_mask: 0x00,
+            ram_bank: 0x00,
+            ram_bank_mask: 0x00,
+            mode: false,
+            mode_locked: false,
+        }
+    }
+
+    // The header at the start of the ROM is the first game's, the one for the cartridge itself
+    // is in the menu at the end
+    pub fn header(rom: &[u8]) -> Option<CartridgeHeader> {
+        let menu = rom.get(rom.len().checked_sub(MENU_SIZE)?..)?;
+        CartridgeHeader::parse(menu)
+            .ok()
+            .filter(|header| header.cartridge_type.mapper == Mapper::Mmm01)
+    }
+
+    // The lower 5 bits of the bank that the game can still change
+    fn writable_bits(&self) -> u16 {
+        0x1F & !((self.rom_bank_mask as u16) << 1)
+    }
+
+    fn low_rom_bank(&self) -> usize {
+        let bank = self.rom_bank & !self.writable_bits();
+        match self.mode {
+            true => (bank | (self.ram_bank as u16 & 0x03) << 5) as usize,
+            false => bank as usize,
+        }
+    }
+
+    fn high_rom_bank(&self) -> usize {
+        // Bank 0 translation only looks at the bits the game can write
+        let writable = self.writable_bits();
+        match self.rom_bank & writable {
+            0 => (self.rom_bank | 0x01) as usize,
+            _ => self.rom_bank as usize,
+        }
+    }
+}
+
+impl Mbc for Mmm01 {
+    fn read_rom(&self, addr: Address) -> u8 {
+        if !self.mapped {
+            let menu = self.rom.len().saturating_sub(MENU_SIZE) / ROM_BANK_SIZE;
+            return read_rom_bank(&self.rom, menu + (addr as usize >> 14), addr);
+        }
+        match addr {
+            0x0000..=0x3FFF => read_rom_bank(&self.rom, self.low_rom_bank(), addr),
+            _ => read_rom_bank(&self.rom, self.high_rom_bank(), addr),
+        }
+    }
+
+    fn write_rom(&mut self, addr: Address, data: u8) {
+        match addr {
+            0x0000..=0x1FFF => {
+                self.ram_enabled = data & 0x0F == 0x0A;
+                if !self.mapped {
+                    self.ram_bank_mask = data >> 4 & 0x03;
+                    self.mapped = data & 0x40 != 0;
+                }
+            }
+            0x2000..=0x3FFF => {
+                let writable = match self.mapped {
+                    true => self.writable_bits(),
+                    false => 0x7F,
+                };
+                self.rom_bank = (self.rom_bank & !writable) | (data as u16 & writable);
+            }
+            0x4000..=0x5FFF => {
+                let writable = match self.mapped {
+                    true => 0x03 & !self.ram_bank_mask,
+                    false => 0x03,
+                };
+                self.ram_bank = (self.ram_bank & !writable) | (data & writable);
+                if !self.mapped {
+                    self.rom_bank = (self.rom_bank & 0x7F) | ((data >> 4 & 0x03) as u16) << 7;
+                }
+            }
+            _ => {
+                if !self.mode_locked {
+                    self.mode = data & 0x01 != 0;
+                }
+                if !self.mapped {
+                    self.rom_bank_mask = data >> 2 & 0x0F;
+                    self.mode_locked = data & 0x40 != 0;
+                }
+            }
+        }
+    }
+
+    fn read_ram(&self, addr: Address) -> u8 {
+        match ram_offset(&self.ram, self.ram_bank as usize, addr) {
+            Some(offset) if self.ram_enabled => self.ram[offset],
+            _ => OPEN_BUS,
+        }
+    }
+
+    fn write_ram(&mut self, addr: Address, data: u8) {
+        match ram_offset(&self.ram, self.ram_bank as usize, addr) {
+            Some(offset) if self.ram_enabled => self.ram[offset] = data,
+            _ => {}
+        }
+    }
+
+    fn ram(&self) -> &[u8] {
+        &self.ram
+    }
+
+    fn ram_mut(&mut self) -> &mut [u8] {
+        &mut self.ram
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
+mod tests {
+    use super::*;
+    use crate::cartridge::{banked_rom, ROM_BANK_SIZE};
+
+    #[test]
+    fn test_menu() {
+        let mut mbc = Mmm01::new(banked_rom(64, ROM_BANK_SIZE), 0);
+
+        assert_eq!(62, mbc.read_rom(0x0000));
+        assert_eq!(63, mbc.read_rom(0x4000));
+
+        // Setting up the banks doesn't change anything until the game is mapped
+        mbc.write_rom(0x2000, 0x10);
+        assert_eq!(63, mbc.read_rom(0x4000));
+    }
+
+    #[test]
+    fn test_mapped() {
+        let mut mbc = Mmm01::new(banked_rom(64, ROM_BANK_SIZE), 0x2000);
+
+        // A 128KiB game at bank 0x10, masking bits 3 and 4 to leave the game the lower 3 bits
+        mbc.write_rom(0x2000, 0x10);
+        mbc.write_rom(0x6000, 0x0C << 2);
+        mbc.write_rom(0x0000, 0x40);
+        assert_eq!(true, mbc.mapped);
+
+        assert_eq!(0x10, mbc.read_rom(0x0000));
+        assert_eq!(0x11, mbc.read_rom(0x4000));
+
+        mbc.write_rom(0x2000, 0x05);
+        assert_eq!(0x15, mbc.read_rom(0x4000));
+
+        // The masked bits can't be changed by the game any more
+        mbc.write_rom(0x2000, 0x0F);
+        assert_eq!(0x17, mbc.read_rom(0x4000));
+        mbc.write_rom(0x6000, 0x00);
+        mbc.write_rom(0x2000, 0x00);
+        assert_eq!(0x11, mbc.read_rom(0x4000));
+
+        mbc.write_rom(0x0000, 0x0A);
+        mbc.write_ram(0xA000, 0xAA);
+        assert_eq!(0xAA, mbc.read_ram(0xA000));
+    }
+
+    #[test]
+    fn test_header() {
+        let mut rom = banked_rom(8, ROM_BANK_SIZE);
+        assert_eq!(None, Mmm01::header(&rom));
+
+        let menu = rom.len() - MENU_SIZE;
+        rom[menu + 0x0147] = 0x0D;
+        rom[menu + 0x0148] = 0x02;
+        rom[menu + 0x0149] = 0x00;
+        let header = Mmm01::header(&rom).unwrap();
+        assert_eq!(Mapper::Mmm01, header.cartridge_type.mapper);
+    }
+}
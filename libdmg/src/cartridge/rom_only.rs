@@ -0,0 +1,49 @@
+use super::{ram_offset, Mbc};
+use crate::data::Address;
+use crate::memory::OPEN_BUS;
+
+// A cartridge with no memory bank controller, just 32KiB of ROM and maybe 8KiB of RAM wired
+// straight to the bus
+#[derive(Debug)]
+pub struct RomOnly {
+    rom: Vec<u8>,
+    ram: Vec<u8>,
+}
+
+impl RomOnly {
+    pub fn new(rom: Vec<u8>, ram_size: usize) -> RomOnly {
+        RomOnly {
+            rom,
+            ram: vec![0; ram_size],
+        }
+    }
+}
+
+impl Mbc for RomOnly {
+    fn read_rom(&self, addr: Address) -> u8 {
+        *self.rom.get(addr as usize).unwrap_or(&OPEN_BUS)
+    }
+
+    fn write_rom(&mut self, _addr: Address, _data: u8) {}
+
+    fn read_ram(&self, addr: Address) -> u8 {
+        match ram_offset(&self.ram, 0, addr) {
+            Some(offset) => self.ram[offset],
+            None => OPEN_BUS,
+        }
+    }
+
+    fn write_ram(&mut self, addr: Address, data: u8) {
+        if let Some(offset) = ram_offset(&self.ram, 0, addr) {
+            self.ram[offset] = data;
+        }
+    }
+
+    fn ram(&self) -> &[u8] {
+        &self.ram
+    }
+
+    fn ram_mut(&mut self) -> &mut [u8] {
+        &mut self.ram
+    }
+}
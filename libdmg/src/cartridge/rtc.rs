@@ -0,0 +1,386 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::error::CartridgeError;
+
+// The clock counts off a 32.768kHz crystal, which is an exact fraction of the CPU clock
+const CYCLES_PER_SECOND: u32 = 4_194_304;
+
+// The footer other emulators append to the save RAM: the live and latched registers as 32-bit
+// little endian values followed by a UNIX timestamp, which older versions stored in 32 bits
+pub const RTC_FOOTER_SIZE: usize = 48;
+const RTC_FOOTER_SIZE_32: usize = 44;
+
+const DAY_HIGH: u8 = 0x01;
+const HALT: u8 = 0x40;
+const DAY_CARRY: u8 = 0x80;
+
+// What moves the clock forward
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RtcClock {
+    // Counts emulated cycles, so it runs at the emulator's speed and is deterministic
+    Emulated,
+    // Follows the host's clock, including the time between sessions
+    Host,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct Counters {
+    seconds: u8,
+    minutes: u8,
+    hours: u8,
+    days: u16,
+    halt: bool,
+    day_carry: bool,
+}
+
+impl Counters {
+    fn read(&self, register: u8) -> u8 {
+        match register {
+            0x08 => self.seconds,
+            0x09 => self.minutes,
+            0x0A => self.hours,
+            0x0B => self.days as u8,
+            _ => {
+                let mut value = (self.days >> 8) as u8 & DAY_HIGH;
+                if self.halt {
+                    value |= HALT;
+                }
+                if self.day_carry {
+                    value |= DAY_CARRY;
+                }
+                value
+            }
+        }
+    }
+
+    fn write(&mut self, register: u8, data: u8) {
+        match register {
+            0x08 => self.seconds = data & 0x3F,
+            0x09 => self.minutes = data & 0x3F,
+            0x0A => self.hours = data & 0x1F,
+            0x0B => self.days = (self.days & 0x100) | data as u16,
+            _ => {
+                self.days = (self.days & 0xFF) | ((data & DAY_HIGH) as u16) << 8;
+                self.halt = data & HALT != 0;
+                self.day_carry = data & DAY_CARRY != 0;
+            }
+        }
+    }
+
+    // Each counter only rolls over when it reaches its limit exactly. One set past its limit
+    // keeps counting until its bits overflow, without carrying into the next
+    fn tick_second(&mut self) {
+        if !increment(&mut self.seconds, 60, 0x3F)
+            || !increment(&mut self.minutes, 60, 0x3F)
+            || !increment(&mut self.hours, 24, 0x1F)
+        {
+            return;
+        }
+        self.days += 1;
+        if self.days == 0x200 {
+            self.days = 0;
+            self.day_carry = true;
+        }
+    }
+
+    fn in_range(&self) -> bool {
+        self.seconds < 60 && self.minutes < 60 && self.hours < 24
+    }
+
+    fn advance(&mut self, mut seconds: u64) {
+        if self.halt {
+            return;
+        }
+
+        // Step through any counters set out of range until they wrap, which is never more than
+        // a few hours of ticks
+        while seconds > 0 && !self.in_range() {
+            self.tick_second();
+            seconds -= 1;
+        }
+
+        let total = self.seconds as u64
+            + self.minutes as u64 * 60
+            + self.hours as u64 * 3600
+            + self.days as u64 * 86400
+            + seconds;
+        let days = total / 86400;
+        self.seconds = (total % 60) as u8;
+        self.minutes = (total / 60 % 60) as u8;
+        self.hours = (total / 3600 % 24) as u8;
+        self.days = (days % 0x200) as u16;
+        if days >= 0x200 {
+            self.day_carry = true;
+        }
+    }
+}
+
+// Returns whether the counter wrapped around and should carry
+fn increment(counter: &mut u8, limit: u8, mask: u8) -> bool {
+    let next = counter.wrapping_add(1);
+    if next == limit {
+        *counter = 0;
+        true
+    } else {
+        *counter = next & mask;
+        false
+    }
+}
+
+fn unix_time() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|time| time.as_secs())
+        .unwrap_or(0)
+}
+
+// The real time clock on MBC3 cartridges. The registers are written directly but read through a
+// copy that only updates when the game latches it
+#[derive(Clone, Debug, PartialEq)]
+pub struct Rtc {
+    live: Counters,
+    latched: Counters,
+    clock: RtcClock,
+    // Cycles counted towards the next second
+    cycles: u32,
+    // When the host clock was last caught up with
+    timestamp: u64,
+}
+
+impl Default for Rtc {
+    fn default() -> Rtc {
+        Rtc {
+            live: Counters::default(),
+            latched: Counters::default(),
+            clock: RtcClock::Emulated,
+            cycles: 0,
+            timestamp: unix_time(),
+        }
+    }
+}
+
+impl Rtc {
+    pub fn clock(&self) -> RtcClock {
+        self.clock
+    }
+
+    pub fn set_clock(&mut self, clock: RtcClock) {
+        self.timestamp = unix_time();
+        self.clock = clock;
+    }
+
+    pub(crate) fn tick(&mut self, cycles: u8) {
+        if self.clock != RtcClock::Emulated || self.live.halt {
+            return;
+        }
+        self.cycles += cycles as u32;
+        if self.cycles >= CYCLES_PER_SECOND {
+            self.cycles -= CYCLES_PER_SECOND;
+            self.live.tick_second();
+        }
+    }
+
+    pub(crate) fn latch(&mut self) {
+        self.sync(unix_time());
+        self.latched = self.live;
+    }
+
+    pub(crate) fn read(&self, register: u8) -> u8 {
+        self.latched.read(register)
+    }
+
+    pub(crate) fn write(&mut self, register: u8, data: u8) {
+        self.sync(unix_time());
+        // Writing the seconds restarts the current second
+        if register == 0x08 {
+            self.cycles = 0;
+        }
+        self.live.write(register, data);
+    }
+
+    fn sync(&mut self, now: u64) {
+        if self.clock == RtcClock::Host {
+            self.live.advance(now.saturating_sub(self.timestamp));
+        }
+        self.timestamp = now;
+    }
+
+    // The clock in the footer format, stamped with the current time so it can be caught up when
+    // loaded again
+    pub fn save(&mut self) -> [u8; RTC_FOOTER_SIZE] {
+        self.sync(unix_time());
+
+        let mut footer = [0; RTC_FOOTER_SIZE];
+        let values = [self.live, self.latched]
+            .into_iter()
+            .flat_map(|counters| (0x08..=0x0C).map(move |register| counters.read(register)));
+        for (chunk, value) in footer.chunks_exact_mut(4).zip(values) {
+            chunk.copy_from_slice(&(value as u32).to_le_bytes());
+        }
+        footer[40..].copy_from_slice(&self.timestamp.to_le_bytes());
+        footer
+    }
+
+    // Restores the clock from a footer written by save or another emulator. With the host clock
+    // the time spent switched off passes at the next access
+    pub fn load(&mut self, footer: &[u8]) -> Result<(), CartridgeError> {
+        let timestamp = match footer.len() {
+            RTC_FOOTER_SIZE => u64::from_le_bytes(footer[40..48].try_into().unwrap()),
+            RTC_FOOTER_SIZE_32 => u32::from_le_bytes(footer[40..44].try_into().unwrap()) as u64,
+            length => return Err(CartridgeError::InvalidRtcData { length }),
+        };
+
+        let mut counters = [Counters::default(); 2];
+        for (index, chunk) in footer[..40].chunks_exact(4).enumerate() {
+            let value = u32::from_le_bytes(chunk.try_into().unwrap()) as u8;
+            counters[index / 5].write(0x08 + (index % 5) as u8, value);
+        }
+        self.live = counters[0];
+        self.latched = counters[1];
+        self.timestamp = timestamp;
+        self.cycles = 0;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tick() {
+        let mut rtc = Rtc::default();
+        rtc.write(0x08, 59);
+        rtc.write(0x09, 59);
+        rtc.write(0x0A, 23);
+        rtc.write(0x0B, 0xFF);
+
+        for _ in 0..CYCLES_PER_SECOND / 4 {
+            rtc.tick(4);
+        }
+
+        // Reads come from the latched copy, still as it was at power up
+        assert_eq!(0, rtc.read(0x09));
+        rtc.latch();
+        assert_eq!(0, rtc.read(0x08));
+        assert_eq!(0, rtc.read(0x09));
+        assert_eq!(0, rtc.read(0x0A));
+        assert_eq!(0x00, rtc.read(0x0B));
+        assert_eq!(DAY_HIGH, rtc.read(0x0C));
+    }
+
+    #[test]
+    fn test_halt() {
+        let mut rtc = Rtc::default();
+        rtc.write(0x0C, HALT);
+
+        for _ in 0..CYCLES_PER_SECOND / 4 {
+            rtc.tick(4);
+        }
+
+        rtc.latch();
+        assert_eq!(0, rtc.read(0x08));
+        assert_eq!(HALT, rtc.read(0x0C));
+    }
+
+    #[test]
+    fn test_overflow() {
+        let mut counters = Counters {
+            seconds: 59,
+            minutes: 59,
+            hours: 23,
+            days: 0x1FF,
+            ..Counters::default()
+        };
+        counters.tick_second();
+        assert_eq!(0, counters.days);
+        assert_eq!(true, counters.day_carry);
+
+        // Out of range values wrap at their bit width without carrying
+        let mut counters = Counters {
+            seconds: 63,
+            ..Counters::default()
+        };
+        counters.tick_second();
+        assert_eq!(0, counters.seconds);
+        assert_eq!(0, counters.minutes);
+    }
+
+    #[test]
+    fn test_advance() {
+        let mut counters = Counters::default();
+        counters.advance(86400 * 2 + 3600 * 5 + 60 * 4 + 3);
+        assert_eq!(
+            (3, 4, 5, 2),
+            (
+                counters.seconds,
+                counters.minutes,
+                counters.hours,
+                counters.days
+            )
+        );
+
+        let mut stepped = Counters {
+            seconds: 62,
+            minutes: 61,
+            ..Counters::default()
+        };
+        let mut advanced = stepped;
+        for _ in 0..10000 {
+            stepped.tick_second();
+        }
+        advanced.advance(10000);
+        assert_eq!(stepped, advanced);
+
+        let mut counters = Counters::default();
+        counters.advance(86400 * 0x200);
+        assert_eq!(0, counters.days);
+        assert_eq!(true, counters.day_carry);
+    }
+
+    #[test]
+    fn test_host_clock() {
+        let mut rtc = Rtc::default();
+        rtc.set_clock(RtcClock::Host);
+        let start = rtc.timestamp;
+
+        // Cycles don't count
+        rtc.tick(255);
+        rtc.sync(start + 90);
+        rtc.latched = rtc.live;
+        assert_eq!(30, rtc.read(0x08));
+        assert_eq!(1, rtc.read(0x09));
+    }
+
+    #[test]
+    fn test_save_load() {
+        let mut rtc = Rtc::default();
+        rtc.write(0x08, 12);
+        rtc.write(0x0B, 0x34);
+        rtc.write(0x0C, DAY_HIGH | DAY_CARRY);
+        rtc.latch();
+        rtc.write(0x09, 56);
+
+        let footer = rtc.save();
+        assert_eq!([12, 0, 0, 0], footer[0..4]);
+        assert_eq!([56, 0, 0, 0], footer[4..8]);
+        assert_eq!([0x81, 0, 0, 0], footer[16..20]);
+        assert_eq!([0, 0, 0, 0], footer[24..28]);
+
+        let mut loaded = Rtc::default();
+        loaded.load(&footer).unwrap();
+        assert_eq!(rtc.live, loaded.live);
+        assert_eq!(rtc.latched, loaded.latched);
+        assert_eq!(rtc.timestamp, loaded.timestamp);
+
+        // The older footer with a 32-bit timestamp
+        loaded.load(&footer[..RTC_FOOTER_SIZE_32]).unwrap();
+        assert_eq!(rtc.live, loaded.live);
+
+        assert_eq!(
+            Err(CartridgeError::InvalidRtcData { length: 40 }),
+            loaded.load(&footer[..40])
+        );
+    }
+}
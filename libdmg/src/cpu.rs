@@ -1,20 +1,891 @@
-use super::instructions::{self, execute_instruction};
-use super::memory::MemoryBus;
-use super::registers::{Register, Registers};
+use super::cartridge::CgbSupport;
+use super::data::Address;
+use super::error::{BootRomError, CartridgeError, CpuError};
+use super::instructions;
+use super::interrupts::{self, Interrupt, INTERRUPT_FLAG};
+use super::memory::{Bus, MemoryBus, TimedBus};
+use super::model::Model;
+use super::registers::{PowerMode, Registers};
 
-#[derive(Default)]
-pub struct CPU {
-    memory: MemoryBus,
+// Number of cycles the DMG takes to draw a single frame
+pub const CYCLES_PER_FRAME: u64 = 70224;
+
+// While halted or stopped the CPU idles one M-cycle at a time
+const IDLE_CYCLES: u8 = 4;
+
+const KEY1: Address = 0xFF4D;
+
+// What the CPU does when it runs into an opcode that doesn't exist on the SM83
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub enum IllegalOpcodePolicy {
+    // Stop and return a CpuError::IllegalOpcode
+    #[default]
+    Error,
+    // Hang forever without servicing interrupts, like the hardware does
+    LockUp,
+    // Skip the opcode as if it were a NOP
+    Nop,
+}
+
+// How finely the CPU advances the rest of the system while it runs
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub enum Timing {
+    // Tick the bus once after each instruction has finished
+    #[default]
+    Instruction,
+    // Tick the bus one M-cycle at a time as each memory access within an instruction happens
+    MCycle,
+}
+
+// The CPU can drive any memory system, the Game Boy's own is MemoryBus
+pub struct CPU<B: Bus = MemoryBus> {
+    memory: B,
     registers: Registers,
+    cycles: u64,
+    illegal_opcode_policy: IllegalOpcodePolicy,
+    timing: Timing,
+    // Only CGB models have the double speed mode switched to by STOP
+    cgb_mode: bool,
+    double_speed: bool,
+}
+
+impl Default for CPU {
+    fn default() -> CPU {
+        CPU::new(MemoryBus::default())
+    }
 }
 
 impl CPU {
-    pub fn tick(&mut self) {
-        self.execute_instruction();
-        // TODO: interrupt handling
+    pub fn load_rom(&mut self, rom: Vec<u8>) -> Result<(), CartridgeError> {
+        self.memory.load_rom(rom)
+    }
+
+    pub fn set_cgb_mode(&mut self, cgb_mode: bool) {
+        self.cgb_mode = cgb_mode;
+        self.memory.set_cgb_mode(cgb_mode);
+    }
+
+    // Start from power on with the model's boot ROM mapped, which hands over to the cartridge by
+    // itself. CGB boot ROMs run in CGB mode, which stays on even for DMG games since the
+    // compatibility mode they switch to through KEY0 isn't emulated
+    pub fn load_boot_rom(&mut self, model: Model, boot_rom: Vec<u8>) -> Result<(), BootRomError> {
+        self.memory.load_boot_rom(model, boot_rom)?;
+        self.registers = Registers::default();
+        self.set_cgb_mode(model.is_cgb());
+        Ok(())
+    }
+
+    // Start at the cartridge's entry point with everything set up as the model's boot ROM leaves
+    // it. Load the ROM first, CGB models only stay in CGB mode for games that support it
+    pub fn skip_boot(&mut self, model: Model) {
+        let (cgb_game, header_checksum) = match self.memory.cartridge() {
+            Some(cartridge) => (
+                cartridge.header().cgb_support != CgbSupport::None,
+                cartridge.header().header_checksum,
+            ),
+            None => (false, 0x00),
+        };
+        let cgb_mode = model.is_cgb() && cgb_game;
+
+        self.set_cgb_mode(cgb_mode);
+        self.memory.skip_boot(model);
+        self.registers = Registers::post_boot(model, cgb_mode, header_checksum);
+    }
+}
+
+impl<B: Bus> CPU<B> {
+    pub fn new(bus: B) -> CPU<B> {
+        CPU {
+            memory: bus,
+            registers: Registers::default(),
+            cycles: 0,
+            illegal_opcode_policy: IllegalOpcodePolicy::default(),
+            timing: Timing::default(),
+            cgb_mode: false,
+            double_speed: false,
+        }
+    }
+
+    pub fn bus(&self) -> &B {
+        &self.memory
+    }
+
+    pub fn bus_mut(&mut self) -> &mut B {
+        &mut self.memory
+    }
+
+    pub fn tick(&mut self) -> Result<(), CpuError> {
+        self.step()?;
+        Ok(())
+    }
+
+    // Run a single instruction, interrupt dispatch or idle M-cycle, returning the number of cycles
+    // it took
+    pub fn step(&mut self) -> Result<u8, CpuError> {
+        let awake = match self.registers.power_mode {
+            PowerMode::Running => true,
+            // Any pending interrupt wakes the CPU from HALT, even with IME unset
+            PowerMode::Halted => interrupts::pending_interrupts(&self.memory) != 0,
+            PowerMode::Stopped => self.wake_from_stop(),
+            PowerMode::Locked => false,
+        };
+
+        let cycles = if awake {
+            self.registers.power_mode = PowerMode::Running;
+            match self.service_interrupt() {
+                0 => self.execute_instruction()?,
+                cycles => cycles,
+            }
+        } else {
+            // The system clock is stopped along with the CPU in STOP
+            if self.registers.power_mode != PowerMode::Stopped {
+                self.memory.tick(IDLE_CYCLES);
+            }
+            IDLE_CYCLES
+        };
+
+        self.cycles += cycles as u64;
+        Ok(cycles)
+    }
+
+    pub fn set_illegal_opcode_policy(&mut self, policy: IllegalOpcodePolicy) {
+        self.illegal_opcode_policy = policy;
+    }
+
+    pub fn set_timing(&mut self, timing: Timing) {
+        self.timing = timing;
+    }
+
+    pub fn double_speed(&self) -> bool {
+        self.double_speed
+    }
+
+    fn execute_instruction(&mut self) -> Result<u8, CpuError> {
+        // EI only takes effect once the instruction after it has finished
+        let enable_interrupts = self.registers.ime_scheduled;
+
+        let mut bus = TimedBus::new(&mut self.memory, self.timing == Timing::MCycle);
+        let code = bus.read(self.registers.pc);
+
+        // The HALT bug reads the opcode without incrementing PC, so its first operand byte (or
+        // the whole instruction for single byte ones) is read again
+        if self.registers.halt_bug {
+            self.registers.pc = self.registers.pc.wrapping_sub(1);
+            self.registers.halt_bug = false;
+        }
+
+        let result = instructions::execute_instruction(code as u16, &mut self.registers, &mut bus);
+        let cycles = match (result, self.illegal_opcode_policy) {
+            (Ok(cycles), _) => cycles,
+            (Err(CpuError::IllegalOpcode { .. }), IllegalOpcodePolicy::LockUp) => {
+                self.registers.power_mode = PowerMode::Locked;
+                IDLE_CYCLES
+            }
+            (Err(CpuError::IllegalOpcode { .. }), IllegalOpcodePolicy::Nop) => {
+                self.registers.pc = self.registers.pc.wrapping_add(1);
+                IDLE_CYCLES
+            }
+            (Err(error), _) => {
+                // The opcode fetch has still happened, so keep the cycle count in step with the
+                // bus
+                bus.finish(IDLE_CYCLES);
+                self.cycles += IDLE_CYCLES as u64;
+                return Err(error);
+            }
+        };
+        bus.finish(cycles);
+
+        // A DI straight after EI cancels the scheduled enable
+        if enable_interrupts && self.registers.ime_scheduled {
+            self.registers.ime = true;
+            self.registers.ime_scheduled = false;
+        }
+
+        Ok(cycles)
+    }
+
+    fn wake_from_stop(&mut self) -> bool {
+        // On CGB, STOP with a speed switch armed in KEY1 changes speed instead of stopping
+        if self.cgb_mode && self.memory.read(KEY1) & 0x01 != 0 {
+            self.double_speed = !self.double_speed;
+            self.memory
+                .write(KEY1, if self.double_speed { 0x80 } else { 0x00 });
+            return true;
+        }
+
+        // Pressing a button requests the joypad interrupt, which wakes the CPU even if disabled
+        self.memory.read(INTERRUPT_FLAG) & Interrupt::Joypad.mask() != 0
+    }
+
+    pub fn read_memory(&self, addr: Address) -> u8 {
+        self.memory.read(addr)
+    }
+
+    pub fn write_memory(&mut self, addr: Address, data: u8) {
+        self.memory.write(addr, data);
     }
 
-    fn execute_instruction(&mut self) {
-        let cycles = instructions::execute_instruction(0x00, &mut self.registers, &mut self.memory);
+    pub fn request_interrupt(&mut self, interrupt: Interrupt) {
+        interrupts::request_interrupt(&mut self.memory, interrupt);
+    }
+
+    // Jump to the handler of the highest priority pending interrupt if IME is set, returning the
+    // number of cycles taken or 0 if no interrupt was dispatched
+    fn service_interrupt(&mut self) -> u8 {
+        if !self.registers.ime || interrupts::pending_interrupts(&self.memory) == 0 {
+            return 0;
+        }
+
+        self.registers.ime = false;
+        // EI straight before HALT with an interrupt pending leaves the HALT bug for the dispatch,
+        // which pushes the address of the HALT so it runs again after the handler returns
+        if self.registers.halt_bug {
+            self.registers.pc = self.registers.pc.wrapping_sub(1);
+            self.registers.halt_bug = false;
+        }
+        let [low, high] = self.registers.pc.to_le_bytes();
+
+        // Two internal cycles pass before PC is pushed, and one more afterwards to jump
+        let mut bus = TimedBus::new(&mut self.memory, self.timing == Timing::MCycle);
+        bus.idle();
+        bus.idle();
+
+        self.registers.sp = self.registers.sp.wrapping_sub(1);
+        bus.write(self.registers.sp, high);
+
+        // The interrupt is only chosen after the high byte of PC is pushed, if that write
+        // overwrote IE and nothing is pending anymore the dispatch is cancelled and jumps to 0x0000
+        let interrupt = interrupts::next_interrupt(bus.memory());
+
+        self.registers.sp = self.registers.sp.wrapping_sub(1);
+        bus.write(self.registers.sp, low);
+
+        self.registers.pc = match interrupt {
+            Some(interrupt) => {
+                interrupts::acknowledge_interrupt(bus.memory(), interrupt);
+                interrupt.vector()
+            }
+            None => 0x0000,
+        };
+
+        bus.finish(interrupts::DISPATCH_CYCLES);
+        interrupts::DISPATCH_CYCLES
+    }
+
+    // Run whole instructions until at least the given number of cycles have passed, returning
+    // the number of cycles actually run which may overshoot by part of an instruction
+    pub fn run_cycles(&mut self, cycles: u64) -> Result<u64, CpuError> {
+        let start = self.cycles;
+        while self.cycles - start < cycles {
+            self.tick()?;
+        }
+
+        Ok(self.cycles - start)
+    }
+
+    // Total number of cycles run since the CPU was created
+    pub fn cycles(&self) -> u64 {
+        self.cycles
+    }
+}
+
+#[cfg(test)]
+mod mooneye;
+
+#[cfg(test)]
+mod screenshots;
+
+#[cfg(test)]
+pub(crate) mod test_roms;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interrupts::INTERRUPT_ENABLE;
+    use crate::model::DMG_BOOT_ROM_SIZE;
+    use crate::registers::Register;
+
+    #[test]
+    fn test_step() {
+        let mut cpu = CPU::default();
+        let mut rom = vec![0x00; 0x8000];
+
+        // LD B, 0x12
+        rom[0x0000] = 0x06;
+        rom[0x0001] = 0x12;
+        // NOP
+        rom[0x0002] = 0x00;
+        cpu.load_rom(rom).unwrap();
+
+        assert_eq!(8, cpu.step().unwrap());
+        assert_eq!(0x0002, cpu.registers.pc);
+        assert_eq!(4, cpu.step().unwrap());
+        assert_eq!(0x0003, cpu.registers.pc);
+        assert_eq!(12, cpu.cycles());
+    }
+
+    #[test]
+    fn test_run_cycles() {
+        let mut cpu = CPU::default();
+        let mut rom = vec![0x00; 0x8000];
+
+        // JP 0x0000
+        rom[0x0004] = 0xC3;
+        rom[0x0005] = 0x00;
+        rom[0x0006] = 0x00;
+        cpu.load_rom(rom).unwrap();
+
+        // 4 NOPs and a JP make up a 32 cycle loop
+        assert_eq!(32, cpu.run_cycles(32).unwrap());
+        assert_eq!(0x0000, cpu.registers.pc);
+
+        // Stops after the instruction that crosses the requested count
+        assert_eq!(32, cpu.run_cycles(18).unwrap());
+        assert_eq!(0x0000, cpu.registers.pc);
+        assert_eq!(64, cpu.cycles());
+    }
+
+    #[test]
+    fn test_boot_rom() {
+        let mut cpu = CPU::default();
+        cpu.load_rom(vec![0x00; 0x8000]).unwrap();
+
+        // LD A, 0x01; LDH (0x50), A at the very end of the boot ROM, as the real ones do
+        let mut boot_rom = vec![0x00; DMG_BOOT_ROM_SIZE];
+        boot_rom[0x00FC..].copy_from_slice(&[0x3E, 0x01, 0xE0, 0x50]);
+        cpu.load_boot_rom(Model::Dmg, boot_rom).unwrap();
+        assert_eq!(0x0000, cpu.registers.pc);
+
+        while cpu.registers.pc < 0x0100 {
+            cpu.step().unwrap();
+        }
+        assert!(!cpu.bus().boot_rom_mapped());
+    }
+
+    #[test]
+    fn test_skip_boot() {
+        let mut cpu = CPU::default();
+        let mut rom = vec![0x00; 0x8000];
+        rom[0x0143] = 0x80;
+        rom[0x014D] = 0x42;
+        cpu.load_rom(rom).unwrap();
+
+        cpu.skip_boot(Model::Dmg);
+        assert_eq!(0x0100, cpu.registers.pc);
+        assert_eq!(0xFFFE, cpu.registers.sp);
+        assert_eq!(0xB0, cpu.registers.get_reg8(Register::F));
+        assert!(!cpu.cgb_mode);
+
+        // CGB models stay in CGB mode for CGB games
+        cpu.skip_boot(Model::Cgb);
+        assert_eq!(0x11, cpu.registers.get_reg8(Register::A));
+        assert_eq!(0xFF, cpu.registers.get_reg8(Register::D));
+        assert!(cpu.cgb_mode);
+    }
+
+    #[test]
+    fn test_interrupt_dispatch() {
+        let mut cpu = CPU::default();
+
+        cpu.registers.pc = 0x1234;
+        cpu.registers.sp = 0xD000;
+        cpu.registers.ime = true;
+        cpu.memory.write(INTERRUPT_ENABLE, 0xFF);
+        cpu.request_interrupt(Interrupt::Timer);
+        cpu.request_interrupt(Interrupt::Joypad);
+
+        cpu.tick().unwrap();
+
+        assert_eq!(0x0050, cpu.registers.pc);
+        assert_eq!(0xCFFE, cpu.registers.sp);
+        assert_eq!(0x34, cpu.memory.read(0xCFFE));
+        assert_eq!(0x12, cpu.memory.read(0xCFFF));
+        assert_eq!(
+            Interrupt::Joypad.mask(),
+            cpu.memory.read(INTERRUPT_FLAG) & 0x1F
+        );
+        assert!(!cpu.registers.ime);
+        assert_eq!(20, cpu.cycles());
+    }
+
+    #[test]
+    fn test_interrupt_ignored_without_ime() {
+        let mut cpu = CPU::default();
+        cpu.load_rom(vec![0x00; 0x8000]).unwrap();
+
+        cpu.memory.write(INTERRUPT_ENABLE, 0xFF);
+        cpu.request_interrupt(Interrupt::VBlank);
+
+        cpu.tick().unwrap();
+
+        assert_eq!(0x0001, cpu.registers.pc);
+        assert_eq!(
+            Interrupt::VBlank.mask(),
+            cpu.memory.read(INTERRUPT_FLAG) & 0x1F
+        );
+    }
+
+    #[test]
+    fn test_ei_delay() {
+        let mut cpu = CPU::default();
+        let mut rom = vec![0x00; 0x8000];
+
+        cpu.registers.sp = 0xD000;
+        cpu.memory.write(INTERRUPT_ENABLE, 0xFF);
+        cpu.request_interrupt(Interrupt::VBlank);
+        // EI
+        rom[0x0000] = 0xFB;
+        cpu.load_rom(rom).unwrap();
+
+        // EI itself doesn't enable interrupts
+        cpu.tick().unwrap();
+        assert_eq!(0x0001, cpu.registers.pc);
+        assert!(!cpu.registers.ime);
+
+        // The following NOP still runs before the interrupt is taken
+        cpu.tick().unwrap();
+        assert_eq!(0x0002, cpu.registers.pc);
+        assert!(cpu.registers.ime);
+
+        cpu.tick().unwrap();
+        assert_eq!(Interrupt::VBlank.vector(), cpu.registers.pc);
+    }
+
+    #[test]
+    fn test_ei_di() {
+        let mut cpu = CPU::default();
+        let mut rom = vec![0x00; 0x8000];
+
+        // EI, DI
+        rom[0x0000] = 0xFB;
+        rom[0x0001] = 0xF3;
+        cpu.load_rom(rom).unwrap();
+
+        cpu.tick().unwrap();
+        cpu.tick().unwrap();
+
+        assert!(!cpu.registers.ime);
+        assert!(!cpu.registers.ime_scheduled);
+    }
+
+    #[test]
+    fn test_reti() {
+        let mut cpu = CPU::default();
+        let mut rom = vec![0x00; 0x8000];
+
+        cpu.registers.pc = 0x0100;
+        cpu.registers.sp = 0xD000;
+        cpu.registers.ime = true;
+        cpu.memory.write(INTERRUPT_ENABLE, 0xFF);
+        rom[0x0040] = 0xD9;
+        cpu.load_rom(rom).unwrap();
+        cpu.request_interrupt(Interrupt::VBlank);
+
+        cpu.tick().unwrap();
+        cpu.tick().unwrap();
+
+        // RETI re-enables interrupts immediately
+        assert_eq!(0x0100, cpu.registers.pc);
+        assert_eq!(0xD000, cpu.registers.sp);
+        assert!(cpu.registers.ime);
+        assert_eq!(36, cpu.cycles());
+    }
+
+    #[test]
+    fn test_interrupt_dispatch_cancelled_by_ie_push() {
+        let mut cpu = CPU::default();
+
+        // Pushing the high byte of PC overwrites IE with 0x00
+        cpu.registers.pc = 0x0012;
+        cpu.registers.sp = 0x0000;
+        cpu.registers.ime = true;
+        cpu.memory.write(INTERRUPT_ENABLE, 0xFF);
+        cpu.request_interrupt(Interrupt::VBlank);
+
+        cpu.tick().unwrap();
+
+        assert_eq!(0x0000, cpu.registers.pc);
+        assert_eq!(
+            Interrupt::VBlank.mask(),
+            cpu.memory.read(INTERRUPT_FLAG) & 0x1F
+        );
+    }
+
+    #[test]
+    fn test_halt() {
+        let mut cpu = CPU::default();
+        let mut rom = vec![0x00; 0x8000];
+
+        // HALT, INC A
+        rom[0x0000] = 0x76;
+        rom[0x0001] = 0x3C;
+        cpu.load_rom(rom).unwrap();
+        cpu.memory.write(INTERRUPT_ENABLE, Interrupt::Timer.mask());
+
+        cpu.tick().unwrap();
+        assert_eq!(PowerMode::Halted, cpu.registers.power_mode);
+
+        // Idles without running any instructions
+        cpu.run_cycles(100).unwrap();
+        assert_eq!(0x0001, cpu.registers.pc);
+        assert_eq!(PowerMode::Halted, cpu.registers.power_mode);
+
+        // Wakes up on a pending interrupt even with IME unset and continues after HALT
+        cpu.request_interrupt(Interrupt::Timer);
+        cpu.tick().unwrap();
+        assert_eq!(PowerMode::Running, cpu.registers.power_mode);
+        assert_eq!(0x0002, cpu.registers.pc);
+        assert_eq!(1, cpu.registers.get_reg8(Register::A));
+    }
+
+    #[test]
+    fn test_halt_interrupt() {
+        let mut cpu = CPU::default();
+        let mut rom = vec![0x00; 0x8000];
+
+        cpu.registers.sp = 0xD000;
+        cpu.registers.ime = true;
+        rom[0x0000] = 0x76;
+        cpu.load_rom(rom).unwrap();
+        cpu.memory.write(INTERRUPT_ENABLE, Interrupt::VBlank.mask());
+
+        cpu.tick().unwrap();
+        cpu.tick().unwrap();
+        assert_eq!(PowerMode::Halted, cpu.registers.power_mode);
+
+        cpu.request_interrupt(Interrupt::VBlank);
+        cpu.tick().unwrap();
+        assert_eq!(PowerMode::Running, cpu.registers.power_mode);
+        assert_eq!(Interrupt::VBlank.vector(), cpu.registers.pc);
+        assert_eq!(0x01, cpu.memory.read(0xCFFE));
+    }
+
+    #[test]
+    fn test_halt_bug() {
+        let mut cpu = CPU::default();
+        let mut rom = vec![0x00; 0x8000];
+
+        // HALT, INC A, INC A
+        rom[0x0000] = 0x76;
+        rom[0x0001] = 0x3C;
+        rom[0x0002] = 0x3C;
+        cpu.load_rom(rom).unwrap();
+        cpu.memory.write(INTERRUPT_ENABLE, Interrupt::Timer.mask());
+        cpu.request_interrupt(Interrupt::Timer);
+
+        // The CPU doesn't halt and the first INC A is run twice
+        cpu.tick().unwrap();
+        assert_eq!(PowerMode::Running, cpu.registers.power_mode);
+        cpu.tick().unwrap();
+        assert_eq!(0x0001, cpu.registers.pc);
+        cpu.tick().unwrap();
+        cpu.tick().unwrap();
+        assert_eq!(0x0003, cpu.registers.pc);
+        assert_eq!(3, cpu.registers.get_reg8(Register::A));
+    }
+
+    #[test]
+    fn test_halt_bug_operand() {
+        let mut cpu = CPU::default();
+        let mut rom = vec![0x00; 0x8000];
+
+        // HALT, LD B, 0x12
+        rom[0x0000] = 0x76;
+        rom[0x0001] = 0x06;
+        rom[0x0002] = 0x12;
+        cpu.load_rom(rom).unwrap();
+        cpu.memory.write(INTERRUPT_ENABLE, Interrupt::Timer.mask());
+        cpu.request_interrupt(Interrupt::Timer);
+
+        // The opcode is read again as the operand
+        cpu.tick().unwrap();
+        cpu.tick().unwrap();
+        assert_eq!(0x06, cpu.registers.get_reg8(Register::B));
+        assert_eq!(0x0002, cpu.registers.pc);
+    }
+
+    #[test]
+    fn test_halt_bug_ei() {
+        let mut cpu = CPU::default();
+        let mut rom = vec![0x00; 0x8000];
+
+        // EI, HALT, with LD HL, 0xC000; LD (HL), 0x55; RETI as the VBlank handler
+        rom[0x0000] = 0xFB;
+        rom[0x0001] = 0x76;
+        rom[0x0040..0x0046].copy_from_slice(&[0x21, 0x00, 0xC0, 0x36, 0x55, 0xD9]);
+        cpu.registers.sp = 0xD000;
+        cpu.load_rom(rom).unwrap();
+        cpu.memory.write(INTERRUPT_ENABLE, Interrupt::VBlank.mask());
+        cpu.request_interrupt(Interrupt::VBlank);
+
+        // The interrupt is taken straight after HALT, returning to the HALT itself
+        cpu.tick().unwrap();
+        cpu.tick().unwrap();
+        cpu.tick().unwrap();
+        assert_eq!(Interrupt::VBlank.vector(), cpu.registers.pc);
+        assert!(!cpu.registers.halt_bug);
+        assert_eq!(0x01, cpu.memory.read(0xCFFE));
+
+        // The handler runs untouched by the bug
+        cpu.tick().unwrap();
+        cpu.tick().unwrap();
+        assert_eq!(0x55, cpu.memory.read(0xC000));
+        cpu.tick().unwrap();
+        assert_eq!(0x0001, cpu.registers.pc);
+
+        // Nothing is pending anymore, so this time the CPU halts
+        cpu.tick().unwrap();
+        assert_eq!(PowerMode::Halted, cpu.registers.power_mode);
+    }
+
+    #[test]
+    fn test_stop() {
+        let mut cpu = CPU::default();
+        let mut rom = vec![0x00; 0x8000];
+
+        // STOP
+        rom[0x0000] = 0x10;
+        cpu.load_rom(rom).unwrap();
+        cpu.memory.write(0xFF04, 0xAB);
+
+        cpu.tick().unwrap();
+        assert_eq!(PowerMode::Stopped, cpu.registers.power_mode);
+        assert_eq!(0x00, cpu.memory.read(0xFF04));
+
+        // Other interrupts don't wake the CPU
+        cpu.memory.write(INTERRUPT_ENABLE, 0xFF);
+        cpu.request_interrupt(Interrupt::VBlank);
+        cpu.run_cycles(100).unwrap();
+        assert_eq!(PowerMode::Stopped, cpu.registers.power_mode);
+        assert_eq!(0x0002, cpu.registers.pc);
+
+        cpu.memory.write(INTERRUPT_ENABLE, 0x00);
+        cpu.request_interrupt(Interrupt::Joypad);
+        cpu.tick().unwrap();
+        assert_eq!(PowerMode::Running, cpu.registers.power_mode);
+        assert_eq!(0x0003, cpu.registers.pc);
+    }
+
+    #[test]
+    fn test_stop_speed_switch() {
+        let mut cpu = CPU::default();
+        cpu.set_cgb_mode(true);
+        let mut rom = vec![0x00; 0x8000];
+
+        // STOP
+        rom[0x0000] = 0x10;
+        cpu.load_rom(rom).unwrap();
+        cpu.memory.write(KEY1, 0x01);
+
+        cpu.tick().unwrap();
+        cpu.tick().unwrap();
+
+        assert!(cpu.double_speed());
+        assert_eq!(0x80, cpu.memory.read(KEY1) & 0x81);
+        assert_eq!(PowerMode::Running, cpu.registers.power_mode);
+    }
+
+    #[test]
+    fn test_illegal_opcode_error() {
+        let mut cpu = CPU::default();
+        let mut rom = vec![0x00; 0x8000];
+
+        cpu.registers.pc = 0x0100;
+        rom[0x0100] = 0xD3;
+        cpu.load_rom(rom).unwrap();
+
+        let result = cpu.step();
+
+        assert_eq!(
+            Err(CpuError::IllegalOpcode {
+                opcode: 0xD3,
+                address: 0x0100
+            }),
+            result
+        );
+        assert_eq!(0x0100, cpu.registers.pc);
+    }
+
+    #[test]
+    fn test_illegal_opcode_error_cycles() {
+        for timing in [Timing::Instruction, Timing::MCycle] {
+            let mut cpu = CPU::default();
+            cpu.set_timing(timing);
+            let mut rom = vec![0x00; 0x8000];
+            rom[0x0000] = 0xD3;
+            cpu.load_rom(rom).unwrap();
+            cpu.memory.tick(252);
+
+            // The fetch counts towards the cycles and ticks the bus over
+            assert!(cpu.step().is_err());
+            assert_eq!(4, cpu.cycles());
+            assert_eq!(0x01, cpu.memory.read(0xFF04));
+        }
+    }
+
+    #[test]
+    fn test_illegal_opcode_lock_up() {
+        let mut cpu = CPU::default();
+        let mut rom = vec![0x00; 0x8000];
+        cpu.set_illegal_opcode_policy(IllegalOpcodePolicy::LockUp);
+
+        cpu.registers.ime = true;
+        rom[0x0000] = 0xDD;
+        cpu.load_rom(rom).unwrap();
+        cpu.memory.write(INTERRUPT_ENABLE, 0xFF);
+
+        cpu.tick().unwrap();
+        assert_eq!(PowerMode::Locked, cpu.registers.power_mode);
+
+        // Not even interrupts wake the CPU back up
+        cpu.request_interrupt(Interrupt::VBlank);
+        cpu.run_cycles(100).unwrap();
+        assert_eq!(PowerMode::Locked, cpu.registers.power_mode);
+        assert_eq!(0x0000, cpu.registers.pc);
+    }
+
+    #[test]
+    fn test_illegal_opcode_nop() {
+        let mut cpu = CPU::default();
+        let mut rom = vec![0x00; 0x8000];
+        cpu.set_illegal_opcode_policy(IllegalOpcodePolicy::Nop);
+
+        rom[0x0000] = 0xFC;
+        cpu.load_rom(rom).unwrap();
+
+        assert_eq!(4, cpu.step().unwrap());
+        assert_eq!(0x0001, cpu.registers.pc);
+    }
+
+    #[test]
+    fn test_mcycle_timing_read() {
+        // LD A, (0xFF04)
+        let mut rom = vec![0xFA, 0x04, 0xFF];
+        rom.resize(0x8000, 0x00);
+
+        // The read happens in the fourth M-cycle, after DIV has ticked over
+        let mut cpu = CPU::default();
+        cpu.set_timing(Timing::MCycle);
+        cpu.load_rom(rom.clone()).unwrap();
+        cpu.memory.tick(240);
+        cpu.tick().unwrap();
+        assert_eq!(0x01, cpu.registers.get_reg8(Register::A));
+        assert_eq!(0x01, cpu.memory.read(0xFF04));
+
+        // Otherwise the whole instruction sees the bus as it was before it started
+        let mut cpu = CPU::default();
+        cpu.load_rom(rom).unwrap();
+        cpu.memory.tick(240);
+        cpu.tick().unwrap();
+        assert_eq!(0x00, cpu.registers.get_reg8(Register::A));
+        assert_eq!(0x01, cpu.memory.read(0xFF04));
+    }
+
+    #[test]
+    fn test_mcycle_timing_ret() {
+        let mut cpu = CPU::default();
+        cpu.set_timing(Timing::MCycle);
+        let mut rom = vec![0x00; 0x8000];
+
+        // RET, RET NZ
+        rom[0x0000] = 0xC9;
+        rom[0x0100] = 0xC0;
+        cpu.load_rom(rom).unwrap();
+
+        // RET pops the low byte in its second M-cycle
+        cpu.registers.sp = 0xFF04;
+        cpu.memory.tick(248);
+        cpu.tick().unwrap();
+        assert_eq!(0x0001, cpu.registers.pc);
+
+        // RET NZ checks the flags first, so the pop happens a cycle later
+        cpu.registers.pc = 0x0100;
+        cpu.registers.sp = 0xFF04;
+        cpu.memory.write(0xFF04, 0x00);
+        cpu.memory.tick(244);
+        cpu.tick().unwrap();
+        assert_eq!(0x0001, cpu.registers.pc);
+    }
+
+    #[test]
+    fn test_mcycle_timing_call() {
+        let mut cpu = CPU::default();
+        cpu.set_timing(Timing::MCycle);
+        let mut rom = vec![0x00; 0x8000];
+
+        // CALL 0x0100
+        rom[0x0000] = 0xCD;
+        rom[0x0001] = 0x00;
+        rom[0x0002] = 0x01;
+        cpu.load_rom(rom).unwrap();
+
+        // The low byte of the return address is pushed onto DIV in the last M-cycle, resetting it
+        cpu.registers.sp = 0xFF06;
+        cpu.memory.tick(0x80);
+        assert_eq!(24, cpu.step().unwrap());
+        cpu.memory.tick(252);
+        assert_eq!(0x00, cpu.memory.read(0xFF04));
+        cpu.memory.tick(4);
+        assert_eq!(0x01, cpu.memory.read(0xFF04));
+        assert_eq!(0x0100, cpu.registers.pc);
+    }
+
+    #[test]
+    fn test_mcycle_timing_total_cycles() {
+        let mut cpu = CPU::default();
+        cpu.set_timing(Timing::MCycle);
+        let mut rom = vec![0x00; 0x8000];
+
+        // JP 0x0000 after 4 NOPs, the bus is ticked for exactly as long as the CPU has run
+        rom[0x0004] = 0xC3;
+        cpu.load_rom(rom).unwrap();
+        cpu.run_cycles(32 * 8).unwrap();
+
+        assert_eq!(32 * 8, cpu.cycles());
+        assert_eq!(0x01, cpu.memory.read(0xFF04));
+    }
+
+    // 64KiB of RAM that counts how long it has been running
+    struct FlatBus {
+        memory: Vec<u8>,
+        cycles: u64,
+    }
+
+    impl Bus for FlatBus {
+        fn read(&self, addr: Address) -> u8 {
+            self.memory[addr as usize]
+        }
+
+        fn write(&mut self, addr: Address, data: u8) {
+            self.memory[addr as usize] = data;
+        }
+
+        fn tick(&mut self, cycles: u8) {
+            self.cycles += cycles as u64;
+        }
+    }
+
+    #[test]
+    fn test_custom_bus() {
+        let mut cpu = CPU::new(FlatBus {
+            memory: vec![0x00; 0x10000],
+            cycles: 0,
+        });
+
+        // LD (0x0000), SP, code can write over itself with no ROM in the way
+        cpu.write_memory(0x0000, 0x08);
+        cpu.write_memory(0x0001, 0x00);
+        cpu.write_memory(0x0002, 0x00);
+        cpu.registers.sp = 0xBEEF;
+
+        cpu.tick().unwrap();
+
+        assert_eq!(0xEF, cpu.read_memory(0x0000));
+        assert_eq!(0xBE, cpu.read_memory(0x0001));
+        assert_eq!(20, cpu.bus().cycles);
+
+        cpu.bus_mut().cycles = 0;
+        cpu.run_cycles(8).unwrap();
+        assert_eq!(cpu.cycles() - 20, cpu.bus().cycles);
     }
 }
@@ -0,0 +1,131 @@
+// Runs ROMs from the mooneye test suite. Each test ends by running LD B, B with the Fibonacci
+// numbers 3, 5, 8, 13, 21 and 34 in B, C, D, E, H and L if it passed, or 0x42 in all of them if
+// it failed. Build https://github.com/Gekkio/mooneye-test-suite and copy its build directory to
+// libdmg/tests/mooneye or point MOONEYE_TESTS at it to run them
+use std::fs;
+
+use super::test_roms::{data_dir, found, list_roms, run_to_break};
+use super::*;
+use crate::model::Model;
+use crate::registers::Register;
+
+const DATA_DIR_VAR: &str = "MOONEYE_TESTS";
+
+const PASS: [u8; 6] = [3, 5, 8, 13, 21, 34];
+
+// Every test finishes in well under this many seconds of emulated time
+const TIMEOUT_CYCLES: u64 = 4_194_304 * 30;
+
+fn run_rom(rom: Vec<u8>, timeout: u64) -> Result<(), String> {
+    let mut cpu = CPU::default();
+    cpu.load_rom(rom).map_err(|error| error.to_string())?;
+    cpu.skip_boot(Model::Dmg);
+    // The acceptance tests time accesses within instructions
+    cpu.set_timing(Timing::MCycle);
+    run_to_break(&mut cpu, timeout)?;
+
+    let result = [
+        Register::B,
+        Register::C,
+        Register::D,
+        Register::E,
+        Register::H,
+        Register::L,
+    ]
+    .map(|register| cpu.registers.get_reg8(register));
+    match result {
+        PASS => Ok(()),
+        _ => Err(format!("failed with registers {:02X?}", result)),
+    }
+}
+
+fn run_suite(suite: &str) {
+    run_matching(suite, |_| true);
+}
+
+// Runs every ROM in a directory of the suite whose name matches, printing a line for each
+fn run_matching(suite: &str, matches: impl Fn(&str) -> bool) {
+    let dir = data_dir(DATA_DIR_VAR, "mooneye").join(suite);
+    if !found(&format!("mooneye {} tests", suite), &dir, DATA_DIR_VAR) {
+        return;
+    }
+
+    let roms = list_roms(&dir).into_iter().filter(|path| {
+        path.file_name()
+            .map_or(false, |name| matches(&name.to_string_lossy()))
+    });
+
+    let mut failed_roms = Vec::new();
+    for path in roms {
+        let name = path.file_name().unwrap().to_string_lossy().to_string();
+        let rom =
+            fs::read(&path).unwrap_or_else(|error| panic!("Failed to read {}: {}", name, error));
+        match run_rom(rom, TIMEOUT_CYCLES) {
+            Ok(()) => println!("{:<24} passed", name),
+            Err(error) => {
+                println!("{:<24} {}", name, error);
+                failed_roms.push(name);
+            }
+        }
+    }
+
+    assert!(
+        failed_roms.is_empty(),
+        "Failing ROMs: {}",
+        failed_roms.join(", ")
+    );
+}
+
+#[test]
+fn test_mooneye_mbc1() {
+    run_suite("emulator-only/mbc1");
+}
+
+#[test]
+fn test_mooneye_mbc2() {
+    run_suite("emulator-only/mbc2");
+}
+
+#[test]
+fn test_mooneye_mbc5() {
+    run_suite("emulator-only/mbc5");
+}
+
+#[test]
+fn test_mooneye_oam_dma() {
+    run_suite("acceptance/oam_dma");
+    run_matching("acceptance", |name| name.starts_with("oam_dma_"));
+}
+
+// The acceptance *_timing tests, e.g. call_timing and ld_hl_sp_e_timing, which check which M-cycle
+// each memory access, interrupt or DIV change lands on
+#[test]
+fn test_mooneye_timing() {
+    run_matching("acceptance", |name| {
+        name.contains("_timing") && !name.starts_with("oam_dma_")
+    });
+}
+
+#[test]
+fn test_mooneye_result() {
+    let mut rom = vec![0x00; 0x8000];
+
+    // LD B, 3; LD C, 5; LD D, 8; LD E, 13; LD H, 21; LD L, 34; LD B, B
+    rom[0x0100..0x010D].copy_from_slice(&[
+        0x06, 3, 0x0E, 5, 0x16, 8, 0x1E, 13, 0x26, 21, 0x2E, 34, 0x40,
+    ]);
+    assert_eq!(Ok(()), run_rom(rom.clone(), TIMEOUT_CYCLES));
+
+    rom[0x0107] = 0x42;
+    assert_eq!(
+        Err("failed with registers [03, 05, 08, 42, 15, 22]".to_string()),
+        run_rom(rom.clone(), TIMEOUT_CYCLES)
+    );
+
+    // JR -2 forever
+    rom[0x0100..0x0102].copy_from_slice(&[0x18, 0xFE]);
+    assert_eq!(
+        Err("timed out at PC 0x0100".to_string()),
+        run_rom(rom, 1000)
+    );
+}
@@ -0,0 +1,141 @@
+// Runs PPU test ROMs that finish by drawing a picture, then checks the frame against a reference
+// screenshot. Both dmg-acid2 and the mealybug tearoom tests run LD B, B once the picture is up.
+// Put dmg-acid2.gb and the reference-dmg.png screenshot from
+// https://github.com/mattcurrie/dmg-acid2 in libdmg/tests/dmg-acid2, and a clone of
+// https://github.com/mattcurrie/mealybug-tearoom-tests with its ROMs built in libdmg/tests/mealybug,
+// or point DMG_ACID2 and MEALYBUG_TESTS at them
+use std::env;
+use std::fs::{self, File};
+use std::path::Path;
+
+use super::test_roms::{data_dir, found, list_roms, run_to_break, DEBUG_BREAK};
+use super::*;
+use crate::model::Model;
+use crate::ppu::{Accuracy, SCREEN_HEIGHT, SCREEN_WIDTH};
+
+const ACID2_DIR_VAR: &str = "DMG_ACID2";
+const MEALYBUG_DIR_VAR: &str = "MEALYBUG_TESTS";
+
+const TIMEOUT_CYCLES: u64 = 4_194_304 * 10;
+
+fn run_rom(rom: Vec<u8>, reference: &[u8]) -> Result<(), String> {
+    let mut cpu = CPU::default();
+    cpu.load_rom(rom).map_err(|error| error.to_string())?;
+    cpu.bus_mut().ppu_mut().set_accuracy(Accuracy::PixelFifo);
+    cpu.skip_boot(Model::Dmg);
+    run_to_break(&mut cpu, TIMEOUT_CYCLES)?;
+
+    let frame = cpu.bus().ppu().frame();
+    match frame.iter().zip(reference).position(|(a, b)| a != b) {
+        Some(index) => Err(format!(
+            "differs from the screenshot first at ({}, {})",
+            index % SCREEN_WIDTH,
+            index / SCREEN_WIDTH
+        )),
+        None => Ok(()),
+    }
+}
+
+// Loads a screenshot as shade indices, from white being 0 to black being 3
+fn load_screenshot(path: &Path) -> Result<Vec<u8>, String> {
+    let file = File::open(path).map_err(|error| error.to_string())?;
+    let mut decoder = png::Decoder::new(file);
+    decoder.set_transformations(png::Transformations::EXPAND | png::Transformations::STRIP_16);
+    let mut reader = decoder.read_info().map_err(|error| error.to_string())?;
+    let mut buffer = vec![0; reader.output_buffer_size()];
+    let info = reader
+        .next_frame(&mut buffer)
+        .map_err(|error| error.to_string())?;
+    if (info.width as usize, info.height as usize) != (SCREEN_WIDTH, SCREEN_HEIGHT) {
+        return Err(format!("{}x{} isn't a screenshot", info.width, info.height));
+    }
+
+    // The screenshots are grey, so the first channel is as good as any
+    let channels = info.color_type.samples();
+    Ok(buffer[..info.buffer_size()]
+        .chunks_exact(channels)
+        .map(|pixel| 3 - ((pixel[0] as u16 + 0x2A) / 0x55) as u8)
+        .collect())
+}
+
+fn check(name: &str, rom_path: &Path, screenshot_path: &Path) -> Result<(), String> {
+    let rom = fs::read(rom_path).map_err(|error| error.to_string())?;
+    let reference = load_screenshot(screenshot_path)
+        .map_err(|error| format!("{}: {}", screenshot_path.display(), error))?;
+    let result = run_rom(rom, &reference);
+    match &result {
+        Ok(()) => println!("{:<32} passed", name),
+        Err(error) => println!("{:<32} {}", name, error),
+    }
+    result
+}
+
+#[test]
+fn test_dmg_acid2() {
+    let dir = data_dir(ACID2_DIR_VAR, "dmg-acid2");
+    let rom_path = dir.join("dmg-acid2.gb");
+    if !found("dmg-acid2", &rom_path, ACID2_DIR_VAR) {
+        return;
+    }
+
+    check("dmg-acid2.gb", &rom_path, &dir.join("reference-dmg.png")).unwrap();
+}
+
+#[test]
+fn test_mealybug() {
+    let dir = data_dir(MEALYBUG_DIR_VAR, "mealybug");
+    let rom_dir = dir.join("build/ppu");
+    if !found("mealybug tearoom tests", &rom_dir, MEALYBUG_DIR_VAR) {
+        return;
+    }
+
+    // Only some of the tests have a DMG screenshot, the rest are for the CGB
+    let mut failed_roms = Vec::new();
+    for path in list_roms(&rom_dir) {
+        let name = path.file_stem().unwrap().to_string_lossy().to_string();
+        let screenshot = dir
+            .join("expected/DMG-blob")
+            .join(&name)
+            .with_extension("png");
+        if screenshot.exists() && check(&name, &path, &screenshot).is_err() {
+            failed_roms.push(name);
+        }
+    }
+
+    assert!(
+        failed_roms.is_empty(),
+        "Failing ROMs: {}",
+        failed_roms.join(", ")
+    );
+}
+
+#[test]
+fn test_screenshot_result() {
+    let path = env::temp_dir().join(format!("libdmg-screenshot-{}.png", std::process::id()));
+    let mut encoder = png::Encoder::new(
+        File::create(&path).unwrap(),
+        SCREEN_WIDTH as u32,
+        SCREEN_HEIGHT as u32,
+    );
+    encoder.set_color(png::ColorType::Rgb);
+    let mut pixels = vec![0xFF; SCREEN_WIDTH * SCREEN_HEIGHT * 3];
+    pixels[..6].copy_from_slice(&[0x00, 0x00, 0x00, 0x55, 0x55, 0x55]);
+    encoder
+        .write_header()
+        .unwrap()
+        .write_image_data(&pixels)
+        .unwrap();
+    let screenshot = load_screenshot(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+    assert_eq!([3, 2, 0], screenshot[..3]);
+
+    // LD B, B straight away with the LCD still blank
+    let mut rom = vec![0x00; 0x8000];
+    rom[0x0100] = DEBUG_BREAK;
+    let blank = vec![0; SCREEN_WIDTH * SCREEN_HEIGHT];
+    assert_eq!(Ok(()), run_rom(rom.clone(), &blank));
+    assert_eq!(
+        Err("differs from the screenshot first at (0, 0)".to_string()),
+        run_rom(rom, &screenshot)
+    );
+}
@@ -0,0 +1,66 @@
+// Support for the tests that run external test ROMs and vectors. None of them are checked in, so
+// each suite looks under libdmg/tests or wherever its environment variable points, and is skipped
+// with a note when its files aren't there
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::*;
+
+// LD B, B is used as a breakpoint
+pub const DEBUG_BREAK: u8 = 0x40;
+
+// Where a suite's files are, from var if it's set or libdmg/tests/default otherwise
+pub fn data_dir(var: &str, default: &str) -> PathBuf {
+    match env::var_os(var) {
+        Some(dir) => PathBuf::from(dir),
+        None => Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join(default),
+    }
+}
+
+// Whether a suite's files are there, printing that the suite is being skipped if they aren't.
+// Setting var makes them required, so CI fails instead of skipping when a fetch goes wrong
+pub fn found(suite: &str, path: &Path, var: &str) -> bool {
+    if path.exists() {
+        return true;
+    }
+    assert!(
+        env::var_os(var).is_none(),
+        "{} not found at {} with {} set",
+        suite,
+        path.display(),
+        var
+    );
+    eprintln!(
+        "Skipping {}, {} not found (set {} to override)",
+        suite,
+        path.display(),
+        var
+    );
+    false
+}
+
+// The ROMs in a directory in name order
+pub fn list_roms(dir: &Path) -> Vec<PathBuf> {
+    let entries = fs::read_dir(dir)
+        .unwrap_or_else(|error| panic!("Failed to read {}: {}", dir.display(), error));
+    let mut roms: Vec<_> = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().map_or(false, |ext| ext == "gb"))
+        .collect();
+    roms.sort();
+    roms
+}
+
+// Runs until the ROM hits LD B, B, failing if that takes more than timeout cycles
+pub fn run_to_break(cpu: &mut CPU, timeout: u64) -> Result<(), String> {
+    while cpu.read_memory(cpu.registers.pc) != DEBUG_BREAK {
+        if cpu.cycles() >= timeout {
+            return Err(format!("timed out at PC {:#06X}", cpu.registers.pc));
+        }
+        cpu.step().map_err(|error| error.to_string())?;
+    }
+    Ok(())
+}
@@ -0,0 +1,128 @@
+use super::data::Address;
+
+pub const DMA: Address = 0xFF46;
+
+// How many bytes a transfer copies, filling OAM
+pub const OAM_DMA_LENGTH: u8 = 0xA0;
+
+// A transfer starts copying on the second M-cycle after DMA is written
+const START_DELAY: u8 = 2;
+
+// OAM DMA copies 160 bytes from XX00-XX9F into OAM, one per M-cycle, where XX is the value written
+// to DMA. The memory bus does the copying, this only keeps track of where the transfer is
+#[derive(Debug, Default)]
+pub(crate) struct OamDma {
+    // The last value written, which reads back
+    register: u8,
+    // A transfer that's been requested but not started yet, with the M-cycles left until it does.
+    // Any transfer already running carries on until then
+    pending: Option<(Address, u8)>,
+    source: Address,
+    // How many bytes have been copied, with the transfer running until all of them have
+    copied: u8,
+    active: bool,
+    // The byte last read for the transfer, which is what the CPU sees when it reads from the same
+    // bus
+    data: u8,
+}
+
+impl OamDma {
+    // Leaves the register as the boot ROM does without starting a transfer
+    pub fn new(register: u8) -> OamDma {
+        OamDma {
+            register,
+            ..OamDma::default()
+        }
+    }
+
+    pub fn register(&self) -> u8 {
+        self.register
+    }
+
+    pub fn start(&mut self, data: u8) {
+        self.register = data;
+        self.pending = Some(((data as Address) << 8, START_DELAY));
+    }
+
+    pub fn active(&self) -> bool {
+        self.active
+    }
+
+    pub fn source(&self) -> Address {
+        self.source
+    }
+
+    pub fn data(&self) -> u8 {
+        self.data
+    }
+
+    // Moves on an M-cycle, returning the address to copy from and the offset into OAM to copy to
+    // if a byte is copied this cycle
+    pub fn step(&mut self) -> Option<(Address, u8)> {
+        if self.copied == OAM_DMA_LENGTH {
+            self.active = false;
+        }
+        if let Some((source, delay)) = &mut self.pending {
+            *delay -= 1;
+            if *delay == 0 {
+                self.source = *source;
+                self.copied = 0;
+                self.active = true;
+                self.pending = None;
+            }
+        }
+        if !self.active {
+            return None;
+        }
+
+        let offset = self.copied;
+        self.copied += 1;
+        Some((self.source + offset as Address, offset))
+    }
+
+    // Records the byte read for the transfer
+    pub fn set_data(&mut self, data: u8) {
+        self.data = data;
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transfer() {
+        let mut dma = OamDma::new(0xFF);
+        assert_eq!(None, dma.step());
+
+        dma.start(0xC1);
+        assert_eq!(0xC1, dma.register());
+        assert_eq!(None, dma.step());
+        assert_eq!(false, dma.active());
+        assert_eq!(Some((0xC100, 0)), dma.step());
+        assert_eq!(true, dma.active());
+
+        for offset in 1..OAM_DMA_LENGTH {
+            assert_eq!(Some((0xC100 + offset as Address, offset)), dma.step());
+        }
+        assert_eq!(true, dma.active());
+        assert_eq!(None, dma.step());
+        assert_eq!(false, dma.active());
+    }
+
+    #[test]
+    fn test_restart() {
+        let mut dma = OamDma::default();
+        dma.start(0xC1);
+        for _ in 0..10 {
+            dma.step();
+        }
+
+        // The first transfer carries on until the second one starts over from the beginning
+        dma.start(0xC2);
+        assert_eq!(Some((0xC109, 9)), dma.step());
+        assert_eq!(Some((0xC200, 0)), dma.step());
+        assert_eq!(true, dma.active());
+    }
+}
@@ -0,0 +1,127 @@
+use std::fmt;
+
+use super::cartridge::Mapper;
+use super::data::Address;
+use super::model::Model;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum CpuError {
+    // One of the opcodes that don't exist on the SM83: 0xD3, 0xDB, 0xDD, 0xE3, 0xE4, 0xEB, 0xEC,
+    // 0xED, 0xF4, 0xFC and 0xFD
+    IllegalOpcode { opcode: u8, address: Address },
+    // An operation was decoded with an operand it can't be used with
+    InvalidOperand(String),
+    // The decoded instruction is inconsistent, e.g. it is missing an operand or flag result
+    DecodeFault(&'static str),
+}
+
+impl fmt::Display for CpuError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CpuError::IllegalOpcode { opcode, address } => {
+                write!(f, "Illegal opcode {:#04X} at {:#06X}", opcode, address)
+            }
+            CpuError::InvalidOperand(message) => write!(f, "Invalid operand: {}", message),
+            CpuError::DecodeFault(message) => write!(f, "Decode fault: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for CpuError {}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum CartridgeError {
+    // The ROM ends before the end of the header at 0x014F
+    Truncated { length: usize },
+    UnknownCartridgeType(u8),
+    UnknownRomSize(u8),
+    UnknownRamSize(u8),
+    // A known controller that hasn't been implemented yet
+    UnsupportedMapper(Mapper),
+    // Saved clock data that isn't in the 44 or 48 byte footer format
+    InvalidRtcData { length: usize },
+    // A save file that doesn't match the size of the cartridge's RAM, plus any clock or flash footer
+    SaveSizeMismatch { expected: usize, actual: usize },
+    // The ROM is smaller than the size given in its header
+    RomSizeMismatch { expected: usize, actual: usize },
+    // The logo at 0x0104-0x0133 doesn't match the Nintendo logo the boot ROM checks for
+    InvalidLogo,
+    HeaderChecksumMismatch { expected: u8, actual: u8 },
+    GlobalChecksumMismatch { expected: u16, actual: u16 },
+}
+
+impl fmt::Display for CartridgeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CartridgeError::Truncated { length } => write!(
+                f,
+                "ROM is only {} bytes long, too short to contain a header",
+                length
+            ),
+            CartridgeError::UnknownCartridgeType(code) => {
+                write!(f, "Unknown cartridge type {:#04X}", code)
+            }
+            CartridgeError::UnknownRomSize(code) => write!(f, "Unknown ROM size {:#04X}", code),
+            CartridgeError::UnknownRamSize(code) => write!(f, "Unknown RAM size {:#04X}", code),
+            CartridgeError::UnsupportedMapper(mapper) => {
+                write!(f, "{} cartridges aren't supported", mapper)
+            }
+            CartridgeError::InvalidRtcData { length } => write!(
+                f,
+                "Clock data is {} bytes, expected a 44 or 48 byte footer",
+                length
+            ),
+            CartridgeError::SaveSizeMismatch { expected, actual } => write!(
+                f,
+                "Save data is {} bytes, expected {} bytes",
+                actual, expected
+            ),
+            CartridgeError::RomSizeMismatch { expected, actual } => write!(
+                f,
+                "Header says the ROM is {} bytes but it is {} bytes",
+                expected, actual
+            ),
+            CartridgeError::InvalidLogo => write!(f, "Nintendo logo doesn't match"),
+            CartridgeError::HeaderChecksumMismatch { expected, actual } => write!(
+                f,
+                "Header checksum is {:#04X} but the header sums to {:#04X}",
+                expected, actual
+            ),
+            CartridgeError::GlobalChecksumMismatch { expected, actual } => write!(
+                f,
+                "Global checksum is {:#06X} but the ROM sums to {:#06X}",
+                expected, actual
+            ),
+        }
+    }
+}
+
+impl std::error::Error for CartridgeError {}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum BootRomError {
+    // Boot ROMs are 256 bytes, or 2304 bytes on the CGB
+    SizeMismatch {
+        model: Model,
+        expected: usize,
+        actual: usize,
+    },
+}
+
+impl fmt::Display for BootRomError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BootRomError::SizeMismatch {
+                model,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "{} boot ROMs are {} bytes but this one is {} bytes",
+                model, expected, actual
+            ),
+        }
+    }
+}
+
+impl std::error::Error for BootRomError {}
@@ -12,8 +12,23 @@ pub fn check_carry8(mode: ArithmeticMode, a: u8, b: u8) -> bool {
 
 pub fn check_half_carry8(mode: ArithmeticMode, a: u8, b: u8) -> bool {
     match mode {
-        ArithmeticMode::Add => (a & 0x0F) + (b & 0x0F) & 0x10 == 0x10,
-        ArithmeticMode::Subtract => (a & 0x10 == 0x10) && (b & 0x0F > 0x0),
+        ArithmeticMode::Add => ((a & 0x0F) + (b & 0x0F)) & 0x10 == 0x10,
+        ArithmeticMode::Subtract => (a & 0x0F) < (b & 0x0F),
+    }
+}
+
+// Same as check_carry8 but with the carry flag added or subtracted too, as in ADC and SBC
+pub fn check_carry8_with_carry(mode: ArithmeticMode, a: u8, b: u8, carry: bool) -> bool {
+    match mode {
+        ArithmeticMode::Add => a as u16 + b as u16 + carry as u16 > 0xFF,
+        ArithmeticMode::Subtract => (a as u16) < b as u16 + carry as u16,
+    }
+}
+
+pub fn check_half_carry8_with_carry(mode: ArithmeticMode, a: u8, b: u8, carry: bool) -> bool {
+    match mode {
+        ArithmeticMode::Add => (a & 0x0F) + (b & 0x0F) + carry as u8 > 0x0F,
+        ArithmeticMode::Subtract => (a & 0x0F) < (b & 0x0F) + carry as u8,
     }
 }
 
@@ -26,12 +41,51 @@ pub fn check_carry16(mode: ArithmeticMode, a: u16, b: u16) -> bool {
 
 pub fn check_half_carry16(mode: ArithmeticMode, a: u16, b: u16) -> bool {
     match mode {
-        ArithmeticMode::Add => (a & 0x0FFF) + (b & 0x0FFF) & 0x1000 == 0x1000,
-        ArithmeticMode::Subtract => (a & 0x1000 == 0x1000) && (b & 0x0FFF > 0x0),
+        ArithmeticMode::Add => ((a & 0x0FFF) + (b & 0x0FFF)) & 0x1000 == 0x1000,
+        ArithmeticMode::Subtract => (a & 0x0FFF) < (b & 0x0FFF),
+    }
+}
+
+// ADD SP, e8 and LD HL, SP+e8 add a signed offset to SP, but the flags come from an unsigned 8-bit
+// addition of the offset to the low byte of SP
+pub fn check_carry_sp_e8(sp: u16, offset: u8) -> bool {
+    check_carry8(ArithmeticMode::Add, sp as u8, offset)
+}
+
+pub fn check_half_carry_sp_e8(sp: u16, offset: u8) -> bool {
+    check_half_carry8(ArithmeticMode::Add, sp as u8, offset)
+}
+
+// Adjust A back into binary coded decimal after an addition or subtraction of two BCD values,
+// returning the adjusted value and the new carry flag
+pub fn daa(value: u8, subtract: bool, half_carry: bool, carry: bool) -> (u8, bool) {
+    let mut adjust = 0;
+    let mut carry = carry;
+
+    // After a subtraction only the flags say whether a digit borrowed, as a digit can't have
+    // gone above 9
+    if subtract {
+        if half_carry {
+            adjust |= 0x06;
+        }
+        if carry {
+            adjust |= 0x60;
+        }
+        return (value.wrapping_sub(adjust), carry);
+    }
+
+    if half_carry || value & 0x0F > 0x09 {
+        adjust |= 0x06;
     }
+    if carry || value > 0x99 {
+        adjust |= 0x60;
+        carry = true;
+    }
+    (value.wrapping_add(adjust), carry)
 }
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod tests {
     use super::*;
 
@@ -82,6 +136,160 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_check_half_carry8_borrow() {
+        // Any borrow from bit 4 sets the half carry flag, e.g. DEC on 0x20
+        assert_eq!(
+            true,
+            check_half_carry8(ArithmeticMode::Subtract, 0x20, 0x01)
+        );
+        assert_eq!(
+            true,
+            check_half_carry8(ArithmeticMode::Subtract, 0x00, 0x01)
+        );
+        assert_eq!(
+            false,
+            check_half_carry8(ArithmeticMode::Subtract, 0x0F, 0x0F)
+        );
+        assert_eq!(
+            false,
+            check_half_carry8(ArithmeticMode::Subtract, 0x21, 0x01)
+        );
+    }
+
+    #[test]
+    fn test_check_carry8_with_carry() {
+        // Addition
+        assert_eq!(
+            true,
+            check_carry8_with_carry(ArithmeticMode::Add, 0xFF, 0x00, true)
+        );
+        assert_eq!(
+            false,
+            check_carry8_with_carry(ArithmeticMode::Add, 0xFE, 0x00, true)
+        );
+        assert_eq!(
+            true,
+            check_carry8_with_carry(ArithmeticMode::Add, 0x80, 0x80, false)
+        );
+
+        // Subtraction
+        assert_eq!(
+            true,
+            check_carry8_with_carry(ArithmeticMode::Subtract, 0x00, 0x00, true)
+        );
+        assert_eq!(
+            false,
+            check_carry8_with_carry(ArithmeticMode::Subtract, 0x01, 0x00, true)
+        );
+        assert_eq!(
+            true,
+            check_carry8_with_carry(ArithmeticMode::Subtract, 0x01, 0xFF, false)
+        );
+    }
+
+    #[test]
+    fn test_check_half_carry8_with_carry() {
+        // Addition
+        assert_eq!(
+            true,
+            check_half_carry8_with_carry(ArithmeticMode::Add, 0x0F, 0x00, true)
+        );
+        assert_eq!(
+            false,
+            check_half_carry8_with_carry(ArithmeticMode::Add, 0x0E, 0x00, true)
+        );
+        assert_eq!(
+            true,
+            check_half_carry8_with_carry(ArithmeticMode::Add, 0x08, 0x07, true)
+        );
+
+        // Subtraction
+        assert_eq!(
+            true,
+            check_half_carry8_with_carry(ArithmeticMode::Subtract, 0x10, 0x00, true)
+        );
+        assert_eq!(
+            false,
+            check_half_carry8_with_carry(ArithmeticMode::Subtract, 0x11, 0x00, true)
+        );
+        assert_eq!(
+            true,
+            check_half_carry8_with_carry(ArithmeticMode::Subtract, 0x1F, 0x0F, true)
+        );
+    }
+
+    #[test]
+    fn test_check_sp_e8() {
+        // Flags only depend on the low byte, even when the offset is negative
+        assert_eq!(true, check_carry_sp_e8(0x00FF, 0x01));
+        assert_eq!(true, check_half_carry_sp_e8(0x00FF, 0x01));
+        assert_eq!(true, check_carry_sp_e8(0xFF01, 0xFF));
+        assert_eq!(false, check_half_carry_sp_e8(0xFF10, 0xFF));
+        assert_eq!(false, check_carry_sp_e8(0xFF00, 0xFF));
+        assert_eq!(false, check_half_carry_sp_e8(0xFF00, 0xFF));
+    }
+
+    #[test]
+    fn test_daa_invalid_bcd() {
+        // Inputs that adding or subtracting BCD numbers can't give, which still get adjusted by
+        // the digits and flags alone
+        let cases = [
+            // (value, N, H, C, result, carry)
+            (0x0A, false, false, false, 0x10, false),
+            (0x9A, false, false, false, 0x00, true),
+            (0xA0, false, false, false, 0x00, true),
+            (0xFF, false, false, false, 0x65, true),
+            (0x00, false, true, true, 0x66, true),
+            (0x9A, false, true, false, 0x00, true),
+            (0x99, false, false, true, 0xF9, true),
+            // Subtraction only goes by the flags, even with a digit above 9
+            (0x0F, true, false, false, 0x0F, false),
+            (0x00, true, true, false, 0xFA, false),
+            (0x00, true, false, true, 0xA0, true),
+            (0x00, true, true, true, 0x9A, true),
+        ];
+        for (value, subtract, half_carry, carry, result, result_carry) in cases {
+            assert_eq!(
+                (result, result_carry),
+                daa(value, subtract, half_carry, carry),
+                "DAA on {:#04X} with N {}, H {}, C {}",
+                value,
+                subtract,
+                half_carry,
+                carry
+            );
+        }
+    }
+
+    #[test]
+    fn test_daa_bcd() {
+        // Adding or subtracting any two BCD numbers and adjusting gives the BCD result
+        let bcd = |n: u8| ((n / 10) << 4) | (n % 10);
+
+        for a in 0..100 {
+            for b in 0..100 {
+                let sum = bcd(a).wrapping_add(bcd(b));
+                let (result, carry) = daa(
+                    sum,
+                    false,
+                    check_half_carry8(ArithmeticMode::Add, bcd(a), bcd(b)),
+                    check_carry8(ArithmeticMode::Add, bcd(a), bcd(b)),
+                );
+                assert_eq!((bcd((a + b) % 100), a + b >= 100), (result, carry));
+
+                let difference = bcd(a).wrapping_sub(bcd(b));
+                let (result, carry) = daa(
+                    difference,
+                    true,
+                    check_half_carry8(ArithmeticMode::Subtract, bcd(a), bcd(b)),
+                    check_carry8(ArithmeticMode::Subtract, bcd(a), bcd(b)),
+                );
+                assert_eq!((bcd((100 + a - b) % 100), a < b), (result, carry));
+            }
+        }
+    }
+
     #[test]
     fn test_check_carry16() {
         // Addition
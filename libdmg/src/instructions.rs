@@ -1,11 +1,16 @@
-use core::panic;
-
 use super::data::Address;
+use super::error::CpuError;
 use super::flags::*;
-use super::memory::MemoryBus;
-use super::registers::{Flag, Register, RegisterPair, Registers};
+use super::interrupts::pending_interrupts;
+use super::memory::{Bus, TimedBus, DIV};
+use super::registers::{Flag, PowerMode, Register, RegisterPair, Registers};
+
+mod table;
+
+use table::{CB_INSTRUCTIONS, INSTRUCTIONS};
 
 struct Instruction {
+    mnemonic: &'static str,
     source: Option<InstructionTarget>,
     target: Option<InstructionTarget>,
     operation: Operation,
@@ -14,7 +19,19 @@ struct Instruction {
     flags: FlagInstruction,
 }
 
-#[derive(Default)]
+impl Instruction {
+    fn source(&self) -> Result<InstructionTarget, CpuError> {
+        self.source
+            .ok_or(CpuError::DecodeFault("No source provided"))
+    }
+
+    fn target(&self) -> Result<InstructionTarget, CpuError> {
+        self.target
+            .ok_or(CpuError::DecodeFault("No target provided"))
+    }
+}
+
+#[derive(Copy, Clone, Default)]
 struct FlagInstruction {
     zero: FlagOperation,
     subtract: FlagOperation,
@@ -22,6 +39,15 @@ struct FlagInstruction {
     carry: FlagOperation,
 }
 
+impl FlagInstruction {
+    const UNMODIFIED: FlagInstruction = FlagInstruction {
+        zero: FlagOperation::Unmodified,
+        subtract: FlagOperation::Unmodified,
+        half_carry: FlagOperation::Unmodified,
+        carry: FlagOperation::Unmodified,
+    };
+}
+
 #[derive(Default)]
 struct FlagResults {
     zero: Option<FlagResult>,
@@ -30,7 +56,7 @@ struct FlagResults {
     carry: Option<FlagResult>,
 }
 
-#[derive(Default)]
+#[derive(Copy, Clone, Default)]
 enum FlagOperation {
     #[default]
     Unmodified,
@@ -44,8 +70,17 @@ enum FlagResult {
     Unset,
 }
 
+impl std::convert::From<bool> for FlagResult {
+    fn from(value: bool) -> Self {
+        match value {
+            true => FlagResult::Set,
+            false => FlagResult::Unset,
+        }
+    }
+}
+
 #[allow(non_camel_case_types)]
-#[derive(Debug)]
+#[derive(Copy, Clone, Debug)]
 enum InstructionTarget {
     A,
     B,
@@ -54,30 +89,82 @@ enum InstructionTarget {
     E,
     H,
     L,
+    AF,
     BC,
     DE,
     HL,
     SP,
-    N8(Address),
-    N16(Address),
-    Ref(Box<InstructionTarget>),
+    // Immediate values, always the last bytes of the instruction
+    N8,
+    N16,
+    Ref(&'static InstructionTarget),
+    // Memory at 0xFF00 plus the 8-bit value of the inner target, used by LDH
+    High(&'static InstructionTarget),
 }
 
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Copy, Clone)]
 enum Operation {
     NOP,
     LD,
+    // LD with a post-increment of HL, i.e. LD (HL+), A
+    LDI,
+    // LD with a post-decrement of HL, i.e. LD (HL-), A
+    LDD,
+    // LD HL, SP+i8
+    LDHL,
     INC,
     DEC,
     ADD,
+    ADC,
+    SUB,
+    SBC,
+    AND,
+    XOR,
+    OR,
+    CP,
     RXC(Direction),
+    RX(Direction),
+    SLA,
+    SRA,
+    SRL,
+    SWAP,
+    BIT(u8),
+    RES(u8),
+    SET(u8),
+    DAA,
+    CPL,
+    SCF,
+    CCF,
+    JP(Condition),
+    JR(Condition),
+    CALL(Condition),
+    RET(Condition),
+    RETI,
+    RST(Address),
+    PUSH,
+    POP,
+    DI,
+    EI,
+    HALT,
     STOP,
 }
 
+#[derive(Copy, Clone)]
 enum Direction {
     Left,
     Right,
 }
 
+#[derive(Copy, Clone)]
+enum Condition {
+    Always,
+    NotZero,
+    Zero,
+    NotCarry,
+    Carry,
+}
+
 enum InstructionSize {
     Eight,
     Sixteen,
@@ -92,472 +179,703 @@ fn get_op_size(target: &InstructionTarget) -> Option<InstructionSize> {
         InstructionTarget::E => Some(InstructionSize::Eight),
         InstructionTarget::H => Some(InstructionSize::Eight),
         InstructionTarget::L => Some(InstructionSize::Eight),
+        InstructionTarget::AF => Some(InstructionSize::Sixteen),
         InstructionTarget::BC => Some(InstructionSize::Sixteen),
         InstructionTarget::DE => Some(InstructionSize::Sixteen),
         InstructionTarget::HL => Some(InstructionSize::Sixteen),
         InstructionTarget::SP => Some(InstructionSize::Sixteen),
-        InstructionTarget::N8(_) => Some(InstructionSize::Eight),
-        InstructionTarget::N16(_) => Some(InstructionSize::Sixteen),
+        InstructionTarget::N8 => Some(InstructionSize::Eight),
+        InstructionTarget::N16 => Some(InstructionSize::Sixteen),
         InstructionTarget::Ref(_) => None,
+        InstructionTarget::High(_) => None,
     }
 }
 
-fn target_to_register(target: InstructionTarget) -> Register {
+fn target_to_register_pair(target: InstructionTarget) -> Result<RegisterPair, CpuError> {
     match target {
-        InstructionTarget::A => Register::A,
-        InstructionTarget::B => Register::B,
-        InstructionTarget::C => Register::C,
-        InstructionTarget::D => Register::D,
-        InstructionTarget::E => Register::E,
-        InstructionTarget::H => Register::H,
-        InstructionTarget::L => Register::L,
-        _ => panic!("Invalid target: {:?}, cannot convert to register", target),
-    }
-}
-
-fn target_to_register_pair(target: InstructionTarget) -> RegisterPair {
-    match target {
-        InstructionTarget::BC => RegisterPair::BC,
-        InstructionTarget::DE => RegisterPair::DE,
-        InstructionTarget::HL => RegisterPair::HL,
-        _ => panic!(
-            "Invalid target: {:?}, cannot convert to register pair",
+        InstructionTarget::AF => Ok(RegisterPair::AF),
+        InstructionTarget::BC => Ok(RegisterPair::BC),
+        InstructionTarget::DE => Ok(RegisterPair::DE),
+        InstructionTarget::HL => Ok(RegisterPair::HL),
+        _ => Err(CpuError::InvalidOperand(format!(
+            "{:?} cannot be converted to a register pair",
             target
-        ),
+        ))),
     }
 }
 
-fn get_instruction(code: u16, reg: &Registers) -> Instruction {
+fn get_instruction(code: u16, reg: &Registers) -> Result<&'static Instruction, CpuError> {
     match code {
-        0x00 => Instruction {
-            // NOP
-            source: None,
-            target: None,
-            operation: Operation::NOP,
-            cycles: 4,
-            length: 1,
-            flags: FlagInstruction::default(),
-        },
-        0x01 => Instruction {
-            // LD BC, u16
-            source: Some(InstructionTarget::N16(reg.pc + 1)),
-            target: Some(InstructionTarget::BC),
-            operation: Operation::LD,
-            cycles: 12,
-            length: 3,
-            flags: FlagInstruction::default(),
-        },
-        0x02 => Instruction {
-            // LD (BC), A
-            source: Some(InstructionTarget::A),
-            target: Some(InstructionTarget::Ref(Box::new(InstructionTarget::BC))),
-            operation: Operation::LD,
-            cycles: 4,
-            length: 1,
-            flags: FlagInstruction::default(),
-        },
-        0x03 => Instruction {
-            // INC BC
-            source: None,
-            target: Some(InstructionTarget::BC),
-            operation: Operation::INC,
-            cycles: 8,
-            length: 1,
-            flags: FlagInstruction::default(),
-        },
-        0x04 => Instruction {
-            // INC B
-            source: None,
-            target: Some(InstructionTarget::B),
-            operation: Operation::INC,
-            cycles: 4,
-            length: 1,
-            flags: FlagInstruction {
-                zero: FlagOperation::Dependent,
-                subtract: FlagOperation::Unset,
-                half_carry: FlagOperation::Dependent,
-                ..Default::default()
-            },
-        },
-        0x05 => Instruction {
-            // DEC B
-            source: None,
-            target: Some(InstructionTarget::B),
-            operation: Operation::DEC,
-            cycles: 4,
-            length: 1,
-            flags: FlagInstruction {
-                zero: FlagOperation::Dependent,
-                subtract: FlagOperation::Set,
-                half_carry: FlagOperation::Dependent,
-                ..Default::default()
-            },
-        },
-        0x06 => Instruction {
-            // LD B, u8
-            source: Some(InstructionTarget::N8(reg.pc + 1)),
-            target: Some(InstructionTarget::B),
-            operation: Operation::LD,
-            cycles: 8,
-            length: 2,
-            flags: FlagInstruction::default(),
-        },
-        0x07 => Instruction {
-            // RLCA
-            source: None,
-            target: Some(InstructionTarget::A),
-            operation: Operation::RXC(Direction::Left),
-            cycles: 4,
-            length: 1,
-            flags: FlagInstruction {
-                zero: FlagOperation::Unset,
-                subtract: FlagOperation::Unset,
-                half_carry: FlagOperation::Unset,
-                carry: FlagOperation::Dependent,
-            },
-        },
-        0x08 => Instruction {
-            // LD (u16), SP
-            source: Some(InstructionTarget::SP),
-            target: Some(InstructionTarget::Ref(Box::new(InstructionTarget::N16(
-                reg.pc + 1,
-            )))),
-            operation: Operation::LD,
-            cycles: 20,
-            length: 3,
-            flags: FlagInstruction::default(),
-        },
-        0x09 => Instruction {
-            // ADD HL, BC
-            source: Some(InstructionTarget::BC),
-            target: Some(InstructionTarget::HL),
-            operation: Operation::ADD,
-            cycles: 8,
-            length: 1,
-            flags: FlagInstruction {
-                subtract: FlagOperation::Unset,
-                half_carry: FlagOperation::Dependent,
-                carry: FlagOperation::Dependent,
-                ..Default::default()
-            },
-        },
-        0x0A => Instruction {
-            // LD A, (BC)
-            source: Some(InstructionTarget::Ref(Box::new(InstructionTarget::BC))),
-            target: Some(InstructionTarget::A),
-            operation: Operation::LD,
-            cycles: 8,
-            length: 1,
-            flags: FlagInstruction::default(),
-        },
-        0x0B => Instruction {
-            // DEC BC
-            source: None,
-            target: Some(InstructionTarget::BC),
-            operation: Operation::DEC,
-            cycles: 8,
-            length: 1,
-            flags: FlagInstruction::default(),
-        },
-        0x0C => Instruction {
-            // INC C
-            source: None,
-            target: Some(InstructionTarget::C),
-            operation: Operation::INC,
-            cycles: 4,
-            length: 1,
-            flags: FlagInstruction {
-                zero: FlagOperation::Dependent,
-                subtract: FlagOperation::Unset,
-                half_carry: FlagOperation::Dependent,
-                ..Default::default()
-            },
-        },
-        0x0D => Instruction {
-            // DEC C
-            source: None,
-            target: Some(InstructionTarget::C),
-            operation: Operation::DEC,
-            cycles: 4,
-            length: 1,
-            flags: FlagInstruction {
-                zero: FlagOperation::Dependent,
-                subtract: FlagOperation::Set,
-                half_carry: FlagOperation::Dependent,
-                ..Default::default()
-            }
-        },
-        0x0E => Instruction {
-            // LD C, u8
-            source: Some(InstructionTarget::N8(reg.pc + 1)),
-            target: Some(InstructionTarget::C),
-            operation: Operation::LD,
-            cycles: 8,
-            length: 2,
-            flags: FlagInstruction::default(),
-        },
-        0x0F => Instruction {
-            // RRCA
-            source: None,
-            target: Some(InstructionTarget::A),
-            operation: Operation::RXC(Direction::Right),
-            cycles: 4,
-            length: 1,
-            flags: FlagInstruction {
-                zero: FlagOperation::Unset,
-                subtract: FlagOperation::Unset,
-                half_carry: FlagOperation::Unset,
-                carry: FlagOperation::Dependent,
-            }
-        },
-        0x10 => Instruction {
-            // STOP
-            source: None,
-            target: None,
-            operation: Operation::STOP,
-            cycles: 4,
-            length: 1,
-            flags: FlagInstruction::default(),
-        },
-        _ => panic!("Unsupported instruction: {:#X}", code),
+        // Every 0xCB opcode exists, only the unprefixed table has gaps
+        0xCB00..=0xCBFF => Ok(&CB_INSTRUCTIONS[(code & 0xFF) as usize]),
+        _ => INSTRUCTIONS
+            .get(code as usize)
+            .and_then(Option::as_ref)
+            .ok_or(CpuError::IllegalOpcode {
+                opcode: code as u8,
+                address: reg.pc,
+            }),
     }
 }
 
-pub fn execute_instruction(code: u16, reg: &mut Registers, mem: &mut MemoryBus) -> u8 {
-    let instr = get_instruction(code, reg);
+pub fn execute_instruction<B: Bus>(
+    code: u16,
+    reg: &mut Registers,
+    mem: &mut TimedBus<B>,
+) -> Result<u8, CpuError> {
+    // 0xCB is a prefix, the following byte selects an instruction from the extended set
+    let code = match code {
+        0xCB => 0xCB00 | mem.read(reg.pc.wrapping_add(1)) as u16,
+        _ => code,
+    };
+    let instr = get_instruction(code, reg)?;
+
+    // Move the program counter past the instruction before executing it so that jumps, calls and
+    // relative offsets all work from the address of the next instruction. Immediate operands are
+    // always the last bytes of an instruction, so they're read relative to the new PC
+    reg.pc = reg.pc.wrapping_add(instr.length as u16);
+
+    execute(instr, reg, mem).map_err(|error| match error {
+        CpuError::InvalidOperand(message) => {
+            CpuError::InvalidOperand(format!("{}: {}", instr.mnemonic, message))
+        }
+        error => error,
+    })
+}
+
+fn execute<B: Bus>(
+    instr: &Instruction,
+    reg: &mut Registers,
+    mem: &mut TimedBus<B>,
+) -> Result<u8, CpuError> {
+    let mut cycles = instr.cycles;
 
     match instr.operation {
         Operation::NOP => {}
         Operation::LD => {
-            let source = instr.source.unwrap();
-            let target = instr.target.unwrap();
+            let source = instr.source()?;
+            let target = instr.target()?;
 
-            let op_size;
-            if get_op_size(&source).is_some() {
-                op_size = get_op_size(&source).unwrap();
-            } else {
-                op_size = get_op_size(&target).expect("Cannot determine instruction size");
-            }
+            let op_size = match get_op_size(&source) {
+                Some(size) => size,
+                None => get_op_size(&target)
+                    .ok_or(CpuError::DecodeFault("Cannot determine instruction size"))?,
+            };
 
             match op_size {
                 InstructionSize::Eight => {
-                    let value = get_x8(&source, reg, mem);
-                    set_x8(&target, reg, mem, value);
+                    let value = get_x8(&source, reg, mem)?;
+                    set_x8(&target, reg, mem, value)?;
                 }
                 InstructionSize::Sixteen => {
-                    let value = get_x16(&source, reg, mem);
-                    set_x16(&target, reg, mem, value);
+                    let value = get_x16(&source, reg, mem)?;
+                    set_x16(&target, reg, mem, value)?;
                 }
             }
         }
+        Operation::LDI => {
+            let source = instr.source()?;
+            let target = instr.target()?;
+
+            let value = get_x8(&source, reg, mem)?;
+            set_x8(&target, reg, mem, value)?;
+
+            let hl = reg.get_reg16(RegisterPair::HL);
+            reg.set_reg16(RegisterPair::HL, hl.wrapping_add(1));
+        }
+        Operation::LDD => {
+            let source = instr.source()?;
+            let target = instr.target()?;
+
+            let value = get_x8(&source, reg, mem)?;
+            set_x8(&target, reg, mem, value)?;
+
+            let hl = reg.get_reg16(RegisterPair::HL);
+            reg.set_reg16(RegisterPair::HL, hl.wrapping_sub(1));
+        }
+        Operation::LDHL => {
+            let source = instr.source()?;
+            let target = instr.target()?;
+            let mut results = FlagResults::default();
+
+            let offset = get_x8(&source, reg, mem)?;
+            let sp = reg.sp;
+            set_x16(&target, reg, mem, sp.wrapping_add(offset as i8 as u16))?;
+
+            results.half_carry = Some(check_half_carry_sp_e8(sp, offset).into());
+            results.carry = Some(check_carry_sp_e8(sp, offset).into());
+
+            modify_flags(reg, instr.flags, results)?
+        }
         Operation::INC => {
-            let target = instr.target.unwrap();
+            let target = instr.target()?;
             let mut results = FlagResults::default();
 
-            match get_op_size(&target).unwrap() {
+            // The only memory target is (HL), which is always 8-bit
+            match get_op_size(&target).unwrap_or(InstructionSize::Eight) {
                 InstructionSize::Eight => {
-                    let value = get_x8(&target, reg, mem);
-                    set_x8(&target, reg, mem, value.wrapping_add(1));
-                    results.zero = Some(FlagResult::Unset);
+                    let value = get_x8(&target, reg, mem)?;
+                    let result = value.wrapping_add(1);
+                    set_x8(&target, reg, mem, result)?;
+                    results.zero = Some((result == 0).into());
                     results.half_carry =
-                        Some(match check_half_carry8(ArithmeticMode::Add, value, 1) {
-                            true => FlagResult::Set,
-                            false => FlagResult::Unset,
-                        });
+                        Some(check_half_carry8(ArithmeticMode::Add, value, 1).into());
                 }
                 InstructionSize::Sixteen => {
-                    let value = get_x16(&target, reg, mem);
-                    set_x16(&target, reg, mem, value.wrapping_add(1));
-                    results.zero = Some(FlagResult::Unset);
-                    results.half_carry =
-                        Some(match check_half_carry16(ArithmeticMode::Add, value, 1) {
-                            true => FlagResult::Set,
-                            false => FlagResult::Unset,
-                        });
+                    let value = get_x16(&target, reg, mem)?;
+                    set_x16(&target, reg, mem, value.wrapping_add(1))?;
                 }
             }
 
-            modify_flags(reg, instr.flags, results)
+            modify_flags(reg, instr.flags, results)?
         }
         Operation::DEC => {
-            let target = instr.target.unwrap();
+            let target = instr.target()?;
             let mut results = FlagResults::default();
 
-            match get_op_size(&target).unwrap() {
+            // The only memory target is (HL), which is always 8-bit
+            match get_op_size(&target).unwrap_or(InstructionSize::Eight) {
                 InstructionSize::Eight => {
-                    let value = get_x8(&target, reg, mem);
-                    // TODO: check for integer overflow
-                    set_x8(&target, reg, mem, value.wrapping_sub(1));
-                    results.zero = Some(match value.wrapping_sub(1) {
-                        0 => FlagResult::Set,
-                        _ => FlagResult::Unset,
-                    });
-                    results.half_carry = Some(
-                        match check_half_carry8(ArithmeticMode::Subtract, value, 1) {
-                            true => FlagResult::Set,
-                            false => FlagResult::Unset,
-                        },
-                    );
+                    let value = get_x8(&target, reg, mem)?;
+                    let result = value.wrapping_sub(1);
+                    set_x8(&target, reg, mem, result)?;
+                    results.zero = Some((result == 0).into());
+                    results.half_carry =
+                        Some(check_half_carry8(ArithmeticMode::Subtract, value, 1).into());
                 }
                 InstructionSize::Sixteen => {
-                    let value = get_x16(&target, reg, mem);
-                    set_x16(&target, reg, mem, value.wrapping_sub(1));
-                    results.zero = Some(match value.wrapping_sub(1) {
-                        0 => FlagResult::Set,
-                        _ => FlagResult::Unset,
-                    });
-                    results.half_carry = Some(
-                        match check_half_carry16(ArithmeticMode::Subtract, value, 1) {
-                            true => FlagResult::Set,
-                            false => FlagResult::Unset,
-                        },
-                    );
+                    let value = get_x16(&target, reg, mem)?;
+                    set_x16(&target, reg, mem, value.wrapping_sub(1))?;
                 }
             }
 
-            modify_flags(reg, instr.flags, results)
+            modify_flags(reg, instr.flags, results)?
         }
         Operation::ADD => {
-            let source = instr.source.unwrap();
-            let target = instr.target.unwrap();
+            let source = instr.source()?;
+            let target = instr.target()?;
             let mut results = FlagResults::default();
 
-            let op_size;
-            if get_op_size(&source).is_some() {
-                op_size = get_op_size(&source).unwrap();
-            } else {
-                op_size = get_op_size(&target).expect("Cannot determine instruction size");
-            }
-
-            match op_size {
-                InstructionSize::Eight => {
-                    todo!("Add 8-bit")
+            match (get_op_size(&target), get_op_size(&source)) {
+                (Some(InstructionSize::Eight), _) => {
+                    let source_value = get_x8(&source, reg, mem)?;
+                    let target_value = get_x8(&target, reg, mem)?;
+                    let result = target_value.wrapping_add(source_value);
+                    set_x8(&target, reg, mem, result)?;
+                    results.zero = Some((result == 0).into());
+                    results.half_carry = Some(
+                        check_half_carry8(ArithmeticMode::Add, target_value, source_value).into(),
+                    );
+                    results.carry =
+                        Some(check_carry8(ArithmeticMode::Add, target_value, source_value).into());
                 }
-                InstructionSize::Sixteen => {
-                    let source_value = get_x16(&source, reg, mem);
-                    let target_value = get_x16(&target, reg, mem);
-                    set_x16(&target, reg, mem, target_value.wrapping_add(source_value));
-                    results.zero = Some(match target_value.wrapping_add(source_value) {
-                        0 => FlagResult::Set,
-                        _ => FlagResult::Unset,
-                    });
+                (Some(InstructionSize::Sixteen), Some(InstructionSize::Sixteen)) => {
+                    let source_value = get_x16(&source, reg, mem)?;
+                    let target_value = get_x16(&target, reg, mem)?;
+                    set_x16(&target, reg, mem, target_value.wrapping_add(source_value))?;
                     results.half_carry = Some(
-                        match check_half_carry16(ArithmeticMode::Add, target_value, source_value) {
-                            true => FlagResult::Set,
-                            false => FlagResult::Unset,
-                        },
+                        check_half_carry16(ArithmeticMode::Add, target_value, source_value).into(),
                     );
-                    results.carry = Some(
-                        match check_carry16(ArithmeticMode::Add, target_value, source_value) {
-                            true => FlagResult::Set,
-                            false => FlagResult::Unset,
-                        }
-                    )
+                    results.carry =
+                        Some(check_carry16(ArithmeticMode::Add, target_value, source_value).into());
+                }
+                (Some(InstructionSize::Sixteen), Some(InstructionSize::Eight)) => {
+                    // ADD SP, i8
+                    let offset = get_x8(&source, reg, mem)?;
+                    let target_value = get_x16(&target, reg, mem)?;
+                    set_x16(
+                        &target,
+                        reg,
+                        mem,
+                        target_value.wrapping_add(offset as i8 as u16),
+                    )?;
+                    results.half_carry = Some(check_half_carry_sp_e8(target_value, offset).into());
+                    results.carry = Some(check_carry_sp_e8(target_value, offset).into());
+                }
+                _ => {
+                    return Err(CpuError::InvalidOperand(format!(
+                        "ADD cannot be used with {:?} and {:?}",
+                        target, source
+                    )))
                 }
             }
 
-            modify_flags(reg, instr.flags, results)
+            modify_flags(reg, instr.flags, results)?
+        }
+        Operation::ADC => {
+            let source = instr.source()?;
+            let target = instr.target()?;
+            let mut results = FlagResults::default();
+
+            let source_value = get_x8(&source, reg, mem)?;
+            let target_value = get_x8(&target, reg, mem)?;
+            let carry = reg.get_flag(Flag::Carry);
+            let result = target_value
+                .wrapping_add(source_value)
+                .wrapping_add(carry as u8);
+            set_x8(&target, reg, mem, result)?;
+
+            results.zero = Some((result == 0).into());
+            results.half_carry = Some(
+                check_half_carry8_with_carry(
+                    ArithmeticMode::Add,
+                    target_value,
+                    source_value,
+                    carry,
+                )
+                .into(),
+            );
+            results.carry = Some(
+                check_carry8_with_carry(ArithmeticMode::Add, target_value, source_value, carry)
+                    .into(),
+            );
+
+            modify_flags(reg, instr.flags, results)?
+        }
+        Operation::SUB => {
+            let source = instr.source()?;
+            let target = instr.target()?;
+            let mut results = FlagResults::default();
+
+            let source_value = get_x8(&source, reg, mem)?;
+            let target_value = get_x8(&target, reg, mem)?;
+            let result = target_value.wrapping_sub(source_value);
+            set_x8(&target, reg, mem, result)?;
+
+            results.zero = Some((result == 0).into());
+            results.half_carry = Some(
+                check_half_carry8(ArithmeticMode::Subtract, target_value, source_value).into(),
+            );
+            results.carry =
+                Some(check_carry8(ArithmeticMode::Subtract, target_value, source_value).into());
+
+            modify_flags(reg, instr.flags, results)?
+        }
+        Operation::SBC => {
+            let source = instr.source()?;
+            let target = instr.target()?;
+            let mut results = FlagResults::default();
+
+            let source_value = get_x8(&source, reg, mem)?;
+            let target_value = get_x8(&target, reg, mem)?;
+            let carry = reg.get_flag(Flag::Carry);
+            let result = target_value
+                .wrapping_sub(source_value)
+                .wrapping_sub(carry as u8);
+            set_x8(&target, reg, mem, result)?;
+
+            results.zero = Some((result == 0).into());
+            results.half_carry = Some(
+                check_half_carry8_with_carry(
+                    ArithmeticMode::Subtract,
+                    target_value,
+                    source_value,
+                    carry,
+                )
+                .into(),
+            );
+            results.carry = Some(
+                check_carry8_with_carry(
+                    ArithmeticMode::Subtract,
+                    target_value,
+                    source_value,
+                    carry,
+                )
+                .into(),
+            );
+
+            modify_flags(reg, instr.flags, results)?
+        }
+        Operation::AND => {
+            let source = instr.source()?;
+            let target = instr.target()?;
+            let mut results = FlagResults::default();
+
+            let result = get_x8(&target, reg, mem)? & get_x8(&source, reg, mem)?;
+            set_x8(&target, reg, mem, result)?;
+            results.zero = Some((result == 0).into());
+
+            modify_flags(reg, instr.flags, results)?
+        }
+        Operation::XOR => {
+            let source = instr.source()?;
+            let target = instr.target()?;
+            let mut results = FlagResults::default();
+
+            let result = get_x8(&target, reg, mem)? ^ get_x8(&source, reg, mem)?;
+            set_x8(&target, reg, mem, result)?;
+            results.zero = Some((result == 0).into());
+
+            modify_flags(reg, instr.flags, results)?
+        }
+        Operation::OR => {
+            let source = instr.source()?;
+            let target = instr.target()?;
+            let mut results = FlagResults::default();
+
+            let result = get_x8(&target, reg, mem)? | get_x8(&source, reg, mem)?;
+            set_x8(&target, reg, mem, result)?;
+            results.zero = Some((result == 0).into());
+
+            modify_flags(reg, instr.flags, results)?
+        }
+        Operation::CP => {
+            let source = instr.source()?;
+            let target = instr.target()?;
+            let mut results = FlagResults::default();
+
+            // Same as SUB but the result is discarded
+            let source_value = get_x8(&source, reg, mem)?;
+            let target_value = get_x8(&target, reg, mem)?;
+
+            results.zero = Some((target_value == source_value).into());
+            results.half_carry = Some(
+                check_half_carry8(ArithmeticMode::Subtract, target_value, source_value).into(),
+            );
+            results.carry =
+                Some(check_carry8(ArithmeticMode::Subtract, target_value, source_value).into());
+
+            modify_flags(reg, instr.flags, results)?
         }
         Operation::RXC(direction) => {
+            let target = instr.target()?;
             let mut results = FlagResults::default();
-            match direction {
+
+            let value = get_x8(&target, reg, mem)?;
+            let result = match direction {
                 Direction::Left => {
-                    let register = target_to_register(instr.target.expect("No target provided"));
-                    let value = reg.get_reg8(register.clone());
-                    let bit7 = (value & 0x80) >> 7;
+                    results.carry = Some((value & 0x80 != 0).into());
+                    value.rotate_left(1)
+                }
+                Direction::Right => {
+                    results.carry = Some((value & 0x01 != 0).into());
+                    value.rotate_right(1)
+                }
+            };
+            set_x8(&target, reg, mem, result)?;
+            results.zero = Some((result == 0).into());
 
-                    reg.set_reg8(register, (value << 1) + bit7);
+            modify_flags(reg, instr.flags, results)?
+        }
+        Operation::RX(direction) => {
+            let target = instr.target()?;
+            let mut results = FlagResults::default();
+
+            let value = get_x8(&target, reg, mem)?;
+            let carry = reg.get_flag(Flag::Carry) as u8;
 
-                    results.carry = Some(match bit7 {
-                        0 => FlagResult::Unset,
-                        1 => FlagResult::Set,
-                        _ => unreachable!(),
-                    });
+            // Rotate through the carry flag, the bit shifted out becomes the new carry
+            let result = match direction {
+                Direction::Left => {
+                    results.carry = Some((value & 0x80 != 0).into());
+                    (value << 1) | carry
                 }
                 Direction::Right => {
-                    let register = target_to_register(instr.target.expect("No target provided"));
-                    let value = reg.get_reg8(register.clone());
-                    let bit0 = (value & 0x01) << 7;
+                    results.carry = Some((value & 0x01 != 0).into());
+                    (value >> 1) | (carry << 7)
+                }
+            };
+            set_x8(&target, reg, mem, result)?;
+            results.zero = Some((result == 0).into());
 
-                    reg.set_reg8(register, (value >> 1) + bit0);
+            modify_flags(reg, instr.flags, results)?
+        }
+        Operation::SLA => {
+            let target = instr.target()?;
+            let mut results = FlagResults::default();
 
-                    results.carry = Some(match bit0 >> 7 {
-                        0 => FlagResult::Unset,
-                        1 => FlagResult::Set,
-                        _ => unreachable!(),
-                    })
-                }
+            let value = get_x8(&target, reg, mem)?;
+            let result = value << 1;
+            set_x8(&target, reg, mem, result)?;
+
+            results.zero = Some((result == 0).into());
+            results.carry = Some((value & 0x80 != 0).into());
+
+            modify_flags(reg, instr.flags, results)?
+        }
+        Operation::SRA => {
+            let target = instr.target()?;
+            let mut results = FlagResults::default();
+
+            // Arithmetic shift, bit 7 keeps its value
+            let value = get_x8(&target, reg, mem)?;
+            let result = (value >> 1) | (value & 0x80);
+            set_x8(&target, reg, mem, result)?;
+
+            results.zero = Some((result == 0).into());
+            results.carry = Some((value & 0x01 != 0).into());
+
+            modify_flags(reg, instr.flags, results)?
+        }
+        Operation::SRL => {
+            let target = instr.target()?;
+            let mut results = FlagResults::default();
+
+            let value = get_x8(&target, reg, mem)?;
+            let result = value >> 1;
+            set_x8(&target, reg, mem, result)?;
+
+            results.zero = Some((result == 0).into());
+            results.carry = Some((value & 0x01 != 0).into());
+
+            modify_flags(reg, instr.flags, results)?
+        }
+        Operation::SWAP => {
+            let target = instr.target()?;
+            let mut results = FlagResults::default();
+
+            let result = get_x8(&target, reg, mem)?.rotate_left(4);
+            set_x8(&target, reg, mem, result)?;
+
+            results.zero = Some((result == 0).into());
+
+            modify_flags(reg, instr.flags, results)?
+        }
+        Operation::BIT(bit) => {
+            let target = instr.target()?;
+            let mut results = FlagResults::default();
+
+            let value = get_x8(&target, reg, mem)?;
+            results.zero = Some((value & (1 << bit) == 0).into());
+
+            modify_flags(reg, instr.flags, results)?
+        }
+        Operation::RES(bit) => {
+            let target = instr.target()?;
+
+            let value = get_x8(&target, reg, mem)?;
+            set_x8(&target, reg, mem, value & !(1 << bit))?;
+        }
+        Operation::SET(bit) => {
+            let target = instr.target()?;
+
+            let value = get_x8(&target, reg, mem)?;
+            set_x8(&target, reg, mem, value | (1 << bit))?;
+        }
+        Operation::DAA => {
+            let target = instr.target()?;
+            let mut results = FlagResults::default();
+
+            let value = get_x8(&target, reg, mem)?;
+            let (result, carry) = daa(
+                value,
+                reg.get_flag(Flag::Subtract),
+                reg.get_flag(Flag::HalfCarry),
+                reg.get_flag(Flag::Carry),
+            );
+            set_x8(&target, reg, mem, result)?;
+
+            results.zero = Some((result == 0).into());
+            results.carry = Some(carry.into());
+
+            modify_flags(reg, instr.flags, results)?
+        }
+        Operation::CPL => {
+            let target = instr.target()?;
+
+            let value = get_x8(&target, reg, mem)?;
+            set_x8(&target, reg, mem, !value)?;
+
+            modify_flags(reg, instr.flags, FlagResults::default())?
+        }
+        Operation::SCF => modify_flags(reg, instr.flags, FlagResults::default())?,
+        Operation::CCF => {
+            let results = FlagResults {
+                carry: Some((!reg.get_flag(Flag::Carry)).into()),
+                ..Default::default()
+            };
+
+            modify_flags(reg, instr.flags, results)?
+        }
+        Operation::JP(condition) => {
+            let addr = get_x16(&instr.source()?, reg, mem)?;
+
+            if check_condition(&condition, reg) {
+                reg.pc = addr;
+                cycles += branch_cycles(&condition, 4);
             }
+        }
+        Operation::JR(condition) => {
+            let offset = get_x8(&instr.source()?, reg, mem)? as i8;
 
-            modify_flags(reg, instr.flags, results)
-        },
+            if check_condition(&condition, reg) {
+                reg.pc = reg.pc.wrapping_add(offset as u16);
+                cycles += branch_cycles(&condition, 4);
+            }
+        }
+        Operation::CALL(condition) => {
+            let addr = get_x16(&instr.source()?, reg, mem)?;
+
+            if check_condition(&condition, reg) {
+                let pc = reg.pc;
+                // SP is decremented in an internal cycle before the return address is pushed
+                mem.idle();
+                push16(reg, mem, pc);
+                reg.pc = addr;
+                cycles += branch_cycles(&condition, 12);
+            }
+        }
+        Operation::RET(condition) => {
+            // Conditional returns spend a cycle checking the flags before popping
+            if !matches!(condition, Condition::Always) {
+                mem.idle();
+            }
+
+            if check_condition(&condition, reg) {
+                reg.pc = pop16(reg, mem);
+                cycles += branch_cycles(&condition, 12);
+            }
+        }
+        Operation::RETI => {
+            reg.pc = pop16(reg, mem);
+            reg.ime = true;
+        }
+        Operation::RST(vector) => {
+            let pc = reg.pc;
+            mem.idle();
+            push16(reg, mem, pc);
+            reg.pc = vector;
+        }
+        Operation::PUSH => {
+            let pair = target_to_register_pair(instr.source()?)?;
+            let value = reg.get_reg16(pair);
+            mem.idle();
+            push16(reg, mem, value);
+        }
+        Operation::POP => {
+            let pair = target_to_register_pair(instr.target()?)?;
+            let value = pop16(reg, mem);
+            reg.set_reg16(pair, value);
+        }
+        Operation::DI => {
+            reg.ime = false;
+            reg.ime_scheduled = false;
+        }
+        Operation::EI => reg.ime_scheduled = true,
+        Operation::HALT => {
+            // With IME unset and an interrupt already pending the CPU doesn't halt, and instead
+            // fails to increment PC after reading the next opcode
+            if !reg.ime && pending_interrupts(mem.memory()) != 0 {
+                reg.halt_bug = true;
+            } else {
+                reg.power_mode = PowerMode::Halted;
+            }
+        }
         Operation::STOP => {
-            // TODO: implement stop, should halt the CPU and screen until a button is pressed
+            // Entering STOP always resets the divider
+            mem.memory().write(DIV, 0x00);
+            reg.power_mode = PowerMode::Stopped;
         }
     }
 
-    // Increment the program counter
-    reg.pc += instr.length as u16;
+    Ok(cycles)
+}
+
+fn check_condition(condition: &Condition, reg: &Registers) -> bool {
+    match condition {
+        Condition::Always => true,
+        Condition::NotZero => !reg.get_flag(Flag::Zero),
+        Condition::Zero => reg.get_flag(Flag::Zero),
+        Condition::NotCarry => !reg.get_flag(Flag::Carry),
+        Condition::Carry => reg.get_flag(Flag::Carry),
+    }
+}
+
+// Conditional jumps, calls and returns take extra cycles when the branch is taken, the cycle counts
+// in the instruction table for unconditional branches already include them
+fn branch_cycles(condition: &Condition, extra: u8) -> u8 {
+    match condition {
+        Condition::Always => 0,
+        _ => extra,
+    }
+}
+
+fn push16<B: Bus>(reg: &mut Registers, mem: &mut TimedBus<B>, value: u16) {
+    let [low, high] = value.to_le_bytes();
+    reg.sp = reg.sp.wrapping_sub(1);
+    mem.write(reg.sp, high);
+    reg.sp = reg.sp.wrapping_sub(1);
+    mem.write(reg.sp, low);
+}
 
-    instr.cycles
+fn pop16<B: Bus>(reg: &mut Registers, mem: &mut TimedBus<B>) -> u16 {
+    let low = mem.read(reg.sp);
+    reg.sp = reg.sp.wrapping_add(1);
+    let high = mem.read(reg.sp);
+    reg.sp = reg.sp.wrapping_add(1);
+    u16::from_le_bytes([low, high])
 }
 
-fn modify_flags(reg: &mut Registers, instr: FlagInstruction, results: FlagResults) {
+fn modify_flags(
+    reg: &mut Registers,
+    instr: FlagInstruction,
+    results: FlagResults,
+) -> Result<(), CpuError> {
     let zero = match (instr.zero, results.zero) {
         (FlagOperation::Dependent, Some(FlagResult::Set)) => Some(true),
         (FlagOperation::Dependent, Some(FlagResult::Unset)) => Some(false),
-        (FlagOperation::Dependent, None) => panic!("Dependent flag not provided result"),
+        (FlagOperation::Dependent, None) => {
+            return Err(CpuError::DecodeFault("Dependent flag not provided result"))
+        }
         (FlagOperation::Unset, _) => Some(false),
         (FlagOperation::Set, _) => Some(true),
         (FlagOperation::Unmodified, _) => None,
     };
-    match zero {
-        Some(value) => reg.set_flag(Flag::Zero, value),
-        None => {}
+    if let Some(value) = zero {
+        reg.set_flag(Flag::Zero, value)
     }
 
     let subtract = match (instr.subtract, results.subtract) {
         (FlagOperation::Dependent, Some(FlagResult::Set)) => Some(true),
         (FlagOperation::Dependent, Some(FlagResult::Unset)) => Some(false),
-        (FlagOperation::Dependent, None) => panic!("Dependent flag not provided result"),
+        (FlagOperation::Dependent, None) => {
+            return Err(CpuError::DecodeFault("Dependent flag not provided result"))
+        }
         (FlagOperation::Set, _) => Some(true),
         (FlagOperation::Unset, _) => Some(false),
         (FlagOperation::Unmodified, _) => None,
     };
-    match subtract {
-        Some(value) => reg.set_flag(Flag::Subtract, value),
-        None => {}
+    if let Some(value) = subtract {
+        reg.set_flag(Flag::Subtract, value)
     }
 
     let half_carry = match (instr.half_carry, results.half_carry) {
         (FlagOperation::Dependent, Some(FlagResult::Set)) => Some(true),
         (FlagOperation::Dependent, Some(FlagResult::Unset)) => Some(false),
-        (FlagOperation::Dependent, None) => panic!("Dependent flag not provided result"),
+        (FlagOperation::Dependent, None) => {
+            return Err(CpuError::DecodeFault("Dependent flag not provided result"))
+        }
         (FlagOperation::Set, _) => Some(true),
         (FlagOperation::Unset, _) => Some(false),
         (FlagOperation::Unmodified, _) => None,
     };
-    match half_carry {
-        Some(value) => reg.set_flag(Flag::HalfCarry, value),
-        None => {}
+    if let Some(value) = half_carry {
+        reg.set_flag(Flag::HalfCarry, value)
     }
 
     let carry = match (instr.carry, results.carry) {
         (FlagOperation::Dependent, Some(FlagResult::Set)) => Some(true),
         (FlagOperation::Dependent, Some(FlagResult::Unset)) => Some(false),
-        (FlagOperation::Dependent, None) => panic!("Dependent flag not provided result"),
+        (FlagOperation::Dependent, None) => {
+            return Err(CpuError::DecodeFault("Dependent flag not provided result"))
+        }
         (FlagOperation::Set, _) => Some(true),
         (FlagOperation::Unset, _) => Some(false),
         (FlagOperation::Unmodified, _) => None,
     };
-    match carry {
-        Some(value) => reg.set_flag(Flag::Carry, value),
-        None => {}
+    if let Some(value) = carry {
+        reg.set_flag(Flag::Carry, value)
     }
+
+    Ok(())
 }
 
-fn get_x8(target: &InstructionTarget, reg: &Registers, mem: &MemoryBus) -> u8 {
-    match target {
+fn get_x8<B: Bus>(
+    target: &InstructionTarget,
+    reg: &Registers,
+    mem: &mut TimedBus<B>,
+) -> Result<u8, CpuError> {
+    let value = match target {
         InstructionTarget::A => reg.get_reg8(Register::A),
         InstructionTarget::B => reg.get_reg8(Register::B),
         InstructionTarget::C => reg.get_reg8(Register::C),
@@ -565,33 +883,61 @@ fn get_x8(target: &InstructionTarget, reg: &Registers, mem: &MemoryBus) -> u8 {
         InstructionTarget::E => reg.get_reg8(Register::E),
         InstructionTarget::H => reg.get_reg8(Register::H),
         InstructionTarget::L => reg.get_reg8(Register::L),
-        InstructionTarget::N8(addr) => mem.read(*addr),
+        InstructionTarget::N8 => mem.read(reg.pc.wrapping_sub(1)),
         InstructionTarget::Ref(inner) => {
-            let addr = get_x16(&inner, reg, mem);
+            let addr = get_x16(inner, reg, mem)?;
             mem.read(addr)
         }
-        _ => panic!("Unsupported target for 8-bit value read"),
-    }
+        InstructionTarget::High(inner) => {
+            let addr = 0xFF00 | get_x8(inner, reg, mem)? as Address;
+            mem.read(addr)
+        }
+        _ => {
+            return Err(CpuError::InvalidOperand(format!(
+                "{:?} cannot be read as an 8-bit value",
+                target
+            )))
+        }
+    };
+
+    Ok(value)
 }
 
-fn get_x16(target: &InstructionTarget, reg: &Registers, mem: &MemoryBus) -> u16 {
-    match target {
+fn get_x16<B: Bus>(
+    target: &InstructionTarget,
+    reg: &Registers,
+    mem: &mut TimedBus<B>,
+) -> Result<u16, CpuError> {
+    let value = match target {
         InstructionTarget::BC => reg.get_reg16(RegisterPair::BC),
         InstructionTarget::DE => reg.get_reg16(RegisterPair::DE),
         InstructionTarget::HL => reg.get_reg16(RegisterPair::HL),
         InstructionTarget::SP => reg.sp,
-        InstructionTarget::N16(addr) => {
-            u16::from_le_bytes([mem.read(*addr), mem.read((*addr) + 1)])
-        }
+        InstructionTarget::N16 => u16::from_le_bytes([
+            mem.read(reg.pc.wrapping_sub(2)),
+            mem.read(reg.pc.wrapping_sub(1)),
+        ]),
         InstructionTarget::Ref(inner) => {
-            let addr = get_x16(&inner, reg, mem);
-            u16::from_le_bytes([mem.read(addr), mem.read((addr) + 1)])
+            let addr = get_x16(inner, reg, mem)?;
+            u16::from_le_bytes([mem.read(addr), mem.read(addr.wrapping_add(1))])
         }
-        _ => panic!("Unsupported target for 16-bit value read"),
-    }
+        _ => {
+            return Err(CpuError::InvalidOperand(format!(
+                "{:?} cannot be read as a 16-bit value",
+                target
+            )))
+        }
+    };
+
+    Ok(value)
 }
 
-fn set_x8(target: &InstructionTarget, reg: &mut Registers, mem: &mut MemoryBus, value: u8) {
+fn set_x8<B: Bus>(
+    target: &InstructionTarget,
+    reg: &mut Registers,
+    mem: &mut TimedBus<B>,
+    value: u8,
+) -> Result<(), CpuError> {
     match target {
         InstructionTarget::A => reg.set_reg8(Register::A, value),
         InstructionTarget::B => reg.set_reg8(Register::B, value),
@@ -600,34 +946,61 @@ fn set_x8(target: &InstructionTarget, reg: &mut Registers, mem: &mut MemoryBus,
         InstructionTarget::E => reg.set_reg8(Register::E, value),
         InstructionTarget::H => reg.set_reg8(Register::H, value),
         InstructionTarget::L => reg.set_reg8(Register::L, value),
-        InstructionTarget::N8(addr) => mem.write(*addr, value),
+        InstructionTarget::N8 => mem.write(reg.pc.wrapping_sub(1), value),
         InstructionTarget::Ref(inner) => {
-            let addr = get_x16(&inner, reg, mem);
+            let addr = get_x16(inner, reg, mem)?;
+            mem.write(addr, value);
+        }
+        InstructionTarget::High(inner) => {
+            let addr = 0xFF00 | get_x8(inner, reg, mem)? as Address;
             mem.write(addr, value);
         }
-        _ => panic!("Unsupported target for 8-bit value write"),
+        _ => {
+            return Err(CpuError::InvalidOperand(format!(
+                "{:?} cannot be written as an 8-bit value",
+                target
+            )))
+        }
     }
+
+    Ok(())
 }
 
-fn set_x16(target: &InstructionTarget, reg: &mut Registers, mem: &mut MemoryBus, value: u16) {
+fn set_x16<B: Bus>(
+    target: &InstructionTarget,
+    reg: &mut Registers,
+    mem: &mut TimedBus<B>,
+    value: u16,
+) -> Result<(), CpuError> {
     match target {
         InstructionTarget::BC => reg.set_reg16(RegisterPair::BC, value),
         InstructionTarget::DE => reg.set_reg16(RegisterPair::DE, value),
         InstructionTarget::HL => reg.set_reg16(RegisterPair::HL, value),
         InstructionTarget::SP => reg.sp = value,
-        InstructionTarget::N16(addr) => {
-            mem.write((*addr) + 1, (value >> 8) as u8);
-            mem.write(*addr, value as u8);
+        InstructionTarget::N16 => {
+            mem.write(reg.pc.wrapping_sub(2), value as u8);
+            mem.write(reg.pc.wrapping_sub(1), (value >> 8) as u8);
         }
         InstructionTarget::Ref(inner) => {
-            let addr = get_x16(&inner, reg, mem);
-            mem.write((addr) + 1, (value >> 8) as u8);
+            // LD (a16), SP stores the low byte first
+            let addr = get_x16(inner, reg, mem)?;
             mem.write(addr, value as u8);
+            mem.write(addr.wrapping_add(1), (value >> 8) as u8);
+        }
+        _ => {
+            return Err(CpuError::InvalidOperand(format!(
+                "{:?} cannot be written as a 16-bit value",
+                target
+            )))
         }
-        _ => panic!("Unsupported target for 16-bit value write"),
     }
+
+    Ok(())
 }
 
 #[cfg(test)]
-#[allow(non_snake_case)]
+#[allow(non_snake_case, clippy::bool_assert_comparison)]
 mod tests;
+
+#[cfg(test)]
+mod single_step;
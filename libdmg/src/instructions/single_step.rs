@@ -0,0 +1,285 @@
+// Conformance tests against the SingleStepTests SM83 vectors, one JSON file per opcode each holding
+// a list of cases with the state before and after a single instruction and every bus cycle it made.
+// Clone https://github.com/SingleStepTests/sm83 into
+// libdmg/tests/sm83 or point SM83_TESTS at its v1 directory to run them
+use std::cell::RefCell;
+use std::fs;
+
+use serde::Deserialize;
+use serde_json::Value;
+
+use super::*;
+use crate::cpu::test_roms::{data_dir, found};
+use crate::interrupts::INTERRUPT_ENABLE;
+use crate::memory::M_CYCLE;
+
+const DATA_DIR_VAR: &str = "SM83_TESTS";
+
+#[derive(Deserialize)]
+struct TestCase {
+    name: String,
+    initial: CpuState,
+    #[serde(rename = "final")]
+    expected: CpuState,
+    cycles: Vec<Value>,
+}
+
+#[derive(Deserialize)]
+struct CpuState {
+    a: u8,
+    b: u8,
+    c: u8,
+    d: u8,
+    e: u8,
+    f: u8,
+    h: u8,
+    l: u8,
+    pc: u16,
+    sp: u16,
+    #[serde(default)]
+    ime: u8,
+    #[serde(default)]
+    ie: Option<u8>,
+    ram: Vec<(Address, u8)>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Access {
+    Read(Address, u8),
+    Write(Address, u8),
+}
+
+// 64KiB of plain RAM with no registers or mapping, recording the access made in each M-cycle
+#[derive(Clone, Debug)]
+pub struct TestBus {
+    memory: Vec<u8>,
+    cycles: RefCell<Vec<Option<Access>>>,
+}
+
+impl Default for TestBus {
+    fn default() -> TestBus {
+        TestBus {
+            memory: vec![0; 0x10000],
+            cycles: RefCell::new(Vec::new()),
+        }
+    }
+}
+
+// Two buses are the same if they hold the same memory, no matter which cycles got them there
+impl PartialEq for TestBus {
+    fn eq(&self, other: &TestBus) -> bool {
+        self.memory == other.memory
+    }
+}
+
+impl TestBus {
+    // Only the first access in a cycle is a real bus access, anything else is the CPU peeking at
+    // the interrupt lines
+    fn record(&self, access: Access) {
+        if let Some(cycle @ None) = self.cycles.borrow_mut().last_mut() {
+            *cycle = Some(access);
+        }
+    }
+}
+
+impl Bus for TestBus {
+    fn read(&self, addr: Address) -> u8 {
+        let value = self.memory[addr as usize];
+        self.record(Access::Read(addr, value));
+        value
+    }
+
+    fn write(&mut self, addr: Address, data: u8) {
+        self.memory[addr as usize] = data;
+        self.record(Access::Write(addr, data));
+    }
+
+    fn tick(&mut self, cycles: u8) {
+        for _ in 0..cycles / M_CYCLE {
+            self.cycles.get_mut().push(None);
+        }
+    }
+}
+
+// Cycles are recorded as [address, value, "r-m"] for reads, [address, value, "-wm"] for writes
+// and null or a "---" entry for internal cycles
+fn parse_cycle(cycle: &Value) -> Option<Access> {
+    let addr = cycle.get(0)?.as_u64()? as Address;
+    let value = cycle.get(1)?.as_u64()? as u8;
+    match cycle.get(2)?.as_str()?.as_bytes() {
+        [b'r', ..] => Some(Access::Read(addr, value)),
+        [_, b'w', ..] => Some(Access::Write(addr, value)),
+        _ => None,
+    }
+}
+
+fn run_case(case: &TestCase) -> Result<(), String> {
+    let mut bus = TestBus::default();
+    let mut reg = Registers::default();
+
+    let initial = &case.initial;
+    for &(addr, value) in &initial.ram {
+        bus.memory[addr as usize] = value;
+    }
+    if let Some(ie) = initial.ie {
+        bus.memory[INTERRUPT_ENABLE as usize] = ie;
+    }
+    reg.set_reg16(RegisterPair::AF, u16::from_be_bytes([initial.a, initial.f]));
+    reg.set_reg8(Register::B, initial.b);
+    reg.set_reg8(Register::C, initial.c);
+    reg.set_reg8(Register::D, initial.d);
+    reg.set_reg8(Register::E, initial.e);
+    reg.set_reg8(Register::H, initial.h);
+    reg.set_reg8(Register::L, initial.l);
+    reg.sp = initial.sp;
+    reg.ime = initial.ime != 0;
+
+    // The vectors start with the opcode already fetched and end once the next one has been, so PC
+    // is one past the start of each instruction
+    reg.pc = initial.pc.wrapping_sub(1);
+
+    let mut timed = TimedBus::new(&mut bus, true);
+    let code = timed.read(reg.pc);
+    let cycles = execute_instruction(code as u16, &mut reg, &mut timed)
+        .map_err(|error| format!("{}: {}", case.name, error))?;
+    timed.finish(cycles);
+
+    let expected = &case.expected;
+    let actual = [
+        ("A", reg.get_reg8(Register::A) as u16, expected.a as u16),
+        ("F", reg.get_flags() as u16, expected.f as u16),
+        ("B", reg.get_reg8(Register::B) as u16, expected.b as u16),
+        ("C", reg.get_reg8(Register::C) as u16, expected.c as u16),
+        ("D", reg.get_reg8(Register::D) as u16, expected.d as u16),
+        ("E", reg.get_reg8(Register::E) as u16, expected.e as u16),
+        ("H", reg.get_reg8(Register::H) as u16, expected.h as u16),
+        ("L", reg.get_reg8(Register::L) as u16, expected.l as u16),
+        ("SP", reg.sp, expected.sp),
+        ("PC", reg.pc.wrapping_add(1), expected.pc),
+        // EI has taken effect by the time the next instruction has been fetched
+        (
+            "IME",
+            (reg.ime || reg.ime_scheduled) as u16,
+            expected.ime as u16,
+        ),
+    ];
+    for (name, value, expected) in actual {
+        if value != expected {
+            return Err(format!(
+                "{}: {} is {:#06X}, expected {:#06X}",
+                case.name, name, value, expected
+            ));
+        }
+    }
+
+    for &(addr, value) in &expected.ram {
+        let actual = bus.memory[addr as usize];
+        if actual != value {
+            return Err(format!(
+                "{}: ({:#06X}) is {:#04X}, expected {:#04X}",
+                case.name, addr, actual, value
+            ));
+        }
+    }
+
+    // Our first cycle fetches this opcode while theirs is the read of the next one, so compare the
+    // cycles in between
+    let actual: Vec<_> = bus.cycles.into_inner().into_iter().skip(1).collect();
+    let mut expected: Vec<_> = case.cycles.iter().map(parse_cycle).collect();
+    expected.pop();
+    if actual != expected {
+        return Err(format!(
+            "{}: bus cycles were {:?}, expected {:?}",
+            case.name, actual, expected
+        ));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_single_step() {
+    let dir = data_dir(DATA_DIR_VAR, "sm83/v1");
+    if !found("SM83 single step tests", &dir, DATA_DIR_VAR) {
+        return;
+    }
+
+    let files = (0x00..=0xFF)
+        .map(|code| format!("{:02x}.json", code))
+        .chain((0x00..=0xFF).map(|code| format!("cb {:02x}.json", code)));
+
+    let mut failed_opcodes = Vec::new();
+    for file in files {
+        // There are no vectors for illegal opcodes or the 0xCB prefix on its own
+        let json = match fs::read_to_string(dir.join(&file)) {
+            Ok(json) => json,
+            Err(_) => continue,
+        };
+        let cases: Vec<TestCase> = serde_json::from_str(&json)
+            .unwrap_or_else(|error| panic!("Failed to parse {}: {}", file, error));
+
+        let results: Vec<_> = cases.iter().map(run_case).collect();
+        let failures: Vec<_> = results.iter().filter_map(|r| r.as_ref().err()).collect();
+        let opcode = file.trim_end_matches(".json");
+
+        println!(
+            "{:<6} {:>6} passed {:>6} failed",
+            opcode,
+            results.len() - failures.len(),
+            failures.len()
+        );
+        if let Some(failure) = failures.first() {
+            println!("    {}", failure);
+            failed_opcodes.push(opcode.to_string());
+        }
+    }
+
+    assert!(
+        failed_opcodes.is_empty(),
+        "Failing opcodes: {}",
+        failed_opcodes.join(", ")
+    );
+}
+
+#[test]
+fn test_single_step_case() {
+    // LD (HL), 0x42 followed by a CALL 0xC000, in the same form as the vectors
+    let json = r#"[
+        {
+            "name": "36 0000",
+            "initial": {
+                "a": 1, "b": 2, "c": 3, "d": 4, "e": 5, "f": 176, "h": 208, "l": 0,
+                "pc": 49153, "sp": 65534, "ime": 0, "ie": 0,
+                "ram": [[49152, 54], [49153, 66], [49154, 205]]
+            },
+            "final": {
+                "a": 1, "b": 2, "c": 3, "d": 4, "e": 5, "f": 176, "h": 208, "l": 0,
+                "pc": 49155, "sp": 65534, "ime": 0,
+                "ram": [[53248, 66]]
+            },
+            "cycles": [[49153, 66, "r-m"], [53248, 66, "-wm"], [49154, 205, "r-m"]]
+        },
+        {
+            "name": "cd 0000",
+            "initial": {
+                "a": 0, "b": 0, "c": 0, "d": 0, "e": 0, "f": 0, "h": 0, "l": 0,
+                "pc": 49153, "sp": 57344, "ime": 1,
+                "ram": [[49152, 205], [49153, 0], [49154, 192]]
+            },
+            "final": {
+                "a": 0, "b": 0, "c": 0, "d": 0, "e": 0, "f": 0, "h": 0, "l": 0,
+                "pc": 49153, "sp": 57342, "ime": 1,
+                "ram": [[57342, 3], [57343, 192]]
+            },
+            "cycles": [
+                [49153, 0, "r-m"], [49154, 192, "r-m"], null,
+                [57343, 192, "-wm"], [57342, 3, "-wm"], [49152, 205, "r-m"]
+            ]
+        }
+    ]"#;
+    let cases: Vec<TestCase> = serde_json::from_str(json).unwrap();
+
+    for case in &cases {
+        assert_eq!(Ok(()), run_case(case));
+    }
+}
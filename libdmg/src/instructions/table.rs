@@ -0,0 +1,6183 @@
+use super::*;
+
+// Unprefixed instructions indexed by opcode, illegal opcodes and the 0xCB prefix are None
+pub static INSTRUCTIONS: [Option<Instruction>; 256] = [
+    // 0x00
+    Some(Instruction {
+        mnemonic: "NOP",
+        source: None,
+        target: None,
+        operation: Operation::NOP,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x01
+    Some(Instruction {
+        mnemonic: "LD BC, u16",
+        source: Some(InstructionTarget::N16),
+        target: Some(InstructionTarget::BC),
+        operation: Operation::LD,
+        cycles: 12,
+        length: 3,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x02
+    Some(Instruction {
+        mnemonic: "LD (BC), A",
+        source: Some(InstructionTarget::A),
+        target: Some(InstructionTarget::Ref(&InstructionTarget::BC)),
+        operation: Operation::LD,
+        cycles: 8,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x03
+    Some(Instruction {
+        mnemonic: "INC BC",
+        source: None,
+        target: Some(InstructionTarget::BC),
+        operation: Operation::INC,
+        cycles: 8,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x04
+    Some(Instruction {
+        mnemonic: "INC B",
+        source: None,
+        target: Some(InstructionTarget::B),
+        operation: Operation::INC,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Unmodified,
+        },
+    }),
+    // 0x05
+    Some(Instruction {
+        mnemonic: "DEC B",
+        source: None,
+        target: Some(InstructionTarget::B),
+        operation: Operation::DEC,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Set,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Unmodified,
+        },
+    }),
+    // 0x06
+    Some(Instruction {
+        mnemonic: "LD B, u8",
+        source: Some(InstructionTarget::N8),
+        target: Some(InstructionTarget::B),
+        operation: Operation::LD,
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x07
+    Some(Instruction {
+        mnemonic: "RLCA",
+        source: None,
+        target: Some(InstructionTarget::A),
+        operation: Operation::RXC(Direction::Left),
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Unset,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Dependent,
+        },
+    }),
+    // 0x08
+    Some(Instruction {
+        mnemonic: "LD (u16), SP",
+        source: Some(InstructionTarget::SP),
+        target: Some(InstructionTarget::Ref(&InstructionTarget::N16)),
+        operation: Operation::LD,
+        cycles: 20,
+        length: 3,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x09
+    Some(Instruction {
+        mnemonic: "ADD HL, BC",
+        source: Some(InstructionTarget::BC),
+        target: Some(InstructionTarget::HL),
+        operation: Operation::ADD,
+        cycles: 8,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Unmodified,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Dependent,
+        },
+    }),
+    // 0x0A
+    Some(Instruction {
+        mnemonic: "LD A, (BC)",
+        source: Some(InstructionTarget::Ref(&InstructionTarget::BC)),
+        target: Some(InstructionTarget::A),
+        operation: Operation::LD,
+        cycles: 8,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x0B
+    Some(Instruction {
+        mnemonic: "DEC BC",
+        source: None,
+        target: Some(InstructionTarget::BC),
+        operation: Operation::DEC,
+        cycles: 8,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x0C
+    Some(Instruction {
+        mnemonic: "INC C",
+        source: None,
+        target: Some(InstructionTarget::C),
+        operation: Operation::INC,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Unmodified,
+        },
+    }),
+    // 0x0D
+    Some(Instruction {
+        mnemonic: "DEC C",
+        source: None,
+        target: Some(InstructionTarget::C),
+        operation: Operation::DEC,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Set,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Unmodified,
+        },
+    }),
+    // 0x0E
+    Some(Instruction {
+        mnemonic: "LD C, u8",
+        source: Some(InstructionTarget::N8),
+        target: Some(InstructionTarget::C),
+        operation: Operation::LD,
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x0F
+    Some(Instruction {
+        mnemonic: "RRCA",
+        source: None,
+        target: Some(InstructionTarget::A),
+        operation: Operation::RXC(Direction::Right),
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Unset,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Dependent,
+        },
+    }),
+    // 0x10
+    Some(Instruction {
+        mnemonic: "STOP",
+        source: None,
+        target: None,
+        operation: Operation::STOP,
+        cycles: 4,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x11
+    Some(Instruction {
+        mnemonic: "LD DE, u16",
+        source: Some(InstructionTarget::N16),
+        target: Some(InstructionTarget::DE),
+        operation: Operation::LD,
+        cycles: 12,
+        length: 3,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x12
+    Some(Instruction {
+        mnemonic: "LD (DE), A",
+        source: Some(InstructionTarget::A),
+        target: Some(InstructionTarget::Ref(&InstructionTarget::DE)),
+        operation: Operation::LD,
+        cycles: 8,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x13
+    Some(Instruction {
+        mnemonic: "INC DE",
+        source: None,
+        target: Some(InstructionTarget::DE),
+        operation: Operation::INC,
+        cycles: 8,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x14
+    Some(Instruction {
+        mnemonic: "INC D",
+        source: None,
+        target: Some(InstructionTarget::D),
+        operation: Operation::INC,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Unmodified,
+        },
+    }),
+    // 0x15
+    Some(Instruction {
+        mnemonic: "DEC D",
+        source: None,
+        target: Some(InstructionTarget::D),
+        operation: Operation::DEC,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Set,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Unmodified,
+        },
+    }),
+    // 0x16
+    Some(Instruction {
+        mnemonic: "LD D, u8",
+        source: Some(InstructionTarget::N8),
+        target: Some(InstructionTarget::D),
+        operation: Operation::LD,
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x17
+    Some(Instruction {
+        mnemonic: "RLA",
+        source: None,
+        target: Some(InstructionTarget::A),
+        operation: Operation::RX(Direction::Left),
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Unset,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Dependent,
+        },
+    }),
+    // 0x18
+    Some(Instruction {
+        mnemonic: "JR i8",
+        source: Some(InstructionTarget::N8),
+        target: None,
+        operation: Operation::JR(Condition::Always),
+        cycles: 12,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x19
+    Some(Instruction {
+        mnemonic: "ADD HL, DE",
+        source: Some(InstructionTarget::DE),
+        target: Some(InstructionTarget::HL),
+        operation: Operation::ADD,
+        cycles: 8,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Unmodified,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Dependent,
+        },
+    }),
+    // 0x1A
+    Some(Instruction {
+        mnemonic: "LD A, (DE)",
+        source: Some(InstructionTarget::Ref(&InstructionTarget::DE)),
+        target: Some(InstructionTarget::A),
+        operation: Operation::LD,
+        cycles: 8,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x1B
+    Some(Instruction {
+        mnemonic: "DEC DE",
+        source: None,
+        target: Some(InstructionTarget::DE),
+        operation: Operation::DEC,
+        cycles: 8,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x1C
+    Some(Instruction {
+        mnemonic: "INC E",
+        source: None,
+        target: Some(InstructionTarget::E),
+        operation: Operation::INC,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Unmodified,
+        },
+    }),
+    // 0x1D
+    Some(Instruction {
+        mnemonic: "DEC E",
+        source: None,
+        target: Some(InstructionTarget::E),
+        operation: Operation::DEC,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Set,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Unmodified,
+        },
+    }),
+    // 0x1E
+    Some(Instruction {
+        mnemonic: "LD E, u8",
+        source: Some(InstructionTarget::N8),
+        target: Some(InstructionTarget::E),
+        operation: Operation::LD,
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x1F
+    Some(Instruction {
+        mnemonic: "RRA",
+        source: None,
+        target: Some(InstructionTarget::A),
+        operation: Operation::RX(Direction::Right),
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Unset,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Dependent,
+        },
+    }),
+    // 0x20
+    Some(Instruction {
+        mnemonic: "JR NZ, i8",
+        source: Some(InstructionTarget::N8),
+        target: None,
+        operation: Operation::JR(Condition::NotZero),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x21
+    Some(Instruction {
+        mnemonic: "LD HL, u16",
+        source: Some(InstructionTarget::N16),
+        target: Some(InstructionTarget::HL),
+        operation: Operation::LD,
+        cycles: 12,
+        length: 3,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x22
+    Some(Instruction {
+        mnemonic: "LD (HL+), A",
+        source: Some(InstructionTarget::A),
+        target: Some(InstructionTarget::Ref(&InstructionTarget::HL)),
+        operation: Operation::LDI,
+        cycles: 8,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x23
+    Some(Instruction {
+        mnemonic: "INC HL",
+        source: None,
+        target: Some(InstructionTarget::HL),
+        operation: Operation::INC,
+        cycles: 8,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x24
+    Some(Instruction {
+        mnemonic: "INC H",
+        source: None,
+        target: Some(InstructionTarget::H),
+        operation: Operation::INC,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Unmodified,
+        },
+    }),
+    // 0x25
+    Some(Instruction {
+        mnemonic: "DEC H",
+        source: None,
+        target: Some(InstructionTarget::H),
+        operation: Operation::DEC,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Set,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Unmodified,
+        },
+    }),
+    // 0x26
+    Some(Instruction {
+        mnemonic: "LD H, u8",
+        source: Some(InstructionTarget::N8),
+        target: Some(InstructionTarget::H),
+        operation: Operation::LD,
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x27
+    Some(Instruction {
+        mnemonic: "DAA",
+        source: None,
+        target: Some(InstructionTarget::A),
+        operation: Operation::DAA,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unmodified,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Dependent,
+        },
+    }),
+    // 0x28
+    Some(Instruction {
+        mnemonic: "JR Z, i8",
+        source: Some(InstructionTarget::N8),
+        target: None,
+        operation: Operation::JR(Condition::Zero),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x29
+    Some(Instruction {
+        mnemonic: "ADD HL, HL",
+        source: Some(InstructionTarget::HL),
+        target: Some(InstructionTarget::HL),
+        operation: Operation::ADD,
+        cycles: 8,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Unmodified,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Dependent,
+        },
+    }),
+    // 0x2A
+    Some(Instruction {
+        mnemonic: "LD A, (HL+)",
+        source: Some(InstructionTarget::Ref(&InstructionTarget::HL)),
+        target: Some(InstructionTarget::A),
+        operation: Operation::LDI,
+        cycles: 8,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x2B
+    Some(Instruction {
+        mnemonic: "DEC HL",
+        source: None,
+        target: Some(InstructionTarget::HL),
+        operation: Operation::DEC,
+        cycles: 8,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x2C
+    Some(Instruction {
+        mnemonic: "INC L",
+        source: None,
+        target: Some(InstructionTarget::L),
+        operation: Operation::INC,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Unmodified,
+        },
+    }),
+    // 0x2D
+    Some(Instruction {
+        mnemonic: "DEC L",
+        source: None,
+        target: Some(InstructionTarget::L),
+        operation: Operation::DEC,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Set,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Unmodified,
+        },
+    }),
+    // 0x2E
+    Some(Instruction {
+        mnemonic: "LD L, u8",
+        source: Some(InstructionTarget::N8),
+        target: Some(InstructionTarget::L),
+        operation: Operation::LD,
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x2F
+    Some(Instruction {
+        mnemonic: "CPL",
+        source: None,
+        target: Some(InstructionTarget::A),
+        operation: Operation::CPL,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Unmodified,
+            subtract: FlagOperation::Set,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    }),
+    // 0x30
+    Some(Instruction {
+        mnemonic: "JR NC, i8",
+        source: Some(InstructionTarget::N8),
+        target: None,
+        operation: Operation::JR(Condition::NotCarry),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x31
+    Some(Instruction {
+        mnemonic: "LD SP, u16",
+        source: Some(InstructionTarget::N16),
+        target: Some(InstructionTarget::SP),
+        operation: Operation::LD,
+        cycles: 12,
+        length: 3,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x32
+    Some(Instruction {
+        mnemonic: "LD (HL-), A",
+        source: Some(InstructionTarget::A),
+        target: Some(InstructionTarget::Ref(&InstructionTarget::HL)),
+        operation: Operation::LDD,
+        cycles: 8,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x33
+    Some(Instruction {
+        mnemonic: "INC SP",
+        source: None,
+        target: Some(InstructionTarget::SP),
+        operation: Operation::INC,
+        cycles: 8,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x34
+    Some(Instruction {
+        mnemonic: "INC (HL)",
+        source: None,
+        target: Some(InstructionTarget::Ref(&InstructionTarget::HL)),
+        operation: Operation::INC,
+        cycles: 12,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Unmodified,
+        },
+    }),
+    // 0x35
+    Some(Instruction {
+        mnemonic: "DEC (HL)",
+        source: None,
+        target: Some(InstructionTarget::Ref(&InstructionTarget::HL)),
+        operation: Operation::DEC,
+        cycles: 12,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Set,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Unmodified,
+        },
+    }),
+    // 0x36
+    Some(Instruction {
+        mnemonic: "LD (HL), u8",
+        source: Some(InstructionTarget::N8),
+        target: Some(InstructionTarget::Ref(&InstructionTarget::HL)),
+        operation: Operation::LD,
+        cycles: 12,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x37
+    Some(Instruction {
+        mnemonic: "SCF",
+        source: None,
+        target: None,
+        operation: Operation::SCF,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Unmodified,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Set,
+        },
+    }),
+    // 0x38
+    Some(Instruction {
+        mnemonic: "JR C, i8",
+        source: Some(InstructionTarget::N8),
+        target: None,
+        operation: Operation::JR(Condition::Carry),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x39
+    Some(Instruction {
+        mnemonic: "ADD HL, SP",
+        source: Some(InstructionTarget::SP),
+        target: Some(InstructionTarget::HL),
+        operation: Operation::ADD,
+        cycles: 8,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Unmodified,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Dependent,
+        },
+    }),
+    // 0x3A
+    Some(Instruction {
+        mnemonic: "LD A, (HL-)",
+        source: Some(InstructionTarget::Ref(&InstructionTarget::HL)),
+        target: Some(InstructionTarget::A),
+        operation: Operation::LDD,
+        cycles: 8,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x3B
+    Some(Instruction {
+        mnemonic: "DEC SP",
+        source: None,
+        target: Some(InstructionTarget::SP),
+        operation: Operation::DEC,
+        cycles: 8,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x3C
+    Some(Instruction {
+        mnemonic: "INC A",
+        source: None,
+        target: Some(InstructionTarget::A),
+        operation: Operation::INC,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Unmodified,
+        },
+    }),
+    // 0x3D
+    Some(Instruction {
+        mnemonic: "DEC A",
+        source: None,
+        target: Some(InstructionTarget::A),
+        operation: Operation::DEC,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Set,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Unmodified,
+        },
+    }),
+    // 0x3E
+    Some(Instruction {
+        mnemonic: "LD A, u8",
+        source: Some(InstructionTarget::N8),
+        target: Some(InstructionTarget::A),
+        operation: Operation::LD,
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x3F
+    Some(Instruction {
+        mnemonic: "CCF",
+        source: None,
+        target: None,
+        operation: Operation::CCF,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Unmodified,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Dependent,
+        },
+    }),
+    // 0x40
+    Some(Instruction {
+        mnemonic: "LD B, B",
+        source: Some(InstructionTarget::B),
+        target: Some(InstructionTarget::B),
+        operation: Operation::LD,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x41
+    Some(Instruction {
+        mnemonic: "LD B, C",
+        source: Some(InstructionTarget::C),
+        target: Some(InstructionTarget::B),
+        operation: Operation::LD,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x42
+    Some(Instruction {
+        mnemonic: "LD B, D",
+        source: Some(InstructionTarget::D),
+        target: Some(InstructionTarget::B),
+        operation: Operation::LD,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x43
+    Some(Instruction {
+        mnemonic: "LD B, E",
+        source: Some(InstructionTarget::E),
+        target: Some(InstructionTarget::B),
+        operation: Operation::LD,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x44
+    Some(Instruction {
+        mnemonic: "LD B, H",
+        source: Some(InstructionTarget::H),
+        target: Some(InstructionTarget::B),
+        operation: Operation::LD,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x45
+    Some(Instruction {
+        mnemonic: "LD B, L",
+        source: Some(InstructionTarget::L),
+        target: Some(InstructionTarget::B),
+        operation: Operation::LD,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x46
+    Some(Instruction {
+        mnemonic: "LD B, (HL)",
+        source: Some(InstructionTarget::Ref(&InstructionTarget::HL)),
+        target: Some(InstructionTarget::B),
+        operation: Operation::LD,
+        cycles: 8,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x47
+    Some(Instruction {
+        mnemonic: "LD B, A",
+        source: Some(InstructionTarget::A),
+        target: Some(InstructionTarget::B),
+        operation: Operation::LD,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x48
+    Some(Instruction {
+        mnemonic: "LD C, B",
+        source: Some(InstructionTarget::B),
+        target: Some(InstructionTarget::C),
+        operation: Operation::LD,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x49
+    Some(Instruction {
+        mnemonic: "LD C, C",
+        source: Some(InstructionTarget::C),
+        target: Some(InstructionTarget::C),
+        operation: Operation::LD,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x4A
+    Some(Instruction {
+        mnemonic: "LD C, D",
+        source: Some(InstructionTarget::D),
+        target: Some(InstructionTarget::C),
+        operation: Operation::LD,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x4B
+    Some(Instruction {
+        mnemonic: "LD C, E",
+        source: Some(InstructionTarget::E),
+        target: Some(InstructionTarget::C),
+        operation: Operation::LD,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x4C
+    Some(Instruction {
+        mnemonic: "LD C, H",
+        source: Some(InstructionTarget::H),
+        target: Some(InstructionTarget::C),
+        operation: Operation::LD,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x4D
+    Some(Instruction {
+        mnemonic: "LD C, L",
+        source: Some(InstructionTarget::L),
+        target: Some(InstructionTarget::C),
+        operation: Operation::LD,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x4E
+    Some(Instruction {
+        mnemonic: "LD C, (HL)",
+        source: Some(InstructionTarget::Ref(&InstructionTarget::HL)),
+        target: Some(InstructionTarget::C),
+        operation: Operation::LD,
+        cycles: 8,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x4F
+    Some(Instruction {
+        mnemonic: "LD C, A",
+        source: Some(InstructionTarget::A),
+        target: Some(InstructionTarget::C),
+        operation: Operation::LD,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x50
+    Some(Instruction {
+        mnemonic: "LD D, B",
+        source: Some(InstructionTarget::B),
+        target: Some(InstructionTarget::D),
+        operation: Operation::LD,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x51
+    Some(Instruction {
+        mnemonic: "LD D, C",
+        source: Some(InstructionTarget::C),
+        target: Some(InstructionTarget::D),
+        operation: Operation::LD,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x52
+    Some(Instruction {
+        mnemonic: "LD D, D",
+        source: Some(InstructionTarget::D),
+        target: Some(InstructionTarget::D),
+        operation: Operation::LD,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x53
+    Some(Instruction {
+        mnemonic: "LD D, E",
+        source: Some(InstructionTarget::E),
+        target: Some(InstructionTarget::D),
+        operation: Operation::LD,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x54
+    Some(Instruction {
+        mnemonic: "LD D, H",
+        source: Some(InstructionTarget::H),
+        target: Some(InstructionTarget::D),
+        operation: Operation::LD,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x55
+    Some(Instruction {
+        mnemonic: "LD D, L",
+        source: Some(InstructionTarget::L),
+        target: Some(InstructionTarget::D),
+        operation: Operation::LD,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x56
+    Some(Instruction {
+        mnemonic: "LD D, (HL)",
+        source: Some(InstructionTarget::Ref(&InstructionTarget::HL)),
+        target: Some(InstructionTarget::D),
+        operation: Operation::LD,
+        cycles: 8,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x57
+    Some(Instruction {
+        mnemonic: "LD D, A",
+        source: Some(InstructionTarget::A),
+        target: Some(InstructionTarget::D),
+        operation: Operation::LD,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x58
+    Some(Instruction {
+        mnemonic: "LD E, B",
+        source: Some(InstructionTarget::B),
+        target: Some(InstructionTarget::E),
+        operation: Operation::LD,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x59
+    Some(Instruction {
+        mnemonic: "LD E, C",
+        source: Some(InstructionTarget::C),
+        target: Some(InstructionTarget::E),
+        operation: Operation::LD,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x5A
+    Some(Instruction {
+        mnemonic: "LD E, D",
+        source: Some(InstructionTarget::D),
+        target: Some(InstructionTarget::E),
+        operation: Operation::LD,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x5B
+    Some(Instruction {
+        mnemonic: "LD E, E",
+        source: Some(InstructionTarget::E),
+        target: Some(InstructionTarget::E),
+        operation: Operation::LD,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x5C
+    Some(Instruction {
+        mnemonic: "LD E, H",
+        source: Some(InstructionTarget::H),
+        target: Some(InstructionTarget::E),
+        operation: Operation::LD,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x5D
+    Some(Instruction {
+        mnemonic: "LD E, L",
+        source: Some(InstructionTarget::L),
+        target: Some(InstructionTarget::E),
+        operation: Operation::LD,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x5E
+    Some(Instruction {
+        mnemonic: "LD E, (HL)",
+        source: Some(InstructionTarget::Ref(&InstructionTarget::HL)),
+        target: Some(InstructionTarget::E),
+        operation: Operation::LD,
+        cycles: 8,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x5F
+    Some(Instruction {
+        mnemonic: "LD E, A",
+        source: Some(InstructionTarget::A),
+        target: Some(InstructionTarget::E),
+        operation: Operation::LD,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x60
+    Some(Instruction {
+        mnemonic: "LD H, B",
+        source: Some(InstructionTarget::B),
+        target: Some(InstructionTarget::H),
+        operation: Operation::LD,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x61
+    Some(Instruction {
+        mnemonic: "LD H, C",
+        source: Some(InstructionTarget::C),
+        target: Some(InstructionTarget::H),
+        operation: Operation::LD,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x62
+    Some(Instruction {
+        mnemonic: "LD H, D",
+        source: Some(InstructionTarget::D),
+        target: Some(InstructionTarget::H),
+        operation: Operation::LD,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x63
+    Some(Instruction {
+        mnemonic: "LD H, E",
+        source: Some(InstructionTarget::E),
+        target: Some(InstructionTarget::H),
+        operation: Operation::LD,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x64
+    Some(Instruction {
+        mnemonic: "LD H, H",
+        source: Some(InstructionTarget::H),
+        target: Some(InstructionTarget::H),
+        operation: Operation::LD,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x65
+    Some(Instruction {
+        mnemonic: "LD H, L",
+        source: Some(InstructionTarget::L),
+        target: Some(InstructionTarget::H),
+        operation: Operation::LD,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x66
+    Some(Instruction {
+        mnemonic: "LD H, (HL)",
+        source: Some(InstructionTarget::Ref(&InstructionTarget::HL)),
+        target: Some(InstructionTarget::H),
+        operation: Operation::LD,
+        cycles: 8,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x67
+    Some(Instruction {
+        mnemonic: "LD H, A",
+        source: Some(InstructionTarget::A),
+        target: Some(InstructionTarget::H),
+        operation: Operation::LD,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x68
+    Some(Instruction {
+        mnemonic: "LD L, B",
+        source: Some(InstructionTarget::B),
+        target: Some(InstructionTarget::L),
+        operation: Operation::LD,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x69
+    Some(Instruction {
+        mnemonic: "LD L, C",
+        source: Some(InstructionTarget::C),
+        target: Some(InstructionTarget::L),
+        operation: Operation::LD,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x6A
+    Some(Instruction {
+        mnemonic: "LD L, D",
+        source: Some(InstructionTarget::D),
+        target: Some(InstructionTarget::L),
+        operation: Operation::LD,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x6B
+    Some(Instruction {
+        mnemonic: "LD L, E",
+        source: Some(InstructionTarget::E),
+        target: Some(InstructionTarget::L),
+        operation: Operation::LD,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x6C
+    Some(Instruction {
+        mnemonic: "LD L, H",
+        source: Some(InstructionTarget::H),
+        target: Some(InstructionTarget::L),
+        operation: Operation::LD,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x6D
+    Some(Instruction {
+        mnemonic: "LD L, L",
+        source: Some(InstructionTarget::L),
+        target: Some(InstructionTarget::L),
+        operation: Operation::LD,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x6E
+    Some(Instruction {
+        mnemonic: "LD L, (HL)",
+        source: Some(InstructionTarget::Ref(&InstructionTarget::HL)),
+        target: Some(InstructionTarget::L),
+        operation: Operation::LD,
+        cycles: 8,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x6F
+    Some(Instruction {
+        mnemonic: "LD L, A",
+        source: Some(InstructionTarget::A),
+        target: Some(InstructionTarget::L),
+        operation: Operation::LD,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x70
+    Some(Instruction {
+        mnemonic: "LD (HL), B",
+        source: Some(InstructionTarget::B),
+        target: Some(InstructionTarget::Ref(&InstructionTarget::HL)),
+        operation: Operation::LD,
+        cycles: 8,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x71
+    Some(Instruction {
+        mnemonic: "LD (HL), C",
+        source: Some(InstructionTarget::C),
+        target: Some(InstructionTarget::Ref(&InstructionTarget::HL)),
+        operation: Operation::LD,
+        cycles: 8,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x72
+    Some(Instruction {
+        mnemonic: "LD (HL), D",
+        source: Some(InstructionTarget::D),
+        target: Some(InstructionTarget::Ref(&InstructionTarget::HL)),
+        operation: Operation::LD,
+        cycles: 8,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x73
+    Some(Instruction {
+        mnemonic: "LD (HL), E",
+        source: Some(InstructionTarget::E),
+        target: Some(InstructionTarget::Ref(&InstructionTarget::HL)),
+        operation: Operation::LD,
+        cycles: 8,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x74
+    Some(Instruction {
+        mnemonic: "LD (HL), H",
+        source: Some(InstructionTarget::H),
+        target: Some(InstructionTarget::Ref(&InstructionTarget::HL)),
+        operation: Operation::LD,
+        cycles: 8,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x75
+    Some(Instruction {
+        mnemonic: "LD (HL), L",
+        source: Some(InstructionTarget::L),
+        target: Some(InstructionTarget::Ref(&InstructionTarget::HL)),
+        operation: Operation::LD,
+        cycles: 8,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x76
+    Some(Instruction {
+        mnemonic: "HALT",
+        source: None,
+        target: None,
+        operation: Operation::HALT,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x77
+    Some(Instruction {
+        mnemonic: "LD (HL), A",
+        source: Some(InstructionTarget::A),
+        target: Some(InstructionTarget::Ref(&InstructionTarget::HL)),
+        operation: Operation::LD,
+        cycles: 8,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x78
+    Some(Instruction {
+        mnemonic: "LD A, B",
+        source: Some(InstructionTarget::B),
+        target: Some(InstructionTarget::A),
+        operation: Operation::LD,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x79
+    Some(Instruction {
+        mnemonic: "LD A, C",
+        source: Some(InstructionTarget::C),
+        target: Some(InstructionTarget::A),
+        operation: Operation::LD,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x7A
+    Some(Instruction {
+        mnemonic: "LD A, D",
+        source: Some(InstructionTarget::D),
+        target: Some(InstructionTarget::A),
+        operation: Operation::LD,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x7B
+    Some(Instruction {
+        mnemonic: "LD A, E",
+        source: Some(InstructionTarget::E),
+        target: Some(InstructionTarget::A),
+        operation: Operation::LD,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x7C
+    Some(Instruction {
+        mnemonic: "LD A, H",
+        source: Some(InstructionTarget::H),
+        target: Some(InstructionTarget::A),
+        operation: Operation::LD,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x7D
+    Some(Instruction {
+        mnemonic: "LD A, L",
+        source: Some(InstructionTarget::L),
+        target: Some(InstructionTarget::A),
+        operation: Operation::LD,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x7E
+    Some(Instruction {
+        mnemonic: "LD A, (HL)",
+        source: Some(InstructionTarget::Ref(&InstructionTarget::HL)),
+        target: Some(InstructionTarget::A),
+        operation: Operation::LD,
+        cycles: 8,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x7F
+    Some(Instruction {
+        mnemonic: "LD A, A",
+        source: Some(InstructionTarget::A),
+        target: Some(InstructionTarget::A),
+        operation: Operation::LD,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0x80
+    Some(Instruction {
+        mnemonic: "ADD A, B",
+        source: Some(InstructionTarget::B),
+        target: Some(InstructionTarget::A),
+        operation: Operation::ADD,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Dependent,
+        },
+    }),
+    // 0x81
+    Some(Instruction {
+        mnemonic: "ADD A, C",
+        source: Some(InstructionTarget::C),
+        target: Some(InstructionTarget::A),
+        operation: Operation::ADD,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Dependent,
+        },
+    }),
+    // 0x82
+    Some(Instruction {
+        mnemonic: "ADD A, D",
+        source: Some(InstructionTarget::D),
+        target: Some(InstructionTarget::A),
+        operation: Operation::ADD,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Dependent,
+        },
+    }),
+    // 0x83
+    Some(Instruction {
+        mnemonic: "ADD A, E",
+        source: Some(InstructionTarget::E),
+        target: Some(InstructionTarget::A),
+        operation: Operation::ADD,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Dependent,
+        },
+    }),
+    // 0x84
+    Some(Instruction {
+        mnemonic: "ADD A, H",
+        source: Some(InstructionTarget::H),
+        target: Some(InstructionTarget::A),
+        operation: Operation::ADD,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Dependent,
+        },
+    }),
+    // 0x85
+    Some(Instruction {
+        mnemonic: "ADD A, L",
+        source: Some(InstructionTarget::L),
+        target: Some(InstructionTarget::A),
+        operation: Operation::ADD,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Dependent,
+        },
+    }),
+    // 0x86
+    Some(Instruction {
+        mnemonic: "ADD A, (HL)",
+        source: Some(InstructionTarget::Ref(&InstructionTarget::HL)),
+        target: Some(InstructionTarget::A),
+        operation: Operation::ADD,
+        cycles: 8,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Dependent,
+        },
+    }),
+    // 0x87
+    Some(Instruction {
+        mnemonic: "ADD A, A",
+        source: Some(InstructionTarget::A),
+        target: Some(InstructionTarget::A),
+        operation: Operation::ADD,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Dependent,
+        },
+    }),
+    // 0x88
+    Some(Instruction {
+        mnemonic: "ADC A, B",
+        source: Some(InstructionTarget::B),
+        target: Some(InstructionTarget::A),
+        operation: Operation::ADC,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Dependent,
+        },
+    }),
+    // 0x89
+    Some(Instruction {
+        mnemonic: "ADC A, C",
+        source: Some(InstructionTarget::C),
+        target: Some(InstructionTarget::A),
+        operation: Operation::ADC,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Dependent,
+        },
+    }),
+    // 0x8A
+    Some(Instruction {
+        mnemonic: "ADC A, D",
+        source: Some(InstructionTarget::D),
+        target: Some(InstructionTarget::A),
+        operation: Operation::ADC,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Dependent,
+        },
+    }),
+    // 0x8B
+    Some(Instruction {
+        mnemonic: "ADC A, E",
+        source: Some(InstructionTarget::E),
+        target: Some(InstructionTarget::A),
+        operation: Operation::ADC,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Dependent,
+        },
+    }),
+    // 0x8C
+    Some(Instruction {
+        mnemonic: "ADC A, H",
+        source: Some(InstructionTarget::H),
+        target: Some(InstructionTarget::A),
+        operation: Operation::ADC,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Dependent,
+        },
+    }),
+    // 0x8D
+    Some(Instruction {
+        mnemonic: "ADC A, L",
+        source: Some(InstructionTarget::L),
+        target: Some(InstructionTarget::A),
+        operation: Operation::ADC,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Dependent,
+        },
+    }),
+    // 0x8E
+    Some(Instruction {
+        mnemonic: "ADC A, (HL)",
+        source: Some(InstructionTarget::Ref(&InstructionTarget::HL)),
+        target: Some(InstructionTarget::A),
+        operation: Operation::ADC,
+        cycles: 8,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Dependent,
+        },
+    }),
+    // 0x8F
+    Some(Instruction {
+        mnemonic: "ADC A, A",
+        source: Some(InstructionTarget::A),
+        target: Some(InstructionTarget::A),
+        operation: Operation::ADC,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Dependent,
+        },
+    }),
+    // 0x90
+    Some(Instruction {
+        mnemonic: "SUB A, B",
+        source: Some(InstructionTarget::B),
+        target: Some(InstructionTarget::A),
+        operation: Operation::SUB,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Set,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Dependent,
+        },
+    }),
+    // 0x91
+    Some(Instruction {
+        mnemonic: "SUB A, C",
+        source: Some(InstructionTarget::C),
+        target: Some(InstructionTarget::A),
+        operation: Operation::SUB,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Set,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Dependent,
+        },
+    }),
+    // 0x92
+    Some(Instruction {
+        mnemonic: "SUB A, D",
+        source: Some(InstructionTarget::D),
+        target: Some(InstructionTarget::A),
+        operation: Operation::SUB,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Set,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Dependent,
+        },
+    }),
+    // 0x93
+    Some(Instruction {
+        mnemonic: "SUB A, E",
+        source: Some(InstructionTarget::E),
+        target: Some(InstructionTarget::A),
+        operation: Operation::SUB,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Set,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Dependent,
+        },
+    }),
+    // 0x94
+    Some(Instruction {
+        mnemonic: "SUB A, H",
+        source: Some(InstructionTarget::H),
+        target: Some(InstructionTarget::A),
+        operation: Operation::SUB,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Set,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Dependent,
+        },
+    }),
+    // 0x95
+    Some(Instruction {
+        mnemonic: "SUB A, L",
+        source: Some(InstructionTarget::L),
+        target: Some(InstructionTarget::A),
+        operation: Operation::SUB,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Set,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Dependent,
+        },
+    }),
+    // 0x96
+    Some(Instruction {
+        mnemonic: "SUB A, (HL)",
+        source: Some(InstructionTarget::Ref(&InstructionTarget::HL)),
+        target: Some(InstructionTarget::A),
+        operation: Operation::SUB,
+        cycles: 8,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Set,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Dependent,
+        },
+    }),
+    // 0x97
+    Some(Instruction {
+        mnemonic: "SUB A, A",
+        source: Some(InstructionTarget::A),
+        target: Some(InstructionTarget::A),
+        operation: Operation::SUB,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Set,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Dependent,
+        },
+    }),
+    // 0x98
+    Some(Instruction {
+        mnemonic: "SBC A, B",
+        source: Some(InstructionTarget::B),
+        target: Some(InstructionTarget::A),
+        operation: Operation::SBC,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Set,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Dependent,
+        },
+    }),
+    // 0x99
+    Some(Instruction {
+        mnemonic: "SBC A, C",
+        source: Some(InstructionTarget::C),
+        target: Some(InstructionTarget::A),
+        operation: Operation::SBC,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Set,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Dependent,
+        },
+    }),
+    // 0x9A
+    Some(Instruction {
+        mnemonic: "SBC A, D",
+        source: Some(InstructionTarget::D),
+        target: Some(InstructionTarget::A),
+        operation: Operation::SBC,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Set,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Dependent,
+        },
+    }),
+    // 0x9B
+    Some(Instruction {
+        mnemonic: "SBC A, E",
+        source: Some(InstructionTarget::E),
+        target: Some(InstructionTarget::A),
+        operation: Operation::SBC,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Set,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Dependent,
+        },
+    }),
+    // 0x9C
+    Some(Instruction {
+        mnemonic: "SBC A, H",
+        source: Some(InstructionTarget::H),
+        target: Some(InstructionTarget::A),
+        operation: Operation::SBC,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Set,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Dependent,
+        },
+    }),
+    // 0x9D
+    Some(Instruction {
+        mnemonic: "SBC A, L",
+        source: Some(InstructionTarget::L),
+        target: Some(InstructionTarget::A),
+        operation: Operation::SBC,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Set,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Dependent,
+        },
+    }),
+    // 0x9E
+    Some(Instruction {
+        mnemonic: "SBC A, (HL)",
+        source: Some(InstructionTarget::Ref(&InstructionTarget::HL)),
+        target: Some(InstructionTarget::A),
+        operation: Operation::SBC,
+        cycles: 8,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Set,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Dependent,
+        },
+    }),
+    // 0x9F
+    Some(Instruction {
+        mnemonic: "SBC A, A",
+        source: Some(InstructionTarget::A),
+        target: Some(InstructionTarget::A),
+        operation: Operation::SBC,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Set,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Dependent,
+        },
+    }),
+    // 0xA0
+    Some(Instruction {
+        mnemonic: "AND A, B",
+        source: Some(InstructionTarget::B),
+        target: Some(InstructionTarget::A),
+        operation: Operation::AND,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unset,
+        },
+    }),
+    // 0xA1
+    Some(Instruction {
+        mnemonic: "AND A, C",
+        source: Some(InstructionTarget::C),
+        target: Some(InstructionTarget::A),
+        operation: Operation::AND,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unset,
+        },
+    }),
+    // 0xA2
+    Some(Instruction {
+        mnemonic: "AND A, D",
+        source: Some(InstructionTarget::D),
+        target: Some(InstructionTarget::A),
+        operation: Operation::AND,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unset,
+        },
+    }),
+    // 0xA3
+    Some(Instruction {
+        mnemonic: "AND A, E",
+        source: Some(InstructionTarget::E),
+        target: Some(InstructionTarget::A),
+        operation: Operation::AND,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unset,
+        },
+    }),
+    // 0xA4
+    Some(Instruction {
+        mnemonic: "AND A, H",
+        source: Some(InstructionTarget::H),
+        target: Some(InstructionTarget::A),
+        operation: Operation::AND,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unset,
+        },
+    }),
+    // 0xA5
+    Some(Instruction {
+        mnemonic: "AND A, L",
+        source: Some(InstructionTarget::L),
+        target: Some(InstructionTarget::A),
+        operation: Operation::AND,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unset,
+        },
+    }),
+    // 0xA6
+    Some(Instruction {
+        mnemonic: "AND A, (HL)",
+        source: Some(InstructionTarget::Ref(&InstructionTarget::HL)),
+        target: Some(InstructionTarget::A),
+        operation: Operation::AND,
+        cycles: 8,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unset,
+        },
+    }),
+    // 0xA7
+    Some(Instruction {
+        mnemonic: "AND A, A",
+        source: Some(InstructionTarget::A),
+        target: Some(InstructionTarget::A),
+        operation: Operation::AND,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unset,
+        },
+    }),
+    // 0xA8
+    Some(Instruction {
+        mnemonic: "XOR A, B",
+        source: Some(InstructionTarget::B),
+        target: Some(InstructionTarget::A),
+        operation: Operation::XOR,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Unset,
+        },
+    }),
+    // 0xA9
+    Some(Instruction {
+        mnemonic: "XOR A, C",
+        source: Some(InstructionTarget::C),
+        target: Some(InstructionTarget::A),
+        operation: Operation::XOR,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Unset,
+        },
+    }),
+    // 0xAA
+    Some(Instruction {
+        mnemonic: "XOR A, D",
+        source: Some(InstructionTarget::D),
+        target: Some(InstructionTarget::A),
+        operation: Operation::XOR,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Unset,
+        },
+    }),
+    // 0xAB
+    Some(Instruction {
+        mnemonic: "XOR A, E",
+        source: Some(InstructionTarget::E),
+        target: Some(InstructionTarget::A),
+        operation: Operation::XOR,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Unset,
+        },
+    }),
+    // 0xAC
+    Some(Instruction {
+        mnemonic: "XOR A, H",
+        source: Some(InstructionTarget::H),
+        target: Some(InstructionTarget::A),
+        operation: Operation::XOR,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Unset,
+        },
+    }),
+    // 0xAD
+    Some(Instruction {
+        mnemonic: "XOR A, L",
+        source: Some(InstructionTarget::L),
+        target: Some(InstructionTarget::A),
+        operation: Operation::XOR,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Unset,
+        },
+    }),
+    // 0xAE
+    Some(Instruction {
+        mnemonic: "XOR A, (HL)",
+        source: Some(InstructionTarget::Ref(&InstructionTarget::HL)),
+        target: Some(InstructionTarget::A),
+        operation: Operation::XOR,
+        cycles: 8,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Unset,
+        },
+    }),
+    // 0xAF
+    Some(Instruction {
+        mnemonic: "XOR A, A",
+        source: Some(InstructionTarget::A),
+        target: Some(InstructionTarget::A),
+        operation: Operation::XOR,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Unset,
+        },
+    }),
+    // 0xB0
+    Some(Instruction {
+        mnemonic: "OR A, B",
+        source: Some(InstructionTarget::B),
+        target: Some(InstructionTarget::A),
+        operation: Operation::OR,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Unset,
+        },
+    }),
+    // 0xB1
+    Some(Instruction {
+        mnemonic: "OR A, C",
+        source: Some(InstructionTarget::C),
+        target: Some(InstructionTarget::A),
+        operation: Operation::OR,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Unset,
+        },
+    }),
+    // 0xB2
+    Some(Instruction {
+        mnemonic: "OR A, D",
+        source: Some(InstructionTarget::D),
+        target: Some(InstructionTarget::A),
+        operation: Operation::OR,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Unset,
+        },
+    }),
+    // 0xB3
+    Some(Instruction {
+        mnemonic: "OR A, E",
+        source: Some(InstructionTarget::E),
+        target: Some(InstructionTarget::A),
+        operation: Operation::OR,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Unset,
+        },
+    }),
+    // 0xB4
+    Some(Instruction {
+        mnemonic: "OR A, H",
+        source: Some(InstructionTarget::H),
+        target: Some(InstructionTarget::A),
+        operation: Operation::OR,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Unset,
+        },
+    }),
+    // 0xB5
+    Some(Instruction {
+        mnemonic: "OR A, L",
+        source: Some(InstructionTarget::L),
+        target: Some(InstructionTarget::A),
+        operation: Operation::OR,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Unset,
+        },
+    }),
+    // 0xB6
+    Some(Instruction {
+        mnemonic: "OR A, (HL)",
+        source: Some(InstructionTarget::Ref(&InstructionTarget::HL)),
+        target: Some(InstructionTarget::A),
+        operation: Operation::OR,
+        cycles: 8,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Unset,
+        },
+    }),
+    // 0xB7
+    Some(Instruction {
+        mnemonic: "OR A, A",
+        source: Some(InstructionTarget::A),
+        target: Some(InstructionTarget::A),
+        operation: Operation::OR,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Unset,
+        },
+    }),
+    // 0xB8
+    Some(Instruction {
+        mnemonic: "CP A, B",
+        source: Some(InstructionTarget::B),
+        target: Some(InstructionTarget::A),
+        operation: Operation::CP,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Set,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Dependent,
+        },
+    }),
+    // 0xB9
+    Some(Instruction {
+        mnemonic: "CP A, C",
+        source: Some(InstructionTarget::C),
+        target: Some(InstructionTarget::A),
+        operation: Operation::CP,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Set,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Dependent,
+        },
+    }),
+    // 0xBA
+    Some(Instruction {
+        mnemonic: "CP A, D",
+        source: Some(InstructionTarget::D),
+        target: Some(InstructionTarget::A),
+        operation: Operation::CP,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Set,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Dependent,
+        },
+    }),
+    // 0xBB
+    Some(Instruction {
+        mnemonic: "CP A, E",
+        source: Some(InstructionTarget::E),
+        target: Some(InstructionTarget::A),
+        operation: Operation::CP,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Set,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Dependent,
+        },
+    }),
+    // 0xBC
+    Some(Instruction {
+        mnemonic: "CP A, H",
+        source: Some(InstructionTarget::H),
+        target: Some(InstructionTarget::A),
+        operation: Operation::CP,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Set,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Dependent,
+        },
+    }),
+    // 0xBD
+    Some(Instruction {
+        mnemonic: "CP A, L",
+        source: Some(InstructionTarget::L),
+        target: Some(InstructionTarget::A),
+        operation: Operation::CP,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Set,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Dependent,
+        },
+    }),
+    // 0xBE
+    Some(Instruction {
+        mnemonic: "CP A, (HL)",
+        source: Some(InstructionTarget::Ref(&InstructionTarget::HL)),
+        target: Some(InstructionTarget::A),
+        operation: Operation::CP,
+        cycles: 8,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Set,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Dependent,
+        },
+    }),
+    // 0xBF
+    Some(Instruction {
+        mnemonic: "CP A, A",
+        source: Some(InstructionTarget::A),
+        target: Some(InstructionTarget::A),
+        operation: Operation::CP,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Set,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Dependent,
+        },
+    }),
+    // 0xC0
+    Some(Instruction {
+        mnemonic: "RET NZ",
+        source: None,
+        target: None,
+        operation: Operation::RET(Condition::NotZero),
+        cycles: 8,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0xC1
+    Some(Instruction {
+        mnemonic: "POP BC",
+        source: None,
+        target: Some(InstructionTarget::BC),
+        operation: Operation::POP,
+        cycles: 12,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0xC2
+    Some(Instruction {
+        mnemonic: "JP NZ, u16",
+        source: Some(InstructionTarget::N16),
+        target: None,
+        operation: Operation::JP(Condition::NotZero),
+        cycles: 12,
+        length: 3,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0xC3
+    Some(Instruction {
+        mnemonic: "JP u16",
+        source: Some(InstructionTarget::N16),
+        target: None,
+        operation: Operation::JP(Condition::Always),
+        cycles: 16,
+        length: 3,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0xC4
+    Some(Instruction {
+        mnemonic: "CALL NZ, u16",
+        source: Some(InstructionTarget::N16),
+        target: None,
+        operation: Operation::CALL(Condition::NotZero),
+        cycles: 12,
+        length: 3,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0xC5
+    Some(Instruction {
+        mnemonic: "PUSH BC",
+        source: Some(InstructionTarget::BC),
+        target: None,
+        operation: Operation::PUSH,
+        cycles: 16,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0xC6
+    Some(Instruction {
+        mnemonic: "ADD A, u8",
+        source: Some(InstructionTarget::N8),
+        target: Some(InstructionTarget::A),
+        operation: Operation::ADD,
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Dependent,
+        },
+    }),
+    // 0xC7
+    Some(Instruction {
+        mnemonic: "RST 00h",
+        source: None,
+        target: None,
+        operation: Operation::RST(0x0000),
+        cycles: 16,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0xC8
+    Some(Instruction {
+        mnemonic: "RET Z",
+        source: None,
+        target: None,
+        operation: Operation::RET(Condition::Zero),
+        cycles: 8,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0xC9
+    Some(Instruction {
+        mnemonic: "RET",
+        source: None,
+        target: None,
+        operation: Operation::RET(Condition::Always),
+        cycles: 16,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0xCA
+    Some(Instruction {
+        mnemonic: "JP Z, u16",
+        source: Some(InstructionTarget::N16),
+        target: None,
+        operation: Operation::JP(Condition::Zero),
+        cycles: 12,
+        length: 3,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0xCB
+    None,
+    // 0xCC
+    Some(Instruction {
+        mnemonic: "CALL Z, u16",
+        source: Some(InstructionTarget::N16),
+        target: None,
+        operation: Operation::CALL(Condition::Zero),
+        cycles: 12,
+        length: 3,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0xCD
+    Some(Instruction {
+        mnemonic: "CALL u16",
+        source: Some(InstructionTarget::N16),
+        target: None,
+        operation: Operation::CALL(Condition::Always),
+        cycles: 24,
+        length: 3,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0xCE
+    Some(Instruction {
+        mnemonic: "ADC A, u8",
+        source: Some(InstructionTarget::N8),
+        target: Some(InstructionTarget::A),
+        operation: Operation::ADC,
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Dependent,
+        },
+    }),
+    // 0xCF
+    Some(Instruction {
+        mnemonic: "RST 08h",
+        source: None,
+        target: None,
+        operation: Operation::RST(0x0008),
+        cycles: 16,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0xD0
+    Some(Instruction {
+        mnemonic: "RET NC",
+        source: None,
+        target: None,
+        operation: Operation::RET(Condition::NotCarry),
+        cycles: 8,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0xD1
+    Some(Instruction {
+        mnemonic: "POP DE",
+        source: None,
+        target: Some(InstructionTarget::DE),
+        operation: Operation::POP,
+        cycles: 12,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0xD2
+    Some(Instruction {
+        mnemonic: "JP NC, u16",
+        source: Some(InstructionTarget::N16),
+        target: None,
+        operation: Operation::JP(Condition::NotCarry),
+        cycles: 12,
+        length: 3,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0xD3
+    None,
+    // 0xD4
+    Some(Instruction {
+        mnemonic: "CALL NC, u16",
+        source: Some(InstructionTarget::N16),
+        target: None,
+        operation: Operation::CALL(Condition::NotCarry),
+        cycles: 12,
+        length: 3,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0xD5
+    Some(Instruction {
+        mnemonic: "PUSH DE",
+        source: Some(InstructionTarget::DE),
+        target: None,
+        operation: Operation::PUSH,
+        cycles: 16,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0xD6
+    Some(Instruction {
+        mnemonic: "SUB A, u8",
+        source: Some(InstructionTarget::N8),
+        target: Some(InstructionTarget::A),
+        operation: Operation::SUB,
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Set,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Dependent,
+        },
+    }),
+    // 0xD7
+    Some(Instruction {
+        mnemonic: "RST 10h",
+        source: None,
+        target: None,
+        operation: Operation::RST(0x0010),
+        cycles: 16,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0xD8
+    Some(Instruction {
+        mnemonic: "RET C",
+        source: None,
+        target: None,
+        operation: Operation::RET(Condition::Carry),
+        cycles: 8,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0xD9
+    Some(Instruction {
+        mnemonic: "RETI",
+        source: None,
+        target: None,
+        operation: Operation::RETI,
+        cycles: 16,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0xDA
+    Some(Instruction {
+        mnemonic: "JP C, u16",
+        source: Some(InstructionTarget::N16),
+        target: None,
+        operation: Operation::JP(Condition::Carry),
+        cycles: 12,
+        length: 3,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0xDB
+    None,
+    // 0xDC
+    Some(Instruction {
+        mnemonic: "CALL C, u16",
+        source: Some(InstructionTarget::N16),
+        target: None,
+        operation: Operation::CALL(Condition::Carry),
+        cycles: 12,
+        length: 3,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0xDD
+    None,
+    // 0xDE
+    Some(Instruction {
+        mnemonic: "SBC A, u8",
+        source: Some(InstructionTarget::N8),
+        target: Some(InstructionTarget::A),
+        operation: Operation::SBC,
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Set,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Dependent,
+        },
+    }),
+    // 0xDF
+    Some(Instruction {
+        mnemonic: "RST 18h",
+        source: None,
+        target: None,
+        operation: Operation::RST(0x0018),
+        cycles: 16,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0xE0
+    Some(Instruction {
+        mnemonic: "LD (FF00+u8), A",
+        source: Some(InstructionTarget::A),
+        target: Some(InstructionTarget::High(&InstructionTarget::N8)),
+        operation: Operation::LD,
+        cycles: 12,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0xE1
+    Some(Instruction {
+        mnemonic: "POP HL",
+        source: None,
+        target: Some(InstructionTarget::HL),
+        operation: Operation::POP,
+        cycles: 12,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0xE2
+    Some(Instruction {
+        mnemonic: "LD (FF00+C), A",
+        source: Some(InstructionTarget::A),
+        target: Some(InstructionTarget::High(&InstructionTarget::C)),
+        operation: Operation::LD,
+        cycles: 8,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0xE3
+    None,
+    // 0xE4
+    None,
+    // 0xE5
+    Some(Instruction {
+        mnemonic: "PUSH HL",
+        source: Some(InstructionTarget::HL),
+        target: None,
+        operation: Operation::PUSH,
+        cycles: 16,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0xE6
+    Some(Instruction {
+        mnemonic: "AND A, u8",
+        source: Some(InstructionTarget::N8),
+        target: Some(InstructionTarget::A),
+        operation: Operation::AND,
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unset,
+        },
+    }),
+    // 0xE7
+    Some(Instruction {
+        mnemonic: "RST 20h",
+        source: None,
+        target: None,
+        operation: Operation::RST(0x0020),
+        cycles: 16,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0xE8
+    Some(Instruction {
+        mnemonic: "ADD SP, i8",
+        source: Some(InstructionTarget::N8),
+        target: Some(InstructionTarget::SP),
+        operation: Operation::ADD,
+        cycles: 16,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Unset,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Dependent,
+        },
+    }),
+    // 0xE9
+    Some(Instruction {
+        mnemonic: "JP HL",
+        source: Some(InstructionTarget::HL),
+        target: None,
+        operation: Operation::JP(Condition::Always),
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0xEA
+    Some(Instruction {
+        mnemonic: "LD (u16), A",
+        source: Some(InstructionTarget::A),
+        target: Some(InstructionTarget::Ref(&InstructionTarget::N16)),
+        operation: Operation::LD,
+        cycles: 16,
+        length: 3,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0xEB
+    None,
+    // 0xEC
+    None,
+    // 0xED
+    None,
+    // 0xEE
+    Some(Instruction {
+        mnemonic: "XOR A, u8",
+        source: Some(InstructionTarget::N8),
+        target: Some(InstructionTarget::A),
+        operation: Operation::XOR,
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Unset,
+        },
+    }),
+    // 0xEF
+    Some(Instruction {
+        mnemonic: "RST 28h",
+        source: None,
+        target: None,
+        operation: Operation::RST(0x0028),
+        cycles: 16,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0xF0
+    Some(Instruction {
+        mnemonic: "LD A, (FF00+u8)",
+        source: Some(InstructionTarget::High(&InstructionTarget::N8)),
+        target: Some(InstructionTarget::A),
+        operation: Operation::LD,
+        cycles: 12,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0xF1
+    Some(Instruction {
+        mnemonic: "POP AF",
+        source: None,
+        target: Some(InstructionTarget::AF),
+        operation: Operation::POP,
+        cycles: 12,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0xF2
+    Some(Instruction {
+        mnemonic: "LD A, (FF00+C)",
+        source: Some(InstructionTarget::High(&InstructionTarget::C)),
+        target: Some(InstructionTarget::A),
+        operation: Operation::LD,
+        cycles: 8,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0xF3
+    Some(Instruction {
+        mnemonic: "DI",
+        source: None,
+        target: None,
+        operation: Operation::DI,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0xF4
+    None,
+    // 0xF5
+    Some(Instruction {
+        mnemonic: "PUSH AF",
+        source: Some(InstructionTarget::AF),
+        target: None,
+        operation: Operation::PUSH,
+        cycles: 16,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0xF6
+    Some(Instruction {
+        mnemonic: "OR A, u8",
+        source: Some(InstructionTarget::N8),
+        target: Some(InstructionTarget::A),
+        operation: Operation::OR,
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Unset,
+        },
+    }),
+    // 0xF7
+    Some(Instruction {
+        mnemonic: "RST 30h",
+        source: None,
+        target: None,
+        operation: Operation::RST(0x0030),
+        cycles: 16,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0xF8
+    Some(Instruction {
+        mnemonic: "LD HL, SP+i8",
+        source: Some(InstructionTarget::N8),
+        target: Some(InstructionTarget::HL),
+        operation: Operation::LDHL,
+        cycles: 12,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Unset,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Dependent,
+        },
+    }),
+    // 0xF9
+    Some(Instruction {
+        mnemonic: "LD SP, HL",
+        source: Some(InstructionTarget::HL),
+        target: Some(InstructionTarget::SP),
+        operation: Operation::LD,
+        cycles: 8,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0xFA
+    Some(Instruction {
+        mnemonic: "LD A, (u16)",
+        source: Some(InstructionTarget::Ref(&InstructionTarget::N16)),
+        target: Some(InstructionTarget::A),
+        operation: Operation::LD,
+        cycles: 16,
+        length: 3,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0xFB
+    Some(Instruction {
+        mnemonic: "EI",
+        source: None,
+        target: None,
+        operation: Operation::EI,
+        cycles: 4,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+    // 0xFC
+    None,
+    // 0xFD
+    None,
+    // 0xFE
+    Some(Instruction {
+        mnemonic: "CP A, u8",
+        source: Some(InstructionTarget::N8),
+        target: Some(InstructionTarget::A),
+        operation: Operation::CP,
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Set,
+            half_carry: FlagOperation::Dependent,
+            carry: FlagOperation::Dependent,
+        },
+    }),
+    // 0xFF
+    Some(Instruction {
+        mnemonic: "RST 38h",
+        source: None,
+        target: None,
+        operation: Operation::RST(0x0038),
+        cycles: 16,
+        length: 1,
+        flags: FlagInstruction::UNMODIFIED,
+    }),
+];
+
+// Instructions following the 0xCB prefix indexed by the second opcode byte
+pub static CB_INSTRUCTIONS: [Instruction; 256] = [
+    // 0xCB00
+    Instruction {
+        mnemonic: "RLC B",
+        source: None,
+        target: Some(InstructionTarget::B),
+        operation: Operation::RXC(Direction::Left),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Dependent,
+        },
+    },
+    // 0xCB01
+    Instruction {
+        mnemonic: "RLC C",
+        source: None,
+        target: Some(InstructionTarget::C),
+        operation: Operation::RXC(Direction::Left),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Dependent,
+        },
+    },
+    // 0xCB02
+    Instruction {
+        mnemonic: "RLC D",
+        source: None,
+        target: Some(InstructionTarget::D),
+        operation: Operation::RXC(Direction::Left),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Dependent,
+        },
+    },
+    // 0xCB03
+    Instruction {
+        mnemonic: "RLC E",
+        source: None,
+        target: Some(InstructionTarget::E),
+        operation: Operation::RXC(Direction::Left),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Dependent,
+        },
+    },
+    // 0xCB04
+    Instruction {
+        mnemonic: "RLC H",
+        source: None,
+        target: Some(InstructionTarget::H),
+        operation: Operation::RXC(Direction::Left),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Dependent,
+        },
+    },
+    // 0xCB05
+    Instruction {
+        mnemonic: "RLC L",
+        source: None,
+        target: Some(InstructionTarget::L),
+        operation: Operation::RXC(Direction::Left),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Dependent,
+        },
+    },
+    // 0xCB06
+    Instruction {
+        mnemonic: "RLC (HL)",
+        source: None,
+        target: Some(InstructionTarget::Ref(&InstructionTarget::HL)),
+        operation: Operation::RXC(Direction::Left),
+        cycles: 16,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Dependent,
+        },
+    },
+    // 0xCB07
+    Instruction {
+        mnemonic: "RLC A",
+        source: None,
+        target: Some(InstructionTarget::A),
+        operation: Operation::RXC(Direction::Left),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Dependent,
+        },
+    },
+    // 0xCB08
+    Instruction {
+        mnemonic: "RRC B",
+        source: None,
+        target: Some(InstructionTarget::B),
+        operation: Operation::RXC(Direction::Right),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Dependent,
+        },
+    },
+    // 0xCB09
+    Instruction {
+        mnemonic: "RRC C",
+        source: None,
+        target: Some(InstructionTarget::C),
+        operation: Operation::RXC(Direction::Right),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Dependent,
+        },
+    },
+    // 0xCB0A
+    Instruction {
+        mnemonic: "RRC D",
+        source: None,
+        target: Some(InstructionTarget::D),
+        operation: Operation::RXC(Direction::Right),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Dependent,
+        },
+    },
+    // 0xCB0B
+    Instruction {
+        mnemonic: "RRC E",
+        source: None,
+        target: Some(InstructionTarget::E),
+        operation: Operation::RXC(Direction::Right),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Dependent,
+        },
+    },
+    // 0xCB0C
+    Instruction {
+        mnemonic: "RRC H",
+        source: None,
+        target: Some(InstructionTarget::H),
+        operation: Operation::RXC(Direction::Right),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Dependent,
+        },
+    },
+    // 0xCB0D
+    Instruction {
+        mnemonic: "RRC L",
+        source: None,
+        target: Some(InstructionTarget::L),
+        operation: Operation::RXC(Direction::Right),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Dependent,
+        },
+    },
+    // 0xCB0E
+    Instruction {
+        mnemonic: "RRC (HL)",
+        source: None,
+        target: Some(InstructionTarget::Ref(&InstructionTarget::HL)),
+        operation: Operation::RXC(Direction::Right),
+        cycles: 16,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Dependent,
+        },
+    },
+    // 0xCB0F
+    Instruction {
+        mnemonic: "RRC A",
+        source: None,
+        target: Some(InstructionTarget::A),
+        operation: Operation::RXC(Direction::Right),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Dependent,
+        },
+    },
+    // 0xCB10
+    Instruction {
+        mnemonic: "RL B",
+        source: None,
+        target: Some(InstructionTarget::B),
+        operation: Operation::RX(Direction::Left),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Dependent,
+        },
+    },
+    // 0xCB11
+    Instruction {
+        mnemonic: "RL C",
+        source: None,
+        target: Some(InstructionTarget::C),
+        operation: Operation::RX(Direction::Left),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Dependent,
+        },
+    },
+    // 0xCB12
+    Instruction {
+        mnemonic: "RL D",
+        source: None,
+        target: Some(InstructionTarget::D),
+        operation: Operation::RX(Direction::Left),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Dependent,
+        },
+    },
+    // 0xCB13
+    Instruction {
+        mnemonic: "RL E",
+        source: None,
+        target: Some(InstructionTarget::E),
+        operation: Operation::RX(Direction::Left),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Dependent,
+        },
+    },
+    // 0xCB14
+    Instruction {
+        mnemonic: "RL H",
+        source: None,
+        target: Some(InstructionTarget::H),
+        operation: Operation::RX(Direction::Left),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Dependent,
+        },
+    },
+    // 0xCB15
+    Instruction {
+        mnemonic: "RL L",
+        source: None,
+        target: Some(InstructionTarget::L),
+        operation: Operation::RX(Direction::Left),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Dependent,
+        },
+    },
+    // 0xCB16
+    Instruction {
+        mnemonic: "RL (HL)",
+        source: None,
+        target: Some(InstructionTarget::Ref(&InstructionTarget::HL)),
+        operation: Operation::RX(Direction::Left),
+        cycles: 16,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Dependent,
+        },
+    },
+    // 0xCB17
+    Instruction {
+        mnemonic: "RL A",
+        source: None,
+        target: Some(InstructionTarget::A),
+        operation: Operation::RX(Direction::Left),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Dependent,
+        },
+    },
+    // 0xCB18
+    Instruction {
+        mnemonic: "RR B",
+        source: None,
+        target: Some(InstructionTarget::B),
+        operation: Operation::RX(Direction::Right),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Dependent,
+        },
+    },
+    // 0xCB19
+    Instruction {
+        mnemonic: "RR C",
+        source: None,
+        target: Some(InstructionTarget::C),
+        operation: Operation::RX(Direction::Right),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Dependent,
+        },
+    },
+    // 0xCB1A
+    Instruction {
+        mnemonic: "RR D",
+        source: None,
+        target: Some(InstructionTarget::D),
+        operation: Operation::RX(Direction::Right),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Dependent,
+        },
+    },
+    // 0xCB1B
+    Instruction {
+        mnemonic: "RR E",
+        source: None,
+        target: Some(InstructionTarget::E),
+        operation: Operation::RX(Direction::Right),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Dependent,
+        },
+    },
+    // 0xCB1C
+    Instruction {
+        mnemonic: "RR H",
+        source: None,
+        target: Some(InstructionTarget::H),
+        operation: Operation::RX(Direction::Right),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Dependent,
+        },
+    },
+    // 0xCB1D
+    Instruction {
+        mnemonic: "RR L",
+        source: None,
+        target: Some(InstructionTarget::L),
+        operation: Operation::RX(Direction::Right),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Dependent,
+        },
+    },
+    // 0xCB1E
+    Instruction {
+        mnemonic: "RR (HL)",
+        source: None,
+        target: Some(InstructionTarget::Ref(&InstructionTarget::HL)),
+        operation: Operation::RX(Direction::Right),
+        cycles: 16,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Dependent,
+        },
+    },
+    // 0xCB1F
+    Instruction {
+        mnemonic: "RR A",
+        source: None,
+        target: Some(InstructionTarget::A),
+        operation: Operation::RX(Direction::Right),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Dependent,
+        },
+    },
+    // 0xCB20
+    Instruction {
+        mnemonic: "SLA B",
+        source: None,
+        target: Some(InstructionTarget::B),
+        operation: Operation::SLA,
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Dependent,
+        },
+    },
+    // 0xCB21
+    Instruction {
+        mnemonic: "SLA C",
+        source: None,
+        target: Some(InstructionTarget::C),
+        operation: Operation::SLA,
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Dependent,
+        },
+    },
+    // 0xCB22
+    Instruction {
+        mnemonic: "SLA D",
+        source: None,
+        target: Some(InstructionTarget::D),
+        operation: Operation::SLA,
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Dependent,
+        },
+    },
+    // 0xCB23
+    Instruction {
+        mnemonic: "SLA E",
+        source: None,
+        target: Some(InstructionTarget::E),
+        operation: Operation::SLA,
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Dependent,
+        },
+    },
+    // 0xCB24
+    Instruction {
+        mnemonic: "SLA H",
+        source: None,
+        target: Some(InstructionTarget::H),
+        operation: Operation::SLA,
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Dependent,
+        },
+    },
+    // 0xCB25
+    Instruction {
+        mnemonic: "SLA L",
+        source: None,
+        target: Some(InstructionTarget::L),
+        operation: Operation::SLA,
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Dependent,
+        },
+    },
+    // 0xCB26
+    Instruction {
+        mnemonic: "SLA (HL)",
+        source: None,
+        target: Some(InstructionTarget::Ref(&InstructionTarget::HL)),
+        operation: Operation::SLA,
+        cycles: 16,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Dependent,
+        },
+    },
+    // 0xCB27
+    Instruction {
+        mnemonic: "SLA A",
+        source: None,
+        target: Some(InstructionTarget::A),
+        operation: Operation::SLA,
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Dependent,
+        },
+    },
+    // 0xCB28
+    Instruction {
+        mnemonic: "SRA B",
+        source: None,
+        target: Some(InstructionTarget::B),
+        operation: Operation::SRA,
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Dependent,
+        },
+    },
+    // 0xCB29
+    Instruction {
+        mnemonic: "SRA C",
+        source: None,
+        target: Some(InstructionTarget::C),
+        operation: Operation::SRA,
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Dependent,
+        },
+    },
+    // 0xCB2A
+    Instruction {
+        mnemonic: "SRA D",
+        source: None,
+        target: Some(InstructionTarget::D),
+        operation: Operation::SRA,
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Dependent,
+        },
+    },
+    // 0xCB2B
+    Instruction {
+        mnemonic: "SRA E",
+        source: None,
+        target: Some(InstructionTarget::E),
+        operation: Operation::SRA,
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Dependent,
+        },
+    },
+    // 0xCB2C
+    Instruction {
+        mnemonic: "SRA H",
+        source: None,
+        target: Some(InstructionTarget::H),
+        operation: Operation::SRA,
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Dependent,
+        },
+    },
+    // 0xCB2D
+    Instruction {
+        mnemonic: "SRA L",
+        source: None,
+        target: Some(InstructionTarget::L),
+        operation: Operation::SRA,
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Dependent,
+        },
+    },
+    // 0xCB2E
+    Instruction {
+        mnemonic: "SRA (HL)",
+        source: None,
+        target: Some(InstructionTarget::Ref(&InstructionTarget::HL)),
+        operation: Operation::SRA,
+        cycles: 16,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Dependent,
+        },
+    },
+    // 0xCB2F
+    Instruction {
+        mnemonic: "SRA A",
+        source: None,
+        target: Some(InstructionTarget::A),
+        operation: Operation::SRA,
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Dependent,
+        },
+    },
+    // 0xCB30
+    Instruction {
+        mnemonic: "SWAP B",
+        source: None,
+        target: Some(InstructionTarget::B),
+        operation: Operation::SWAP,
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Unset,
+        },
+    },
+    // 0xCB31
+    Instruction {
+        mnemonic: "SWAP C",
+        source: None,
+        target: Some(InstructionTarget::C),
+        operation: Operation::SWAP,
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Unset,
+        },
+    },
+    // 0xCB32
+    Instruction {
+        mnemonic: "SWAP D",
+        source: None,
+        target: Some(InstructionTarget::D),
+        operation: Operation::SWAP,
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Unset,
+        },
+    },
+    // 0xCB33
+    Instruction {
+        mnemonic: "SWAP E",
+        source: None,
+        target: Some(InstructionTarget::E),
+        operation: Operation::SWAP,
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Unset,
+        },
+    },
+    // 0xCB34
+    Instruction {
+        mnemonic: "SWAP H",
+        source: None,
+        target: Some(InstructionTarget::H),
+        operation: Operation::SWAP,
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Unset,
+        },
+    },
+    // 0xCB35
+    Instruction {
+        mnemonic: "SWAP L",
+        source: None,
+        target: Some(InstructionTarget::L),
+        operation: Operation::SWAP,
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Unset,
+        },
+    },
+    // 0xCB36
+    Instruction {
+        mnemonic: "SWAP (HL)",
+        source: None,
+        target: Some(InstructionTarget::Ref(&InstructionTarget::HL)),
+        operation: Operation::SWAP,
+        cycles: 16,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Unset,
+        },
+    },
+    // 0xCB37
+    Instruction {
+        mnemonic: "SWAP A",
+        source: None,
+        target: Some(InstructionTarget::A),
+        operation: Operation::SWAP,
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Unset,
+        },
+    },
+    // 0xCB38
+    Instruction {
+        mnemonic: "SRL B",
+        source: None,
+        target: Some(InstructionTarget::B),
+        operation: Operation::SRL,
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Dependent,
+        },
+    },
+    // 0xCB39
+    Instruction {
+        mnemonic: "SRL C",
+        source: None,
+        target: Some(InstructionTarget::C),
+        operation: Operation::SRL,
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Dependent,
+        },
+    },
+    // 0xCB3A
+    Instruction {
+        mnemonic: "SRL D",
+        source: None,
+        target: Some(InstructionTarget::D),
+        operation: Operation::SRL,
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Dependent,
+        },
+    },
+    // 0xCB3B
+    Instruction {
+        mnemonic: "SRL E",
+        source: None,
+        target: Some(InstructionTarget::E),
+        operation: Operation::SRL,
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Dependent,
+        },
+    },
+    // 0xCB3C
+    Instruction {
+        mnemonic: "SRL H",
+        source: None,
+        target: Some(InstructionTarget::H),
+        operation: Operation::SRL,
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Dependent,
+        },
+    },
+    // 0xCB3D
+    Instruction {
+        mnemonic: "SRL L",
+        source: None,
+        target: Some(InstructionTarget::L),
+        operation: Operation::SRL,
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Dependent,
+        },
+    },
+    // 0xCB3E
+    Instruction {
+        mnemonic: "SRL (HL)",
+        source: None,
+        target: Some(InstructionTarget::Ref(&InstructionTarget::HL)),
+        operation: Operation::SRL,
+        cycles: 16,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Dependent,
+        },
+    },
+    // 0xCB3F
+    Instruction {
+        mnemonic: "SRL A",
+        source: None,
+        target: Some(InstructionTarget::A),
+        operation: Operation::SRL,
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Unset,
+            carry: FlagOperation::Dependent,
+        },
+    },
+    // 0xCB40
+    Instruction {
+        mnemonic: "BIT 0, B",
+        source: None,
+        target: Some(InstructionTarget::B),
+        operation: Operation::BIT(0),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB41
+    Instruction {
+        mnemonic: "BIT 0, C",
+        source: None,
+        target: Some(InstructionTarget::C),
+        operation: Operation::BIT(0),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB42
+    Instruction {
+        mnemonic: "BIT 0, D",
+        source: None,
+        target: Some(InstructionTarget::D),
+        operation: Operation::BIT(0),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB43
+    Instruction {
+        mnemonic: "BIT 0, E",
+        source: None,
+        target: Some(InstructionTarget::E),
+        operation: Operation::BIT(0),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB44
+    Instruction {
+        mnemonic: "BIT 0, H",
+        source: None,
+        target: Some(InstructionTarget::H),
+        operation: Operation::BIT(0),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB45
+    Instruction {
+        mnemonic: "BIT 0, L",
+        source: None,
+        target: Some(InstructionTarget::L),
+        operation: Operation::BIT(0),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB46
+    Instruction {
+        mnemonic: "BIT 0, (HL)",
+        source: None,
+        target: Some(InstructionTarget::Ref(&InstructionTarget::HL)),
+        operation: Operation::BIT(0),
+        cycles: 12,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB47
+    Instruction {
+        mnemonic: "BIT 0, A",
+        source: None,
+        target: Some(InstructionTarget::A),
+        operation: Operation::BIT(0),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB48
+    Instruction {
+        mnemonic: "BIT 1, B",
+        source: None,
+        target: Some(InstructionTarget::B),
+        operation: Operation::BIT(1),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB49
+    Instruction {
+        mnemonic: "BIT 1, C",
+        source: None,
+        target: Some(InstructionTarget::C),
+        operation: Operation::BIT(1),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB4A
+    Instruction {
+        mnemonic: "BIT 1, D",
+        source: None,
+        target: Some(InstructionTarget::D),
+        operation: Operation::BIT(1),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB4B
+    Instruction {
+        mnemonic: "BIT 1, E",
+        source: None,
+        target: Some(InstructionTarget::E),
+        operation: Operation::BIT(1),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB4C
+    Instruction {
+        mnemonic: "BIT 1, H",
+        source: None,
+        target: Some(InstructionTarget::H),
+        operation: Operation::BIT(1),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB4D
+    Instruction {
+        mnemonic: "BIT 1, L",
+        source: None,
+        target: Some(InstructionTarget::L),
+        operation: Operation::BIT(1),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB4E
+    Instruction {
+        mnemonic: "BIT 1, (HL)",
+        source: None,
+        target: Some(InstructionTarget::Ref(&InstructionTarget::HL)),
+        operation: Operation::BIT(1),
+        cycles: 12,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB4F
+    Instruction {
+        mnemonic: "BIT 1, A",
+        source: None,
+        target: Some(InstructionTarget::A),
+        operation: Operation::BIT(1),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB50
+    Instruction {
+        mnemonic: "BIT 2, B",
+        source: None,
+        target: Some(InstructionTarget::B),
+        operation: Operation::BIT(2),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB51
+    Instruction {
+        mnemonic: "BIT 2, C",
+        source: None,
+        target: Some(InstructionTarget::C),
+        operation: Operation::BIT(2),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB52
+    Instruction {
+        mnemonic: "BIT 2, D",
+        source: None,
+        target: Some(InstructionTarget::D),
+        operation: Operation::BIT(2),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB53
+    Instruction {
+        mnemonic: "BIT 2, E",
+        source: None,
+        target: Some(InstructionTarget::E),
+        operation: Operation::BIT(2),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB54
+    Instruction {
+        mnemonic: "BIT 2, H",
+        source: None,
+        target: Some(InstructionTarget::H),
+        operation: Operation::BIT(2),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB55
+    Instruction {
+        mnemonic: "BIT 2, L",
+        source: None,
+        target: Some(InstructionTarget::L),
+        operation: Operation::BIT(2),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB56
+    Instruction {
+        mnemonic: "BIT 2, (HL)",
+        source: None,
+        target: Some(InstructionTarget::Ref(&InstructionTarget::HL)),
+        operation: Operation::BIT(2),
+        cycles: 12,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB57
+    Instruction {
+        mnemonic: "BIT 2, A",
+        source: None,
+        target: Some(InstructionTarget::A),
+        operation: Operation::BIT(2),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB58
+    Instruction {
+        mnemonic: "BIT 3, B",
+        source: None,
+        target: Some(InstructionTarget::B),
+        operation: Operation::BIT(3),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB59
+    Instruction {
+        mnemonic: "BIT 3, C",
+        source: None,
+        target: Some(InstructionTarget::C),
+        operation: Operation::BIT(3),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB5A
+    Instruction {
+        mnemonic: "BIT 3, D",
+        source: None,
+        target: Some(InstructionTarget::D),
+        operation: Operation::BIT(3),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB5B
+    Instruction {
+        mnemonic: "BIT 3, E",
+        source: None,
+        target: Some(InstructionTarget::E),
+        operation: Operation::BIT(3),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB5C
+    Instruction {
+        mnemonic: "BIT 3, H",
+        source: None,
+        target: Some(InstructionTarget::H),
+        operation: Operation::BIT(3),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB5D
+    Instruction {
+        mnemonic: "BIT 3, L",
+        source: None,
+        target: Some(InstructionTarget::L),
+        operation: Operation::BIT(3),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB5E
+    Instruction {
+        mnemonic: "BIT 3, (HL)",
+        source: None,
+        target: Some(InstructionTarget::Ref(&InstructionTarget::HL)),
+        operation: Operation::BIT(3),
+        cycles: 12,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB5F
+    Instruction {
+        mnemonic: "BIT 3, A",
+        source: None,
+        target: Some(InstructionTarget::A),
+        operation: Operation::BIT(3),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB60
+    Instruction {
+        mnemonic: "BIT 4, B",
+        source: None,
+        target: Some(InstructionTarget::B),
+        operation: Operation::BIT(4),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB61
+    Instruction {
+        mnemonic: "BIT 4, C",
+        source: None,
+        target: Some(InstructionTarget::C),
+        operation: Operation::BIT(4),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB62
+    Instruction {
+        mnemonic: "BIT 4, D",
+        source: None,
+        target: Some(InstructionTarget::D),
+        operation: Operation::BIT(4),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB63
+    Instruction {
+        mnemonic: "BIT 4, E",
+        source: None,
+        target: Some(InstructionTarget::E),
+        operation: Operation::BIT(4),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB64
+    Instruction {
+        mnemonic: "BIT 4, H",
+        source: None,
+        target: Some(InstructionTarget::H),
+        operation: Operation::BIT(4),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB65
+    Instruction {
+        mnemonic: "BIT 4, L",
+        source: None,
+        target: Some(InstructionTarget::L),
+        operation: Operation::BIT(4),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB66
+    Instruction {
+        mnemonic: "BIT 4, (HL)",
+        source: None,
+        target: Some(InstructionTarget::Ref(&InstructionTarget::HL)),
+        operation: Operation::BIT(4),
+        cycles: 12,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB67
+    Instruction {
+        mnemonic: "BIT 4, A",
+        source: None,
+        target: Some(InstructionTarget::A),
+        operation: Operation::BIT(4),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB68
+    Instruction {
+        mnemonic: "BIT 5, B",
+        source: None,
+        target: Some(InstructionTarget::B),
+        operation: Operation::BIT(5),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB69
+    Instruction {
+        mnemonic: "BIT 5, C",
+        source: None,
+        target: Some(InstructionTarget::C),
+        operation: Operation::BIT(5),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB6A
+    Instruction {
+        mnemonic: "BIT 5, D",
+        source: None,
+        target: Some(InstructionTarget::D),
+        operation: Operation::BIT(5),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB6B
+    Instruction {
+        mnemonic: "BIT 5, E",
+        source: None,
+        target: Some(InstructionTarget::E),
+        operation: Operation::BIT(5),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB6C
+    Instruction {
+        mnemonic: "BIT 5, H",
+        source: None,
+        target: Some(InstructionTarget::H),
+        operation: Operation::BIT(5),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB6D
+    Instruction {
+        mnemonic: "BIT 5, L",
+        source: None,
+        target: Some(InstructionTarget::L),
+        operation: Operation::BIT(5),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB6E
+    Instruction {
+        mnemonic: "BIT 5, (HL)",
+        source: None,
+        target: Some(InstructionTarget::Ref(&InstructionTarget::HL)),
+        operation: Operation::BIT(5),
+        cycles: 12,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB6F
+    Instruction {
+        mnemonic: "BIT 5, A",
+        source: None,
+        target: Some(InstructionTarget::A),
+        operation: Operation::BIT(5),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB70
+    Instruction {
+        mnemonic: "BIT 6, B",
+        source: None,
+        target: Some(InstructionTarget::B),
+        operation: Operation::BIT(6),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB71
+    Instruction {
+        mnemonic: "BIT 6, C",
+        source: None,
+        target: Some(InstructionTarget::C),
+        operation: Operation::BIT(6),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB72
+    Instruction {
+        mnemonic: "BIT 6, D",
+        source: None,
+        target: Some(InstructionTarget::D),
+        operation: Operation::BIT(6),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB73
+    Instruction {
+        mnemonic: "BIT 6, E",
+        source: None,
+        target: Some(InstructionTarget::E),
+        operation: Operation::BIT(6),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB74
+    Instruction {
+        mnemonic: "BIT 6, H",
+        source: None,
+        target: Some(InstructionTarget::H),
+        operation: Operation::BIT(6),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB75
+    Instruction {
+        mnemonic: "BIT 6, L",
+        source: None,
+        target: Some(InstructionTarget::L),
+        operation: Operation::BIT(6),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB76
+    Instruction {
+        mnemonic: "BIT 6, (HL)",
+        source: None,
+        target: Some(InstructionTarget::Ref(&InstructionTarget::HL)),
+        operation: Operation::BIT(6),
+        cycles: 12,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB77
+    Instruction {
+        mnemonic: "BIT 6, A",
+        source: None,
+        target: Some(InstructionTarget::A),
+        operation: Operation::BIT(6),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB78
+    Instruction {
+        mnemonic: "BIT 7, B",
+        source: None,
+        target: Some(InstructionTarget::B),
+        operation: Operation::BIT(7),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB79
+    Instruction {
+        mnemonic: "BIT 7, C",
+        source: None,
+        target: Some(InstructionTarget::C),
+        operation: Operation::BIT(7),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB7A
+    Instruction {
+        mnemonic: "BIT 7, D",
+        source: None,
+        target: Some(InstructionTarget::D),
+        operation: Operation::BIT(7),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB7B
+    Instruction {
+        mnemonic: "BIT 7, E",
+        source: None,
+        target: Some(InstructionTarget::E),
+        operation: Operation::BIT(7),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB7C
+    Instruction {
+        mnemonic: "BIT 7, H",
+        source: None,
+        target: Some(InstructionTarget::H),
+        operation: Operation::BIT(7),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB7D
+    Instruction {
+        mnemonic: "BIT 7, L",
+        source: None,
+        target: Some(InstructionTarget::L),
+        operation: Operation::BIT(7),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB7E
+    Instruction {
+        mnemonic: "BIT 7, (HL)",
+        source: None,
+        target: Some(InstructionTarget::Ref(&InstructionTarget::HL)),
+        operation: Operation::BIT(7),
+        cycles: 12,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB7F
+    Instruction {
+        mnemonic: "BIT 7, A",
+        source: None,
+        target: Some(InstructionTarget::A),
+        operation: Operation::BIT(7),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction {
+            zero: FlagOperation::Dependent,
+            subtract: FlagOperation::Unset,
+            half_carry: FlagOperation::Set,
+            carry: FlagOperation::Unmodified,
+        },
+    },
+    // 0xCB80
+    Instruction {
+        mnemonic: "RES 0, B",
+        source: None,
+        target: Some(InstructionTarget::B),
+        operation: Operation::RES(0),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCB81
+    Instruction {
+        mnemonic: "RES 0, C",
+        source: None,
+        target: Some(InstructionTarget::C),
+        operation: Operation::RES(0),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCB82
+    Instruction {
+        mnemonic: "RES 0, D",
+        source: None,
+        target: Some(InstructionTarget::D),
+        operation: Operation::RES(0),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCB83
+    Instruction {
+        mnemonic: "RES 0, E",
+        source: None,
+        target: Some(InstructionTarget::E),
+        operation: Operation::RES(0),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCB84
+    Instruction {
+        mnemonic: "RES 0, H",
+        source: None,
+        target: Some(InstructionTarget::H),
+        operation: Operation::RES(0),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCB85
+    Instruction {
+        mnemonic: "RES 0, L",
+        source: None,
+        target: Some(InstructionTarget::L),
+        operation: Operation::RES(0),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCB86
+    Instruction {
+        mnemonic: "RES 0, (HL)",
+        source: None,
+        target: Some(InstructionTarget::Ref(&InstructionTarget::HL)),
+        operation: Operation::RES(0),
+        cycles: 16,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCB87
+    Instruction {
+        mnemonic: "RES 0, A",
+        source: None,
+        target: Some(InstructionTarget::A),
+        operation: Operation::RES(0),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCB88
+    Instruction {
+        mnemonic: "RES 1, B",
+        source: None,
+        target: Some(InstructionTarget::B),
+        operation: Operation::RES(1),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCB89
+    Instruction {
+        mnemonic: "RES 1, C",
+        source: None,
+        target: Some(InstructionTarget::C),
+        operation: Operation::RES(1),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCB8A
+    Instruction {
+        mnemonic: "RES 1, D",
+        source: None,
+        target: Some(InstructionTarget::D),
+        operation: Operation::RES(1),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCB8B
+    Instruction {
+        mnemonic: "RES 1, E",
+        source: None,
+        target: Some(InstructionTarget::E),
+        operation: Operation::RES(1),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCB8C
+    Instruction {
+        mnemonic: "RES 1, H",
+        source: None,
+        target: Some(InstructionTarget::H),
+        operation: Operation::RES(1),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCB8D
+    Instruction {
+        mnemonic: "RES 1, L",
+        source: None,
+        target: Some(InstructionTarget::L),
+        operation: Operation::RES(1),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCB8E
+    Instruction {
+        mnemonic: "RES 1, (HL)",
+        source: None,
+        target: Some(InstructionTarget::Ref(&InstructionTarget::HL)),
+        operation: Operation::RES(1),
+        cycles: 16,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCB8F
+    Instruction {
+        mnemonic: "RES 1, A",
+        source: None,
+        target: Some(InstructionTarget::A),
+        operation: Operation::RES(1),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCB90
+    Instruction {
+        mnemonic: "RES 2, B",
+        source: None,
+        target: Some(InstructionTarget::B),
+        operation: Operation::RES(2),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCB91
+    Instruction {
+        mnemonic: "RES 2, C",
+        source: None,
+        target: Some(InstructionTarget::C),
+        operation: Operation::RES(2),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCB92
+    Instruction {
+        mnemonic: "RES 2, D",
+        source: None,
+        target: Some(InstructionTarget::D),
+        operation: Operation::RES(2),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCB93
+    Instruction {
+        mnemonic: "RES 2, E",
+        source: None,
+        target: Some(InstructionTarget::E),
+        operation: Operation::RES(2),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCB94
+    Instruction {
+        mnemonic: "RES 2, H",
+        source: None,
+        target: Some(InstructionTarget::H),
+        operation: Operation::RES(2),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCB95
+    Instruction {
+        mnemonic: "RES 2, L",
+        source: None,
+        target: Some(InstructionTarget::L),
+        operation: Operation::RES(2),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCB96
+    Instruction {
+        mnemonic: "RES 2, (HL)",
+        source: None,
+        target: Some(InstructionTarget::Ref(&InstructionTarget::HL)),
+        operation: Operation::RES(2),
+        cycles: 16,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCB97
+    Instruction {
+        mnemonic: "RES 2, A",
+        source: None,
+        target: Some(InstructionTarget::A),
+        operation: Operation::RES(2),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCB98
+    Instruction {
+        mnemonic: "RES 3, B",
+        source: None,
+        target: Some(InstructionTarget::B),
+        operation: Operation::RES(3),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCB99
+    Instruction {
+        mnemonic: "RES 3, C",
+        source: None,
+        target: Some(InstructionTarget::C),
+        operation: Operation::RES(3),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCB9A
+    Instruction {
+        mnemonic: "RES 3, D",
+        source: None,
+        target: Some(InstructionTarget::D),
+        operation: Operation::RES(3),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCB9B
+    Instruction {
+        mnemonic: "RES 3, E",
+        source: None,
+        target: Some(InstructionTarget::E),
+        operation: Operation::RES(3),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCB9C
+    Instruction {
+        mnemonic: "RES 3, H",
+        source: None,
+        target: Some(InstructionTarget::H),
+        operation: Operation::RES(3),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCB9D
+    Instruction {
+        mnemonic: "RES 3, L",
+        source: None,
+        target: Some(InstructionTarget::L),
+        operation: Operation::RES(3),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCB9E
+    Instruction {
+        mnemonic: "RES 3, (HL)",
+        source: None,
+        target: Some(InstructionTarget::Ref(&InstructionTarget::HL)),
+        operation: Operation::RES(3),
+        cycles: 16,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCB9F
+    Instruction {
+        mnemonic: "RES 3, A",
+        source: None,
+        target: Some(InstructionTarget::A),
+        operation: Operation::RES(3),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBA0
+    Instruction {
+        mnemonic: "RES 4, B",
+        source: None,
+        target: Some(InstructionTarget::B),
+        operation: Operation::RES(4),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBA1
+    Instruction {
+        mnemonic: "RES 4, C",
+        source: None,
+        target: Some(InstructionTarget::C),
+        operation: Operation::RES(4),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBA2
+    Instruction {
+        mnemonic: "RES 4, D",
+        source: None,
+        target: Some(InstructionTarget::D),
+        operation: Operation::RES(4),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBA3
+    Instruction {
+        mnemonic: "RES 4, E",
+        source: None,
+        target: Some(InstructionTarget::E),
+        operation: Operation::RES(4),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBA4
+    Instruction {
+        mnemonic: "RES 4, H",
+        source: None,
+        target: Some(InstructionTarget::H),
+        operation: Operation::RES(4),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBA5
+    Instruction {
+        mnemonic: "RES 4, L",
+        source: None,
+        target: Some(InstructionTarget::L),
+        operation: Operation::RES(4),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBA6
+    Instruction {
+        mnemonic: "RES 4, (HL)",
+        source: None,
+        target: Some(InstructionTarget::Ref(&InstructionTarget::HL)),
+        operation: Operation::RES(4),
+        cycles: 16,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBA7
+    Instruction {
+        mnemonic: "RES 4, A",
+        source: None,
+        target: Some(InstructionTarget::A),
+        operation: Operation::RES(4),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBA8
+    Instruction {
+        mnemonic: "RES 5, B",
+        source: None,
+        target: Some(InstructionTarget::B),
+        operation: Operation::RES(5),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBA9
+    Instruction {
+        mnemonic: "RES 5, C",
+        source: None,
+        target: Some(InstructionTarget::C),
+        operation: Operation::RES(5),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBAA
+    Instruction {
+        mnemonic: "RES 5, D",
+        source: None,
+        target: Some(InstructionTarget::D),
+        operation: Operation::RES(5),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBAB
+    Instruction {
+        mnemonic: "RES 5, E",
+        source: None,
+        target: Some(InstructionTarget::E),
+        operation: Operation::RES(5),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBAC
+    Instruction {
+        mnemonic: "RES 5, H",
+        source: None,
+        target: Some(InstructionTarget::H),
+        operation: Operation::RES(5),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBAD
+    Instruction {
+        mnemonic: "RES 5, L",
+        source: None,
+        target: Some(InstructionTarget::L),
+        operation: Operation::RES(5),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBAE
+    Instruction {
+        mnemonic: "RES 5, (HL)",
+        source: None,
+        target: Some(InstructionTarget::Ref(&InstructionTarget::HL)),
+        operation: Operation::RES(5),
+        cycles: 16,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBAF
+    Instruction {
+        mnemonic: "RES 5, A",
+        source: None,
+        target: Some(InstructionTarget::A),
+        operation: Operation::RES(5),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBB0
+    Instruction {
+        mnemonic: "RES 6, B",
+        source: None,
+        target: Some(InstructionTarget::B),
+        operation: Operation::RES(6),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBB1
+    Instruction {
+        mnemonic: "RES 6, C",
+        source: None,
+        target: Some(InstructionTarget::C),
+        operation: Operation::RES(6),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBB2
+    Instruction {
+        mnemonic: "RES 6, D",
+        source: None,
+        target: Some(InstructionTarget::D),
+        operation: Operation::RES(6),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBB3
+    Instruction {
+        mnemonic: "RES 6, E",
+        source: None,
+        target: Some(InstructionTarget::E),
+        operation: Operation::RES(6),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBB4
+    Instruction {
+        mnemonic: "RES 6, H",
+        source: None,
+        target: Some(InstructionTarget::H),
+        operation: Operation::RES(6),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBB5
+    Instruction {
+        mnemonic: "RES 6, L",
+        source: None,
+        target: Some(InstructionTarget::L),
+        operation: Operation::RES(6),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBB6
+    Instruction {
+        mnemonic: "RES 6, (HL)",
+        source: None,
+        target: Some(InstructionTarget::Ref(&InstructionTarget::HL)),
+        operation: Operation::RES(6),
+        cycles: 16,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBB7
+    Instruction {
+        mnemonic: "RES 6, A",
+        source: None,
+        target: Some(InstructionTarget::A),
+        operation: Operation::RES(6),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBB8
+    Instruction {
+        mnemonic: "RES 7, B",
+        source: None,
+        target: Some(InstructionTarget::B),
+        operation: Operation::RES(7),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBB9
+    Instruction {
+        mnemonic: "RES 7, C",
+        source: None,
+        target: Some(InstructionTarget::C),
+        operation: Operation::RES(7),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBBA
+    Instruction {
+        mnemonic: "RES 7, D",
+        source: None,
+        target: Some(InstructionTarget::D),
+        operation: Operation::RES(7),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBBB
+    Instruction {
+        mnemonic: "RES 7, E",
+        source: None,
+        target: Some(InstructionTarget::E),
+        operation: Operation::RES(7),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBBC
+    Instruction {
+        mnemonic: "RES 7, H",
+        source: None,
+        target: Some(InstructionTarget::H),
+        operation: Operation::RES(7),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBBD
+    Instruction {
+        mnemonic: "RES 7, L",
+        source: None,
+        target: Some(InstructionTarget::L),
+        operation: Operation::RES(7),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBBE
+    Instruction {
+        mnemonic: "RES 7, (HL)",
+        source: None,
+        target: Some(InstructionTarget::Ref(&InstructionTarget::HL)),
+        operation: Operation::RES(7),
+        cycles: 16,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBBF
+    Instruction {
+        mnemonic: "RES 7, A",
+        source: None,
+        target: Some(InstructionTarget::A),
+        operation: Operation::RES(7),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBC0
+    Instruction {
+        mnemonic: "SET 0, B",
+        source: None,
+        target: Some(InstructionTarget::B),
+        operation: Operation::SET(0),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBC1
+    Instruction {
+        mnemonic: "SET 0, C",
+        source: None,
+        target: Some(InstructionTarget::C),
+        operation: Operation::SET(0),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBC2
+    Instruction {
+        mnemonic: "SET 0, D",
+        source: None,
+        target: Some(InstructionTarget::D),
+        operation: Operation::SET(0),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBC3
+    Instruction {
+        mnemonic: "SET 0, E",
+        source: None,
+        target: Some(InstructionTarget::E),
+        operation: Operation::SET(0),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBC4
+    Instruction {
+        mnemonic: "SET 0, H",
+        source: None,
+        target: Some(InstructionTarget::H),
+        operation: Operation::SET(0),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBC5
+    Instruction {
+        mnemonic: "SET 0, L",
+        source: None,
+        target: Some(InstructionTarget::L),
+        operation: Operation::SET(0),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBC6
+    Instruction {
+        mnemonic: "SET 0, (HL)",
+        source: None,
+        target: Some(InstructionTarget::Ref(&InstructionTarget::HL)),
+        operation: Operation::SET(0),
+        cycles: 16,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBC7
+    Instruction {
+        mnemonic: "SET 0, A",
+        source: None,
+        target: Some(InstructionTarget::A),
+        operation: Operation::SET(0),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBC8
+    Instruction {
+        mnemonic: "SET 1, B",
+        source: None,
+        target: Some(InstructionTarget::B),
+        operation: Operation::SET(1),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBC9
+    Instruction {
+        mnemonic: "SET 1, C",
+        source: None,
+        target: Some(InstructionTarget::C),
+        operation: Operation::SET(1),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBCA
+    Instruction {
+        mnemonic: "SET 1, D",
+        source: None,
+        target: Some(InstructionTarget::D),
+        operation: Operation::SET(1),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBCB
+    Instruction {
+        mnemonic: "SET 1, E",
+        source: None,
+        target: Some(InstructionTarget::E),
+        operation: Operation::SET(1),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBCC
+    Instruction {
+        mnemonic: "SET 1, H",
+        source: None,
+        target: Some(InstructionTarget::H),
+        operation: Operation::SET(1),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBCD
+    Instruction {
+        mnemonic: "SET 1, L",
+        source: None,
+        target: Some(InstructionTarget::L),
+        operation: Operation::SET(1),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBCE
+    Instruction {
+        mnemonic: "SET 1, (HL)",
+        source: None,
+        target: Some(InstructionTarget::Ref(&InstructionTarget::HL)),
+        operation: Operation::SET(1),
+        cycles: 16,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBCF
+    Instruction {
+        mnemonic: "SET 1, A",
+        source: None,
+        target: Some(InstructionTarget::A),
+        operation: Operation::SET(1),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBD0
+    Instruction {
+        mnemonic: "SET 2, B",
+        source: None,
+        target: Some(InstructionTarget::B),
+        operation: Operation::SET(2),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBD1
+    Instruction {
+        mnemonic: "SET 2, C",
+        source: None,
+        target: Some(InstructionTarget::C),
+        operation: Operation::SET(2),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBD2
+    Instruction {
+        mnemonic: "SET 2, D",
+        source: None,
+        target: Some(InstructionTarget::D),
+        operation: Operation::SET(2),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBD3
+    Instruction {
+        mnemonic: "SET 2, E",
+        source: None,
+        target: Some(InstructionTarget::E),
+        operation: Operation::SET(2),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBD4
+    Instruction {
+        mnemonic: "SET 2, H",
+        source: None,
+        target: Some(InstructionTarget::H),
+        operation: Operation::SET(2),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBD5
+    Instruction {
+        mnemonic: "SET 2, L",
+        source: None,
+        target: Some(InstructionTarget::L),
+        operation: Operation::SET(2),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBD6
+    Instruction {
+        mnemonic: "SET 2, (HL)",
+        source: None,
+        target: Some(InstructionTarget::Ref(&InstructionTarget::HL)),
+        operation: Operation::SET(2),
+        cycles: 16,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBD7
+    Instruction {
+        mnemonic: "SET 2, A",
+        source: None,
+        target: Some(InstructionTarget::A),
+        operation: Operation::SET(2),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBD8
+    Instruction {
+        mnemonic: "SET 3, B",
+        source: None,
+        target: Some(InstructionTarget::B),
+        operation: Operation::SET(3),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBD9
+    Instruction {
+        mnemonic: "SET 3, C",
+        source: None,
+        target: Some(InstructionTarget::C),
+        operation: Operation::SET(3),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBDA
+    Instruction {
+        mnemonic: "SET 3, D",
+        source: None,
+        target: Some(InstructionTarget::D),
+        operation: Operation::SET(3),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBDB
+    Instruction {
+        mnemonic: "SET 3, E",
+        source: None,
+        target: Some(InstructionTarget::E),
+        operation: Operation::SET(3),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBDC
+    Instruction {
+        mnemonic: "SET 3, H",
+        source: None,
+        target: Some(InstructionTarget::H),
+        operation: Operation::SET(3),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBDD
+    Instruction {
+        mnemonic: "SET 3, L",
+        source: None,
+        target: Some(InstructionTarget::L),
+        operation: Operation::SET(3),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBDE
+    Instruction {
+        mnemonic: "SET 3, (HL)",
+        source: None,
+        target: Some(InstructionTarget::Ref(&InstructionTarget::HL)),
+        operation: Operation::SET(3),
+        cycles: 16,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBDF
+    Instruction {
+        mnemonic: "SET 3, A",
+        source: None,
+        target: Some(InstructionTarget::A),
+        operation: Operation::SET(3),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBE0
+    Instruction {
+        mnemonic: "SET 4, B",
+        source: None,
+        target: Some(InstructionTarget::B),
+        operation: Operation::SET(4),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBE1
+    Instruction {
+        mnemonic: "SET 4, C",
+        source: None,
+        target: Some(InstructionTarget::C),
+        operation: Operation::SET(4),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBE2
+    Instruction {
+        mnemonic: "SET 4, D",
+        source: None,
+        target: Some(InstructionTarget::D),
+        operation: Operation::SET(4),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBE3
+    Instruction {
+        mnemonic: "SET 4, E",
+        source: None,
+        target: Some(InstructionTarget::E),
+        operation: Operation::SET(4),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBE4
+    Instruction {
+        mnemonic: "SET 4, H",
+        source: None,
+        target: Some(InstructionTarget::H),
+        operation: Operation::SET(4),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBE5
+    Instruction {
+        mnemonic: "SET 4, L",
+        source: None,
+        target: Some(InstructionTarget::L),
+        operation: Operation::SET(4),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBE6
+    Instruction {
+        mnemonic: "SET 4, (HL)",
+        source: None,
+        target: Some(InstructionTarget::Ref(&InstructionTarget::HL)),
+        operation: Operation::SET(4),
+        cycles: 16,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBE7
+    Instruction {
+        mnemonic: "SET 4, A",
+        source: None,
+        target: Some(InstructionTarget::A),
+        operation: Operation::SET(4),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBE8
+    Instruction {
+        mnemonic: "SET 5, B",
+        source: None,
+        target: Some(InstructionTarget::B),
+        operation: Operation::SET(5),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBE9
+    Instruction {
+        mnemonic: "SET 5, C",
+        source: None,
+        target: Some(InstructionTarget::C),
+        operation: Operation::SET(5),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBEA
+    Instruction {
+        mnemonic: "SET 5, D",
+        source: None,
+        target: Some(InstructionTarget::D),
+        operation: Operation::SET(5),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBEB
+    Instruction {
+        mnemonic: "SET 5, E",
+        source: None,
+        target: Some(InstructionTarget::E),
+        operation: Operation::SET(5),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBEC
+    Instruction {
+        mnemonic: "SET 5, H",
+        source: None,
+        target: Some(InstructionTarget::H),
+        operation: Operation::SET(5),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBED
+    Instruction {
+        mnemonic: "SET 5, L",
+        source: None,
+        target: Some(InstructionTarget::L),
+        operation: Operation::SET(5),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBEE
+    Instruction {
+        mnemonic: "SET 5, (HL)",
+        source: None,
+        target: Some(InstructionTarget::Ref(&InstructionTarget::HL)),
+        operation: Operation::SET(5),
+        cycles: 16,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBEF
+    Instruction {
+        mnemonic: "SET 5, A",
+        source: None,
+        target: Some(InstructionTarget::A),
+        operation: Operation::SET(5),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBF0
+    Instruction {
+        mnemonic: "SET 6, B",
+        source: None,
+        target: Some(InstructionTarget::B),
+        operation: Operation::SET(6),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBF1
+    Instruction {
+        mnemonic: "SET 6, C",
+        source: None,
+        target: Some(InstructionTarget::C),
+        operation: Operation::SET(6),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBF2
+    Instruction {
+        mnemonic: "SET 6, D",
+        source: None,
+        target: Some(InstructionTarget::D),
+        operation: Operation::SET(6),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBF3
+    Instruction {
+        mnemonic: "SET 6, E",
+        source: None,
+        target: Some(InstructionTarget::E),
+        operation: Operation::SET(6),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBF4
+    Instruction {
+        mnemonic: "SET 6, H",
+        source: None,
+        target: Some(InstructionTarget::H),
+        operation: Operation::SET(6),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBF5
+    Instruction {
+        mnemonic: "SET 6, L",
+        source: None,
+        target: Some(InstructionTarget::L),
+        operation: Operation::SET(6),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBF6
+    Instruction {
+        mnemonic: "SET 6, (HL)",
+        source: None,
+        target: Some(InstructionTarget::Ref(&InstructionTarget::HL)),
+        operation: Operation::SET(6),
+        cycles: 16,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBF7
+    Instruction {
+        mnemonic: "SET 6, A",
+        source: None,
+        target: Some(InstructionTarget::A),
+        operation: Operation::SET(6),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBF8
+    Instruction {
+        mnemonic: "SET 7, B",
+        source: None,
+        target: Some(InstructionTarget::B),
+        operation: Operation::SET(7),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBF9
+    Instruction {
+        mnemonic: "SET 7, C",
+        source: None,
+        target: Some(InstructionTarget::C),
+        operation: Operation::SET(7),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBFA
+    Instruction {
+        mnemonic: "SET 7, D",
+        source: None,
+        target: Some(InstructionTarget::D),
+        operation: Operation::SET(7),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBFB
+    Instruction {
+        mnemonic: "SET 7, E",
+        source: None,
+        target: Some(InstructionTarget::E),
+        operation: Operation::SET(7),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBFC
+    Instruction {
+        mnemonic: "SET 7, H",
+        source: None,
+        target: Some(InstructionTarget::H),
+        operation: Operation::SET(7),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBFD
+    Instruction {
+        mnemonic: "SET 7, L",
+        source: None,
+        target: Some(InstructionTarget::L),
+        operation: Operation::SET(7),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBFE
+    Instruction {
+        mnemonic: "SET 7, (HL)",
+        source: None,
+        target: Some(InstructionTarget::Ref(&InstructionTarget::HL)),
+        operation: Operation::SET(7),
+        cycles: 16,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+    // 0xCBFF
+    Instruction {
+        mnemonic: "SET 7, A",
+        source: None,
+        target: Some(InstructionTarget::A),
+        operation: Operation::SET(7),
+        cycles: 8,
+        length: 2,
+        flags: FlagInstruction::UNMODIFIED,
+    },
+];
@@ -1,7 +1,16 @@
+use std::ops::RangeInclusive;
+
+use crate::memory::Bus;
 use crate::registers::{Flag, Registers};
 
+use super::single_step::TestBus;
 use super::*;
 
+// Run an instruction on a flat bus that isn't ticked until the instruction has finished
+fn execute_instruction(code: u16, reg: &mut Registers, mem: &mut TestBus) -> Result<u8, CpuError> {
+    super::execute_instruction(code, reg, &mut TimedBus::new(mem, false))
+}
+
 #[test]
 fn test_modify_flags() {
     let mut reg = Registers::default();
@@ -22,7 +31,7 @@ fn test_modify_flags() {
     reg.set_flag(Flag::Zero, true);
     reg.set_flag(Flag::Subtract, true);
 
-    modify_flags(&mut reg, instr, results);
+    modify_flags(&mut reg, instr, results).unwrap();
 
     assert_eq!(false, reg.get_flag(Flag::Zero));
     assert_eq!(false, reg.get_flag(Flag::Subtract));
@@ -30,36 +39,76 @@ fn test_modify_flags() {
     assert_eq!(false, reg.get_flag(Flag::Carry));
 }
 
+#[test]
+fn test_get_x8_invalid_operand() {
+    let mut mem = TestBus::default();
+    let reg = Registers::default();
+
+    let result = get_x8(
+        &InstructionTarget::BC,
+        &reg,
+        &mut TimedBus::new(&mut mem, false),
+    );
+
+    assert!(matches!(result, Err(CpuError::InvalidOperand(_))));
+}
+
+#[test]
+fn test_modify_flags_missing_result() {
+    let mut reg = Registers::default();
+
+    let instr = FlagInstruction {
+        zero: FlagOperation::Dependent,
+        ..Default::default()
+    };
+
+    let result = modify_flags(&mut reg, instr, FlagResults::default());
+
+    assert!(matches!(result, Err(CpuError::DecodeFault(_))));
+}
+
 #[test]
 fn test_get_x8_n8() {
-    let mut mem = MemoryBus::default();
+    let mut mem = TestBus::default();
     let mut reg = Registers::default();
 
     let addr = 0x0000;
     let data = 0xAA;
     mem.write(addr, data);
+    // Immediates are read from the end of the instruction, so point PC just past the operand
+    reg.pc = addr + 1;
 
-    let n8 = get_x8(&InstructionTarget::N8(addr), &mut reg, &mut mem);
+    let n8 = get_x8(
+        &InstructionTarget::N8,
+        &reg,
+        &mut TimedBus::new(&mut mem, false),
+    )
+    .unwrap();
 
     assert_eq!(data, n8);
 }
 
 #[test]
 fn test_get_x8_reg() {
-    let mut mem = MemoryBus::default();
+    let mut mem = TestBus::default();
     let mut reg = Registers::default();
 
     let data = 0xAA;
     reg.set_reg8(Register::B, data);
 
-    let b = get_x8(&InstructionTarget::B, &mut reg, &mut mem);
+    let b = get_x8(
+        &InstructionTarget::B,
+        &reg,
+        &mut TimedBus::new(&mut mem, false),
+    )
+    .unwrap();
 
     assert_eq!(data, b);
 }
 
 #[test]
 fn test_get_x8_addr_from_reg() {
-    let mut mem = MemoryBus::default();
+    let mut mem = TestBus::default();
     let mut reg = Registers::default();
 
     let addr: Address = 0x0000;
@@ -71,23 +120,31 @@ fn test_get_x8_addr_from_reg() {
     mem.write(addr, data);
 
     let addr_hl = get_x8(
-        &InstructionTarget::N8(reg.get_reg16(RegisterPair::HL)),
-        &mut reg,
-        &mut mem,
-    );
+        &InstructionTarget::Ref(&InstructionTarget::HL),
+        &reg,
+        &mut TimedBus::new(&mut mem, false),
+    )
+    .unwrap();
 
     assert_eq!(data, addr_hl);
 }
 
 #[test]
 fn test_set_x8_n8() {
-    let mut mem = MemoryBus::default();
+    let mut mem = TestBus::default();
     let mut reg = Registers::default();
 
     let addr = 0x0000;
     let data = 0xAA;
+    reg.pc = addr + 1;
 
-    set_x8(&InstructionTarget::N8(addr), &mut reg, &mut mem, data);
+    set_x8(
+        &InstructionTarget::N8,
+        &mut reg,
+        &mut TimedBus::new(&mut mem, false),
+        data,
+    )
+    .unwrap();
 
     let n8 = mem.read(addr);
 
@@ -96,12 +153,18 @@ fn test_set_x8_n8() {
 
 #[test]
 fn test_set_x8_reg() {
-    let mut mem = MemoryBus::default();
+    let mut mem = TestBus::default();
     let mut reg = Registers::default();
 
     let data = 0xAA;
 
-    set_x8(&InstructionTarget::B, &mut reg, &mut mem, data);
+    set_x8(
+        &InstructionTarget::B,
+        &mut reg,
+        &mut TimedBus::new(&mut mem, false),
+        data,
+    )
+    .unwrap();
 
     let u8 = reg.get_reg8(Register::B);
 
@@ -110,7 +173,7 @@ fn test_set_x8_reg() {
 
 #[test]
 fn test_set_x8_addr_from_reg() {
-    let mut mem = MemoryBus::default();
+    let mut mem = TestBus::default();
     let mut reg = Registers::default();
 
     let addr: Address = 0x0000;
@@ -121,11 +184,12 @@ fn test_set_x8_addr_from_reg() {
     reg.set_reg8(Register::L, addr_bytes[1]);
 
     set_x8(
-        &InstructionTarget::Ref(Box::new(InstructionTarget::HL)),
+        &InstructionTarget::Ref(&InstructionTarget::HL),
         &mut reg,
-        &mut mem,
+        &mut TimedBus::new(&mut mem, false),
         data,
-    );
+    )
+    .unwrap();
 
     let addr_hl = mem.read(addr);
 
@@ -134,7 +198,7 @@ fn test_set_x8_addr_from_reg() {
 
 #[test]
 fn test_get_x16_n16() {
-    let mut mem = MemoryBus::default();
+    let mut mem = TestBus::default();
     let mut reg = Registers::default();
 
     let addr = 0x0000;
@@ -142,28 +206,39 @@ fn test_get_x16_n16() {
     let bytes = data.to_le_bytes();
     mem.write(addr, bytes[0]);
     mem.write(addr + 1, bytes[1]);
+    reg.pc = addr + 2;
 
-    let n16 = get_x16(&InstructionTarget::N16(addr), &mut reg, &mut mem);
+    let n16 = get_x16(
+        &InstructionTarget::N16,
+        &reg,
+        &mut TimedBus::new(&mut mem, false),
+    )
+    .unwrap();
 
     assert_eq!(data, n16);
 }
 
 #[test]
 fn test_get_x16_reg() {
-    let mut mem = MemoryBus::default();
+    let mut mem = TestBus::default();
     let mut reg = Registers::default();
 
     let data: u16 = 0xAABB;
     reg.set_reg16(RegisterPair::BC, data);
 
-    let bc = get_x16(&InstructionTarget::BC, &mut reg, &mut mem);
+    let bc = get_x16(
+        &InstructionTarget::BC,
+        &reg,
+        &mut TimedBus::new(&mut mem, false),
+    )
+    .unwrap();
 
     assert_eq!(data, bc);
 }
 
 #[test]
 fn test_get_x16_ref() {
-    let mut mem = MemoryBus::default();
+    let mut mem = TestBus::default();
     let mut reg = Registers::default();
 
     let addr: Address = 0x0000;
@@ -175,23 +250,31 @@ fn test_get_x16_ref() {
     mem.write(addr + 1, bytes[1]);
 
     let result = get_x16(
-        &InstructionTarget::Ref(Box::new(InstructionTarget::HL)),
-        &mut reg,
-        &mut mem,
-    );
+        &InstructionTarget::Ref(&InstructionTarget::HL),
+        &reg,
+        &mut TimedBus::new(&mut mem, false),
+    )
+    .unwrap();
 
     assert_eq!(data, result);
 }
 
 #[test]
 fn test_set_x16_n16() {
-    let mut mem = MemoryBus::default();
+    let mut mem = TestBus::default();
     let mut reg = Registers::default();
 
     let addr = 0x0000;
     let data: u16 = 0xAABB;
+    reg.pc = addr + 2;
 
-    set_x16(&InstructionTarget::N16(addr), &mut reg, &mut mem, data);
+    set_x16(
+        &InstructionTarget::N16,
+        &mut reg,
+        &mut TimedBus::new(&mut mem, false),
+        data,
+    )
+    .unwrap();
 
     let n16 = u16::from_le_bytes([mem.read(addr), mem.read(addr + 1)]);
 
@@ -200,12 +283,18 @@ fn test_set_x16_n16() {
 
 #[test]
 fn test_set_x16_reg() {
-    let mut mem = MemoryBus::default();
+    let mut mem = TestBus::default();
     let mut reg = Registers::default();
 
     let data: u16 = 0xAABB;
 
-    set_x16(&InstructionTarget::BC, &mut reg, &mut mem, data);
+    set_x16(
+        &InstructionTarget::BC,
+        &mut reg,
+        &mut TimedBus::new(&mut mem, false),
+        data,
+    )
+    .unwrap();
 
     let n16 = u16::from_be_bytes([reg.get_reg8(Register::B), reg.get_reg8(Register::C)]);
 
@@ -214,7 +303,7 @@ fn test_set_x16_reg() {
 
 #[test]
 fn test_set_x16_ref() {
-    let mut mem = MemoryBus::default();
+    let mut mem = TestBus::default();
     let mut reg = Registers::default();
 
     let addr: Address = 0x0000;
@@ -225,11 +314,12 @@ fn test_set_x16_ref() {
     reg.set_reg8(Register::L, addr_bytes[1]);
 
     set_x16(
-        &InstructionTarget::Ref(Box::new(InstructionTarget::HL)),
+        &InstructionTarget::Ref(&InstructionTarget::HL),
         &mut reg,
-        &mut mem,
+        &mut TimedBus::new(&mut mem, false),
         data,
-    );
+    )
+    .unwrap();
 
     let result = u16::from_le_bytes([mem.read(addr), mem.read(addr + 1)]);
 
@@ -238,13 +328,13 @@ fn test_set_x16_ref() {
 
 #[test]
 fn test_0x00_NOP() {
-    let base_mem = MemoryBus::default();
+    let base_mem = TestBus::default();
     let mut out_mem = base_mem.clone();
     let mut base_reg = Registers::default();
-    let mut out_reg = base_reg.clone();
+    let mut out_reg = base_reg;
 
     base_reg.pc += 1;
-    let _ = execute_instruction(0x00, &mut out_reg, &mut out_mem);
+    execute_instruction(0x00, &mut out_reg, &mut out_mem).unwrap();
 
     assert_eq!(base_mem, out_mem);
     assert_eq!(base_reg, out_reg);
@@ -252,7 +342,7 @@ fn test_0x00_NOP() {
 
 #[test]
 fn test_0x01_LD_BC_u16() {
-    let mut mem = MemoryBus::default();
+    let mut mem = TestBus::default();
     let mut reg = Registers::default();
 
     // Populate the memory with a test value
@@ -262,14 +352,14 @@ fn test_0x01_LD_BC_u16() {
     mem.write(addr, split_data.1);
     mem.write(addr + 1, split_data.0);
 
-    let _ = execute_instruction(0x01, &mut reg, &mut mem);
+    execute_instruction(0x01, &mut reg, &mut mem).unwrap();
 
     assert_eq!(data, reg.get_reg16(RegisterPair::BC));
 }
 
 #[test]
 fn test_0x02_LD_BC_A() {
-    let mut mem = MemoryBus::default();
+    let mut mem = TestBus::default();
     let mut reg = Registers::default();
 
     let data: u8 = 0x12;
@@ -280,33 +370,33 @@ fn test_0x02_LD_BC_A() {
     reg.set_reg8(Register::B, split_addr[0]);
     reg.set_reg8(Register::C, split_addr[1]);
 
-    let _ = execute_instruction(0x02, &mut reg, &mut mem);
+    execute_instruction(0x02, &mut reg, &mut mem).unwrap();
 
     assert_eq!(data, mem.read(addr));
 }
 
 #[test]
 fn test_0x03_INC_BC() {
-    let mut mem = MemoryBus::default();
+    let mut mem = TestBus::default();
     let mut reg = Registers::default();
 
     let data = 0x1234;
     reg.set_reg16(RegisterPair::BC, data);
 
-    let _ = execute_instruction(0x03, &mut reg, &mut mem);
+    execute_instruction(0x03, &mut reg, &mut mem).unwrap();
 
     assert_eq!(data + 1, reg.get_reg16(RegisterPair::BC));
 }
 
 #[test]
 fn test_0x04_INC_B() {
-    let mut mem = MemoryBus::default();
+    let mut mem = TestBus::default();
     let mut reg = Registers::default();
 
     let data = 0x12;
     reg.set_reg8(Register::B, data);
 
-    let _ = execute_instruction(0x04, &mut reg, &mut mem);
+    execute_instruction(0x04, &mut reg, &mut mem).unwrap();
 
     assert_eq!(data + 1, reg.get_reg8(Register::B));
     assert_eq!(false, reg.get_flag(Flag::Zero));
@@ -317,7 +407,7 @@ fn test_0x04_INC_B() {
     let data = 0x1F;
     reg.set_reg8(Register::B, data);
 
-    let _ = execute_instruction(0x04, &mut reg, &mut mem);
+    execute_instruction(0x04, &mut reg, &mut mem).unwrap();
 
     assert_eq!(data + 1, reg.get_reg8(Register::B));
     assert_eq!(false, reg.get_flag(Flag::Zero));
@@ -328,24 +418,24 @@ fn test_0x04_INC_B() {
 
 #[test]
 fn test_0x05_DEC_B() {
-    let mut mem = MemoryBus::default();
+    let mut mem = TestBus::default();
     let mut reg = Registers::default();
 
     let data = 0x20;
     reg.set_reg8(Register::B, data);
 
-    let _ = execute_instruction(0x05, &mut reg, &mut mem);
+    execute_instruction(0x05, &mut reg, &mut mem).unwrap();
 
     assert_eq!(data - 1, reg.get_reg8(Register::B));
     assert_eq!(false, reg.get_flag(Flag::Zero));
     assert_eq!(true, reg.get_flag(Flag::Subtract));
-    assert_eq!(false, reg.get_flag(Flag::HalfCarry));
+    assert_eq!(true, reg.get_flag(Flag::HalfCarry));
     assert_eq!(false, reg.get_flag(Flag::Carry));
 
     let data = 0x10;
     reg.set_reg8(Register::B, data);
 
-    let _ = execute_instruction(0x05, &mut reg, &mut mem);
+    execute_instruction(0x05, &mut reg, &mut mem).unwrap();
 
     assert_eq!(data - 1, reg.get_reg8(Register::B));
     assert_eq!(false, reg.get_flag(Flag::Zero));
@@ -356,7 +446,7 @@ fn test_0x05_DEC_B() {
     let data = 0x01;
     reg.set_reg8(Register::B, data);
 
-    let _ = execute_instruction(0x05, &mut reg, &mut mem);
+    execute_instruction(0x05, &mut reg, &mut mem).unwrap();
 
     assert_eq!(data - 1, reg.get_reg8(Register::B));
     assert_eq!(true, reg.get_flag(Flag::Zero));
@@ -367,20 +457,20 @@ fn test_0x05_DEC_B() {
 
 #[test]
 fn test_0x06_LD_B_u8() {
-    let mut mem = MemoryBus::default();
+    let mut mem = TestBus::default();
     let mut reg = Registers::default();
 
     let data = 0x12;
     mem.write(reg.pc + 1, data);
 
-    let _ = execute_instruction(0x06, &mut reg, &mut mem);
+    execute_instruction(0x06, &mut reg, &mut mem).unwrap();
 
     assert_eq!(data, reg.get_reg8(Register::B));
 }
 
 #[test]
 fn test_0x07_RLCA() {
-    let mut mem = MemoryBus::default();
+    let mut mem = TestBus::default();
     let mut reg = Registers::default();
 
     let data = 0b1011_1010;
@@ -391,7 +481,7 @@ fn test_0x07_RLCA() {
     reg.set_flag(Flag::Subtract, true);
     reg.set_flag(Flag::HalfCarry, true);
 
-    let _ = execute_instruction(0x07, &mut reg, &mut mem);
+    execute_instruction(0x07, &mut reg, &mut mem).unwrap();
 
     assert_eq!(expected, reg.get_reg8(Register::A));
     assert_eq!(false, reg.get_flag(Flag::Zero));
@@ -402,7 +492,7 @@ fn test_0x07_RLCA() {
 
 #[test]
 fn test_0x08_LD_u16_SP() {
-    let mut mem = MemoryBus::default();
+    let mut mem = TestBus::default();
     let mut reg = Registers::default();
 
     let addr: u16 = 0x1000;
@@ -414,7 +504,7 @@ fn test_0x08_LD_u16_SP() {
     mem.write(pc + 1, addr_bytes[0]);
     mem.write(pc + 2, addr_bytes[1]);
 
-    let _ = execute_instruction(0x08, &mut reg, &mut mem);
+    execute_instruction(0x08, &mut reg, &mut mem).unwrap();
 
     assert_eq!(mem.read(addr), data as u8);
     assert_eq!(mem.read(addr + 1), (data >> 8) as u8);
@@ -422,7 +512,7 @@ fn test_0x08_LD_u16_SP() {
 
 #[test]
 fn test_0x09_ADD_HL_BC() {
-    let mut mem = MemoryBus::default();
+    let mut mem = TestBus::default();
     let mut reg = Registers::default();
 
     let bc = 0x0800;
@@ -431,7 +521,7 @@ fn test_0x09_ADD_HL_BC() {
     reg.set_reg16(RegisterPair::BC, bc);
     reg.set_reg16(RegisterPair::HL, hl);
 
-    let _ = execute_instruction(0x09, &mut reg, &mut mem);
+    execute_instruction(0x09, &mut reg, &mut mem).unwrap();
 
     let result = reg.get_reg16(RegisterPair::HL);
 
@@ -447,7 +537,7 @@ fn test_0x09_ADD_HL_BC() {
     reg.set_reg16(RegisterPair::BC, bc);
     reg.set_reg16(RegisterPair::HL, hl);
 
-    let _ = execute_instruction(0x09, &mut reg, &mut mem);
+    execute_instruction(0x09, &mut reg, &mut mem).unwrap();
 
     let result = reg.get_reg16(RegisterPair::HL);
 
@@ -460,7 +550,7 @@ fn test_0x09_ADD_HL_BC() {
 
 #[test]
 fn test_0x0A_LD_A_BC() {
-    let mut mem = MemoryBus::default();
+    let mut mem = TestBus::default();
     let mut reg = Registers::default();
 
     let addr = 0x0101;
@@ -469,33 +559,33 @@ fn test_0x0A_LD_A_BC() {
     mem.write(addr, data);
     reg.set_reg16(RegisterPair::BC, addr);
 
-    let _ = execute_instruction(0x0A, &mut reg, &mut mem);
+    execute_instruction(0x0A, &mut reg, &mut mem).unwrap();
 
     assert_eq!(data, reg.get_reg8(Register::A));
 }
 
 #[test]
 fn test_0x0B_DEC_BC() {
-    let mut mem = MemoryBus::default();
+    let mut mem = TestBus::default();
     let mut reg = Registers::default();
 
     let data = 0x1234;
     reg.set_reg16(RegisterPair::BC, data);
 
-    let _ = execute_instruction(0x0B, &mut reg, &mut mem);
+    execute_instruction(0x0B, &mut reg, &mut mem).unwrap();
 
     assert_eq!(data - 1, reg.get_reg16(RegisterPair::BC));
 }
 
 #[test]
 fn test_0x0C_INC_C() {
-    let mut mem = MemoryBus::default();
+    let mut mem = TestBus::default();
     let mut reg = Registers::default();
 
     let data = 0x12;
     reg.set_reg8(Register::C, data);
 
-    let _ = execute_instruction(0x0C, &mut reg, &mut mem);
+    execute_instruction(0x0C, &mut reg, &mut mem).unwrap();
 
     assert_eq!(data + 1, reg.get_reg8(Register::C));
     assert_eq!(false, reg.get_flag(Flag::Zero));
@@ -506,7 +596,7 @@ fn test_0x0C_INC_C() {
     let data = 0x1F;
     reg.set_reg8(Register::C, data);
 
-    let _ = execute_instruction(0x0C, &mut reg, &mut mem);
+    execute_instruction(0x0C, &mut reg, &mut mem).unwrap();
 
     assert_eq!(data + 1, reg.get_reg8(Register::C));
     assert_eq!(false, reg.get_flag(Flag::Zero));
@@ -517,24 +607,24 @@ fn test_0x0C_INC_C() {
 
 #[test]
 fn test_0x0D_DEC_C() {
-    let mut mem = MemoryBus::default();
+    let mut mem = TestBus::default();
     let mut reg = Registers::default();
 
     let data = 0x20;
     reg.set_reg8(Register::C, data);
 
-    let _ = execute_instruction(0x0D, &mut reg, &mut mem);
+    execute_instruction(0x0D, &mut reg, &mut mem).unwrap();
 
     assert_eq!(data - 1, reg.get_reg8(Register::C));
     assert_eq!(false, reg.get_flag(Flag::Zero));
     assert_eq!(true, reg.get_flag(Flag::Subtract));
-    assert_eq!(false, reg.get_flag(Flag::HalfCarry));
+    assert_eq!(true, reg.get_flag(Flag::HalfCarry));
     assert_eq!(false, reg.get_flag(Flag::Carry));
 
     let data = 0x10;
     reg.set_reg8(Register::C, data);
 
-    let _ = execute_instruction(0x0D, &mut reg, &mut mem);
+    execute_instruction(0x0D, &mut reg, &mut mem).unwrap();
 
     assert_eq!(data - 1, reg.get_reg8(Register::C));
     assert_eq!(false, reg.get_flag(Flag::Zero));
@@ -545,7 +635,7 @@ fn test_0x0D_DEC_C() {
     let data = 0x01;
     reg.set_reg8(Register::C, data);
 
-    let _ = execute_instruction(0x0D, &mut reg, &mut mem);
+    execute_instruction(0x0D, &mut reg, &mut mem).unwrap();
 
     assert_eq!(data - 1, reg.get_reg8(Register::C));
     assert_eq!(true, reg.get_flag(Flag::Zero));
@@ -556,20 +646,20 @@ fn test_0x0D_DEC_C() {
 
 #[test]
 fn test_0x0E_LD_C_u8() {
-    let mut mem = MemoryBus::default();
+    let mut mem = TestBus::default();
     let mut reg = Registers::default();
 
     let data = 0x12;
     mem.write(reg.pc + 1, data);
 
-    let _ = execute_instruction(0x0E, &mut reg, &mut mem);
+    execute_instruction(0x0E, &mut reg, &mut mem).unwrap();
 
     assert_eq!(data, reg.get_reg8(Register::C));
 }
 
 #[test]
 fn test_0x0F_RRCA() {
-    let mut mem = MemoryBus::default();
+    let mut mem = TestBus::default();
     let mut reg = Registers::default();
 
     let data = 0b1011_1011;
@@ -580,7 +670,7 @@ fn test_0x0F_RRCA() {
     reg.set_flag(Flag::Subtract, true);
     reg.set_flag(Flag::HalfCarry, true);
 
-    let _ = execute_instruction(0x0F, &mut reg, &mut mem);
+    execute_instruction(0x0F, &mut reg, &mut mem).unwrap();
 
     assert_eq!(expected, reg.get_reg8(Register::A));
     assert_eq!(false, reg.get_flag(Flag::Zero));
@@ -588,3 +678,783 @@ fn test_0x0F_RRCA() {
     assert_eq!(false, reg.get_flag(Flag::HalfCarry));
     assert_eq!(true, reg.get_flag(Flag::Carry));
 }
+
+#[test]
+fn test_0x18_JR_i8() {
+    let mut mem = TestBus::default();
+    let mut reg = Registers::default();
+
+    reg.pc = 0x1000;
+    mem.write(reg.pc + 1, (-4i8) as u8);
+
+    let cycles = execute_instruction(0x18, &mut reg, &mut mem).unwrap();
+
+    assert_eq!(0x0FFE, reg.pc);
+    assert_eq!(12, cycles);
+}
+
+#[test]
+fn test_0x20_JR_NZ_i8() {
+    let mut mem = TestBus::default();
+    let mut reg = Registers::default();
+
+    mem.write(reg.pc + 1, 0x10);
+
+    let cycles = execute_instruction(0x20, &mut reg, &mut mem).unwrap();
+
+    assert_eq!(0x0012, reg.pc);
+    assert_eq!(12, cycles);
+
+    reg.pc = 0x0000;
+    reg.set_flag(Flag::Zero, true);
+
+    let cycles = execute_instruction(0x20, &mut reg, &mut mem).unwrap();
+
+    assert_eq!(0x0002, reg.pc);
+    assert_eq!(8, cycles);
+}
+
+#[test]
+fn test_0x22_LD_HLI_A() {
+    let mut mem = TestBus::default();
+    let mut reg = Registers::default();
+
+    let addr: Address = 0x1000;
+    let data = 0x12;
+    reg.set_reg16(RegisterPair::HL, addr);
+    reg.set_reg8(Register::A, data);
+
+    execute_instruction(0x22, &mut reg, &mut mem).unwrap();
+
+    assert_eq!(data, mem.read(addr));
+    assert_eq!(addr + 1, reg.get_reg16(RegisterPair::HL));
+}
+
+#[test]
+fn test_0x27_DAA() {
+    let mut mem = TestBus::default();
+    let mut reg = Registers::default();
+
+    // 0x45 + 0x38 = 0x7D, adjusted to 0x83
+    reg.set_reg8(Register::A, 0x7D);
+
+    execute_instruction(0x27, &mut reg, &mut mem).unwrap();
+
+    assert_eq!(0x83, reg.get_reg8(Register::A));
+    assert_eq!(false, reg.get_flag(Flag::Zero));
+    assert_eq!(false, reg.get_flag(Flag::HalfCarry));
+    assert_eq!(false, reg.get_flag(Flag::Carry));
+
+    // 0x90 + 0x10 = 0xA0, adjusted to 0x00 with a carry
+    reg.set_reg8(Register::A, 0xA0);
+
+    execute_instruction(0x27, &mut reg, &mut mem).unwrap();
+
+    assert_eq!(0x00, reg.get_reg8(Register::A));
+    assert_eq!(true, reg.get_flag(Flag::Zero));
+    assert_eq!(true, reg.get_flag(Flag::Carry));
+}
+
+// The DAA adjustment as the usual table of digit ranges, with the value added to A and the carry
+// out for each combination of C and H going in. After a subtraction only the flags matter, and the
+// adjustment is given as the two's complement of the amount taken away
+// (N, C, H, upper digit, lower digit, added, carry out)
+type DaaRow = (
+    bool,
+    bool,
+    bool,
+    RangeInclusive<u8>,
+    RangeInclusive<u8>,
+    u8,
+    bool,
+);
+
+#[rustfmt::skip]
+const DAA_TABLE: [DaaRow; 15] = [
+    (false, false, false, 0x0..=0x9, 0x0..=0x9, 0x00, false),
+    (false, false, false, 0x0..=0x8, 0xA..=0xF, 0x06, false),
+    (false, false, false, 0x9..=0xF, 0xA..=0xF, 0x66, true),
+    (false, false, false, 0xA..=0xF, 0x0..=0x9, 0x60, true),
+    (false, false, true,  0x0..=0x9, 0x0..=0x9, 0x06, false),
+    (false, false, true,  0x0..=0x8, 0xA..=0xF, 0x06, false),
+    (false, false, true,  0x9..=0xF, 0xA..=0xF, 0x66, true),
+    (false, false, true,  0xA..=0xF, 0x0..=0x9, 0x66, true),
+    (false, true,  false, 0x0..=0xF, 0x0..=0x9, 0x60, true),
+    (false, true,  false, 0x0..=0xF, 0xA..=0xF, 0x66, true),
+    (false, true,  true,  0x0..=0xF, 0x0..=0xF, 0x66, true),
+    (true,  false, false, 0x0..=0xF, 0x0..=0xF, 0x00, false),
+    (true,  false, true,  0x0..=0xF, 0x0..=0xF, 0xFA, false),
+    (true,  true,  false, 0x0..=0xF, 0x0..=0xF, 0xA0, true),
+    (true,  true,  true,  0x0..=0xF, 0x0..=0xF, 0x9A, true),
+];
+
+#[test]
+fn test_0x27_DAA_exhaustive() {
+    let mut mem = TestBus::default();
+    let mut reg = Registers::default();
+
+    // Every value of A with every setting of Z, N, H and C
+    for value in 0x00..=0xFF {
+        for flags in 0x0..=0xF {
+            let (subtract, half_carry, carry) =
+                (flags & 0x4 != 0, flags & 0x2 != 0, flags & 0x1 != 0);
+            let (upper, lower) = (value >> 4, value & 0x0F);
+
+            // The ranges cover every input exactly once
+            let rows: Vec<_> = DAA_TABLE
+                .iter()
+                .filter(|row| {
+                    (row.0, row.1, row.2) == (subtract, carry, half_carry)
+                        && row.3.contains(&upper)
+                        && row.4.contains(&lower)
+                })
+                .collect();
+            assert_eq!(1, rows.len(), "DAA table rows for {:#04X}", value);
+            let (expected, expected_carry) = (value.wrapping_add(rows[0].5), rows[0].6);
+
+            reg.set_reg8(Register::A, value);
+            reg.set_flags(flags << 4);
+            execute_instruction(0x27, &mut reg, &mut mem).unwrap();
+
+            let context = format!("DAA on {:#04X} with flags {:#06b}", value, flags);
+            assert_eq!(expected, reg.get_reg8(Register::A), "{}", context);
+            assert_eq!(expected == 0, reg.get_flag(Flag::Zero), "{}", context);
+            assert_eq!(subtract, reg.get_flag(Flag::Subtract), "{}", context);
+            assert_eq!(false, reg.get_flag(Flag::HalfCarry), "{}", context);
+            assert_eq!(expected_carry, reg.get_flag(Flag::Carry), "{}", context);
+        }
+    }
+}
+
+#[test]
+fn test_0x2F_CPL() {
+    let mut mem = TestBus::default();
+    let mut reg = Registers::default();
+
+    reg.set_reg8(Register::A, 0b1010_0101);
+
+    execute_instruction(0x2F, &mut reg, &mut mem).unwrap();
+
+    assert_eq!(0b0101_1010, reg.get_reg8(Register::A));
+    assert_eq!(true, reg.get_flag(Flag::Subtract));
+    assert_eq!(true, reg.get_flag(Flag::HalfCarry));
+}
+
+#[test]
+fn test_0x34_INC_HL() {
+    let mut mem = TestBus::default();
+    let mut reg = Registers::default();
+
+    let addr: Address = 0x1000;
+    reg.set_reg16(RegisterPair::HL, addr);
+    mem.write(addr, 0xFF);
+
+    let cycles = execute_instruction(0x34, &mut reg, &mut mem).unwrap();
+
+    assert_eq!(0x00, mem.read(addr));
+    assert_eq!(true, reg.get_flag(Flag::Zero));
+    assert_eq!(false, reg.get_flag(Flag::Subtract));
+    assert_eq!(true, reg.get_flag(Flag::HalfCarry));
+    assert_eq!(12, cycles);
+}
+
+#[test]
+fn test_0x37_SCF() {
+    let mut mem = TestBus::default();
+    let mut reg = Registers::default();
+
+    reg.set_flag(Flag::Subtract, true);
+    reg.set_flag(Flag::HalfCarry, true);
+
+    execute_instruction(0x37, &mut reg, &mut mem).unwrap();
+
+    assert_eq!(false, reg.get_flag(Flag::Subtract));
+    assert_eq!(false, reg.get_flag(Flag::HalfCarry));
+    assert_eq!(true, reg.get_flag(Flag::Carry));
+}
+
+#[test]
+fn test_0x3F_CCF() {
+    let mut mem = TestBus::default();
+    let mut reg = Registers::default();
+
+    reg.set_flag(Flag::Carry, true);
+
+    execute_instruction(0x3F, &mut reg, &mut mem).unwrap();
+
+    assert_eq!(false, reg.get_flag(Flag::Carry));
+
+    execute_instruction(0x3F, &mut reg, &mut mem).unwrap();
+
+    assert_eq!(true, reg.get_flag(Flag::Carry));
+}
+
+#[test]
+fn test_0x46_LD_B_HL() {
+    let mut mem = TestBus::default();
+    let mut reg = Registers::default();
+
+    let addr: Address = 0x1000;
+    let data = 0x12;
+    reg.set_reg16(RegisterPair::HL, addr);
+    mem.write(addr, data);
+
+    let cycles = execute_instruction(0x46, &mut reg, &mut mem).unwrap();
+
+    assert_eq!(data, reg.get_reg8(Register::B));
+    assert_eq!(8, cycles);
+}
+
+#[test]
+fn test_0x80_ADD_A_B() {
+    let mut mem = TestBus::default();
+    let mut reg = Registers::default();
+
+    reg.set_reg8(Register::A, 0x3A);
+    reg.set_reg8(Register::B, 0xC6);
+
+    execute_instruction(0x80, &mut reg, &mut mem).unwrap();
+
+    assert_eq!(0x00, reg.get_reg8(Register::A));
+    assert_eq!(true, reg.get_flag(Flag::Zero));
+    assert_eq!(false, reg.get_flag(Flag::Subtract));
+    assert_eq!(true, reg.get_flag(Flag::HalfCarry));
+    assert_eq!(true, reg.get_flag(Flag::Carry));
+}
+
+#[test]
+fn test_0x88_ADC_A_B() {
+    let mut mem = TestBus::default();
+    let mut reg = Registers::default();
+
+    reg.set_reg8(Register::A, 0xE1);
+    reg.set_reg8(Register::B, 0x0F);
+    reg.set_flag(Flag::Carry, true);
+
+    execute_instruction(0x88, &mut reg, &mut mem).unwrap();
+
+    assert_eq!(0xF1, reg.get_reg8(Register::A));
+    assert_eq!(false, reg.get_flag(Flag::Zero));
+    assert_eq!(false, reg.get_flag(Flag::Subtract));
+    assert_eq!(true, reg.get_flag(Flag::HalfCarry));
+    assert_eq!(false, reg.get_flag(Flag::Carry));
+}
+
+#[test]
+fn test_0x90_SUB_A_B() {
+    let mut mem = TestBus::default();
+    let mut reg = Registers::default();
+
+    reg.set_reg8(Register::A, 0x3E);
+    reg.set_reg8(Register::B, 0x3E);
+
+    execute_instruction(0x90, &mut reg, &mut mem).unwrap();
+
+    assert_eq!(0x00, reg.get_reg8(Register::A));
+    assert_eq!(true, reg.get_flag(Flag::Zero));
+    assert_eq!(true, reg.get_flag(Flag::Subtract));
+    assert_eq!(false, reg.get_flag(Flag::Carry));
+}
+
+#[test]
+fn test_0x98_SBC_A_B() {
+    let mut mem = TestBus::default();
+    let mut reg = Registers::default();
+
+    reg.set_reg8(Register::A, 0x3B);
+    reg.set_reg8(Register::B, 0x4F);
+    reg.set_flag(Flag::Carry, true);
+
+    execute_instruction(0x98, &mut reg, &mut mem).unwrap();
+
+    assert_eq!(0xEB, reg.get_reg8(Register::A));
+    assert_eq!(false, reg.get_flag(Flag::Zero));
+    assert_eq!(true, reg.get_flag(Flag::Subtract));
+    assert_eq!(true, reg.get_flag(Flag::HalfCarry));
+    assert_eq!(true, reg.get_flag(Flag::Carry));
+}
+
+#[test]
+fn test_0xA0_AND_A_B() {
+    let mut mem = TestBus::default();
+    let mut reg = Registers::default();
+
+    reg.set_reg8(Register::A, 0b1100_1100);
+    reg.set_reg8(Register::B, 0b1010_1010);
+
+    execute_instruction(0xA0, &mut reg, &mut mem).unwrap();
+
+    assert_eq!(0b1000_1000, reg.get_reg8(Register::A));
+    assert_eq!(false, reg.get_flag(Flag::Zero));
+    assert_eq!(false, reg.get_flag(Flag::Subtract));
+    assert_eq!(true, reg.get_flag(Flag::HalfCarry));
+    assert_eq!(false, reg.get_flag(Flag::Carry));
+}
+
+#[test]
+fn test_0xAF_XOR_A_A() {
+    let mut mem = TestBus::default();
+    let mut reg = Registers::default();
+
+    reg.set_reg8(Register::A, 0x12);
+    reg.set_flag(Flag::Carry, true);
+
+    execute_instruction(0xAF, &mut reg, &mut mem).unwrap();
+
+    assert_eq!(0x00, reg.get_reg8(Register::A));
+    assert_eq!(true, reg.get_flag(Flag::Zero));
+    assert_eq!(false, reg.get_flag(Flag::Carry));
+}
+
+#[test]
+fn test_0xB0_OR_A_B() {
+    let mut mem = TestBus::default();
+    let mut reg = Registers::default();
+
+    reg.set_reg8(Register::A, 0b1100_0000);
+    reg.set_reg8(Register::B, 0b0000_0011);
+
+    execute_instruction(0xB0, &mut reg, &mut mem).unwrap();
+
+    assert_eq!(0b1100_0011, reg.get_reg8(Register::A));
+    assert_eq!(false, reg.get_flag(Flag::Zero));
+}
+
+#[test]
+fn test_0xFE_CP_A_u8() {
+    let mut mem = TestBus::default();
+    let mut reg = Registers::default();
+
+    let data = 0x40;
+    reg.set_reg8(Register::A, 0x3C);
+    mem.write(reg.pc + 1, data);
+
+    execute_instruction(0xFE, &mut reg, &mut mem).unwrap();
+
+    assert_eq!(0x3C, reg.get_reg8(Register::A));
+    assert_eq!(false, reg.get_flag(Flag::Zero));
+    assert_eq!(true, reg.get_flag(Flag::Subtract));
+    assert_eq!(true, reg.get_flag(Flag::Carry));
+    assert_eq!(2, reg.pc);
+}
+
+#[test]
+fn test_0xC5_PUSH_BC_0xD1_POP_DE() {
+    let mut mem = TestBus::default();
+    let mut reg = Registers::default();
+
+    let data = 0x1234;
+    reg.sp = 0xFFFE;
+    reg.set_reg16(RegisterPair::BC, data);
+
+    execute_instruction(0xC5, &mut reg, &mut mem).unwrap();
+
+    assert_eq!(0xFFFC, reg.sp);
+    assert_eq!(0x12, mem.read(0xFFFD));
+    assert_eq!(0x34, mem.read(0xFFFC));
+
+    execute_instruction(0xD1, &mut reg, &mut mem).unwrap();
+
+    assert_eq!(0xFFFE, reg.sp);
+    assert_eq!(data, reg.get_reg16(RegisterPair::DE));
+}
+
+#[test]
+fn test_0xF1_POP_AF() {
+    let mut mem = TestBus::default();
+    let mut reg = Registers::default();
+
+    reg.sp = 0x1000;
+    mem.write(0x1000, 0xFF);
+    mem.write(0x1001, 0x12);
+
+    execute_instruction(0xF1, &mut reg, &mut mem).unwrap();
+
+    // The lower nibble of F is always zero
+    assert_eq!(0x12F0, reg.get_reg16(RegisterPair::AF));
+}
+
+#[test]
+fn test_0xC3_JP_u16() {
+    let mut mem = TestBus::default();
+    let mut reg = Registers::default();
+
+    mem.write(reg.pc + 1, 0x50);
+    mem.write(reg.pc + 2, 0x01);
+
+    let cycles = execute_instruction(0xC3, &mut reg, &mut mem).unwrap();
+
+    assert_eq!(0x0150, reg.pc);
+    assert_eq!(16, cycles);
+}
+
+#[test]
+fn test_0xCD_CALL_u16_0xC9_RET() {
+    let mut mem = TestBus::default();
+    let mut reg = Registers::default();
+
+    reg.pc = 0x0100;
+    reg.sp = 0xFFFE;
+    mem.write(reg.pc + 1, 0x00);
+    mem.write(reg.pc + 2, 0x20);
+
+    let cycles = execute_instruction(0xCD, &mut reg, &mut mem).unwrap();
+
+    assert_eq!(0x2000, reg.pc);
+    assert_eq!(0xFFFC, reg.sp);
+    assert_eq!(24, cycles);
+
+    let cycles = execute_instruction(0xC9, &mut reg, &mut mem).unwrap();
+
+    assert_eq!(0x0103, reg.pc);
+    assert_eq!(0xFFFE, reg.sp);
+    assert_eq!(16, cycles);
+}
+
+#[test]
+fn test_0xC0_RET_NZ() {
+    let mut mem = TestBus::default();
+    let mut reg = Registers::default();
+
+    reg.sp = 0x1000;
+    mem.write(0x1000, 0x34);
+    mem.write(0x1001, 0x12);
+    reg.set_flag(Flag::Zero, true);
+
+    let cycles = execute_instruction(0xC0, &mut reg, &mut mem).unwrap();
+
+    assert_eq!(0x0001, reg.pc);
+    assert_eq!(8, cycles);
+
+    reg.set_flag(Flag::Zero, false);
+
+    let cycles = execute_instruction(0xC0, &mut reg, &mut mem).unwrap();
+
+    assert_eq!(0x1234, reg.pc);
+    assert_eq!(20, cycles);
+}
+
+#[test]
+fn test_0xD9_RETI() {
+    let mut mem = TestBus::default();
+    let mut reg = Registers::default();
+
+    reg.sp = 0x1000;
+    mem.write(0x1000, 0x34);
+    mem.write(0x1001, 0x12);
+
+    execute_instruction(0xD9, &mut reg, &mut mem).unwrap();
+
+    assert_eq!(0x1234, reg.pc);
+    assert!(reg.ime);
+}
+
+#[test]
+fn test_0xE0_LD_FF00_u8_A() {
+    let mut mem = TestBus::default();
+    let mut reg = Registers::default();
+
+    let data = 0x12;
+    reg.set_reg8(Register::A, data);
+    mem.write(reg.pc + 1, 0x80);
+
+    execute_instruction(0xE0, &mut reg, &mut mem).unwrap();
+
+    assert_eq!(data, mem.read(0xFF80));
+}
+
+#[test]
+fn test_0xF2_LD_A_FF00_C() {
+    let mut mem = TestBus::default();
+    let mut reg = Registers::default();
+
+    let data = 0x12;
+    reg.set_reg8(Register::C, 0x44);
+    mem.write(0xFF44, data);
+
+    execute_instruction(0xF2, &mut reg, &mut mem).unwrap();
+
+    assert_eq!(data, reg.get_reg8(Register::A));
+}
+
+#[test]
+fn test_0xE8_ADD_SP_i8() {
+    let mut mem = TestBus::default();
+    let mut reg = Registers::default();
+
+    reg.sp = 0x00FF;
+    mem.write(reg.pc + 1, (-1i8) as u8);
+
+    let cycles = execute_instruction(0xE8, &mut reg, &mut mem).unwrap();
+
+    assert_eq!(0x00FE, reg.sp);
+    assert_eq!(false, reg.get_flag(Flag::Zero));
+    assert_eq!(false, reg.get_flag(Flag::Subtract));
+    assert_eq!(true, reg.get_flag(Flag::HalfCarry));
+    assert_eq!(true, reg.get_flag(Flag::Carry));
+    assert_eq!(16, cycles);
+}
+
+#[test]
+fn test_0xF8_LD_HL_SP_i8() {
+    let mut mem = TestBus::default();
+    let mut reg = Registers::default();
+
+    reg.sp = 0xFFF8;
+    mem.write(reg.pc + 1, 0x02);
+
+    execute_instruction(0xF8, &mut reg, &mut mem).unwrap();
+
+    assert_eq!(0xFFFA, reg.get_reg16(RegisterPair::HL));
+    assert_eq!(0xFFF8, reg.sp);
+    assert_eq!(false, reg.get_flag(Flag::HalfCarry));
+    assert_eq!(false, reg.get_flag(Flag::Carry));
+}
+
+#[test]
+fn test_0xF3_DI_0xFB_EI() {
+    let mut mem = TestBus::default();
+    let mut reg = Registers::default();
+
+    execute_instruction(0xFB, &mut reg, &mut mem).unwrap();
+
+    // IME is enabled by the CPU after the next instruction
+    assert!(!reg.ime);
+    assert!(reg.ime_scheduled);
+
+    execute_instruction(0xF3, &mut reg, &mut mem).unwrap();
+
+    assert!(!reg.ime);
+    assert!(!reg.ime_scheduled);
+}
+
+#[test]
+fn test_0xFF_RST_38h() {
+    let mut mem = TestBus::default();
+    let mut reg = Registers::default();
+
+    reg.pc = 0x0200;
+    reg.sp = 0xFFFE;
+
+    execute_instruction(0xFF, &mut reg, &mut mem).unwrap();
+
+    assert_eq!(0x0038, reg.pc);
+    assert_eq!(
+        0x0201,
+        u16::from_le_bytes([mem.read(0xFFFC), mem.read(0xFFFD)])
+    );
+}
+
+#[test]
+fn test_0xCB00_RLC_B() {
+    let mut mem = TestBus::default();
+    let mut reg = Registers::default();
+
+    reg.set_reg8(Register::B, 0b1000_0101);
+    mem.write(reg.pc + 1, 0x00);
+
+    let cycles = execute_instruction(0xCB, &mut reg, &mut mem).unwrap();
+
+    assert_eq!(0b0000_1011, reg.get_reg8(Register::B));
+    assert_eq!(false, reg.get_flag(Flag::Zero));
+    assert_eq!(true, reg.get_flag(Flag::Carry));
+    assert_eq!(2, reg.pc);
+    assert_eq!(8, cycles);
+}
+
+#[test]
+fn test_0xCB11_RL_C() {
+    let mut mem = TestBus::default();
+    let mut reg = Registers::default();
+
+    reg.set_reg8(Register::C, 0b1000_0000);
+    mem.write(reg.pc + 1, 0x11);
+
+    execute_instruction(0xCB, &mut reg, &mut mem).unwrap();
+
+    assert_eq!(0x00, reg.get_reg8(Register::C));
+    assert_eq!(true, reg.get_flag(Flag::Zero));
+    assert_eq!(true, reg.get_flag(Flag::Carry));
+}
+
+#[test]
+fn test_0xCB1E_RR_HL() {
+    let mut mem = TestBus::default();
+    let mut reg = Registers::default();
+
+    let addr: Address = 0x1000;
+    reg.set_reg16(RegisterPair::HL, addr);
+    reg.set_flag(Flag::Carry, true);
+    mem.write(addr, 0b0000_0010);
+    mem.write(reg.pc + 1, 0x1E);
+
+    let cycles = execute_instruction(0xCB, &mut reg, &mut mem).unwrap();
+
+    assert_eq!(0b1000_0001, mem.read(addr));
+    assert_eq!(false, reg.get_flag(Flag::Zero));
+    assert_eq!(false, reg.get_flag(Flag::Carry));
+    assert_eq!(16, cycles);
+}
+
+#[test]
+fn test_0xCB27_SLA_A() {
+    let mut mem = TestBus::default();
+    let mut reg = Registers::default();
+
+    reg.set_reg8(Register::A, 0b1100_0001);
+    mem.write(reg.pc + 1, 0x27);
+
+    execute_instruction(0xCB, &mut reg, &mut mem).unwrap();
+
+    assert_eq!(0b1000_0010, reg.get_reg8(Register::A));
+    assert_eq!(true, reg.get_flag(Flag::Carry));
+}
+
+#[test]
+fn test_0xCB2F_SRA_A() {
+    let mut mem = TestBus::default();
+    let mut reg = Registers::default();
+
+    reg.set_reg8(Register::A, 0b1000_0001);
+    mem.write(reg.pc + 1, 0x2F);
+
+    execute_instruction(0xCB, &mut reg, &mut mem).unwrap();
+
+    assert_eq!(0b1100_0000, reg.get_reg8(Register::A));
+    assert_eq!(true, reg.get_flag(Flag::Carry));
+}
+
+#[test]
+fn test_0xCB37_SWAP_A() {
+    let mut mem = TestBus::default();
+    let mut reg = Registers::default();
+
+    reg.set_reg8(Register::A, 0xAB);
+    reg.set_flag(Flag::Carry, true);
+    mem.write(reg.pc + 1, 0x37);
+
+    execute_instruction(0xCB, &mut reg, &mut mem).unwrap();
+
+    assert_eq!(0xBA, reg.get_reg8(Register::A));
+    assert_eq!(false, reg.get_flag(Flag::Zero));
+    assert_eq!(false, reg.get_flag(Flag::Carry));
+}
+
+#[test]
+fn test_0xCB3F_SRL_A() {
+    let mut mem = TestBus::default();
+    let mut reg = Registers::default();
+
+    reg.set_reg8(Register::A, 0b1000_0001);
+    mem.write(reg.pc + 1, 0x3F);
+
+    execute_instruction(0xCB, &mut reg, &mut mem).unwrap();
+
+    assert_eq!(0b0100_0000, reg.get_reg8(Register::A));
+    assert_eq!(true, reg.get_flag(Flag::Carry));
+}
+
+#[test]
+fn test_0xCB7C_BIT_7_H() {
+    let mut mem = TestBus::default();
+    let mut reg = Registers::default();
+
+    reg.set_reg8(Register::H, 0b0111_1111);
+    reg.set_flag(Flag::Carry, true);
+    mem.write(reg.pc + 1, 0x7C);
+
+    execute_instruction(0xCB, &mut reg, &mut mem).unwrap();
+
+    assert_eq!(true, reg.get_flag(Flag::Zero));
+    assert_eq!(false, reg.get_flag(Flag::Subtract));
+    assert_eq!(true, reg.get_flag(Flag::HalfCarry));
+    assert_eq!(true, reg.get_flag(Flag::Carry));
+}
+
+#[test]
+fn test_0xCB46_BIT_0_HL() {
+    let mut mem = TestBus::default();
+    let mut reg = Registers::default();
+
+    let addr: Address = 0x1000;
+    reg.set_reg16(RegisterPair::HL, addr);
+    mem.write(addr, 0x01);
+    mem.write(reg.pc + 1, 0x46);
+
+    let cycles = execute_instruction(0xCB, &mut reg, &mut mem).unwrap();
+
+    assert_eq!(false, reg.get_flag(Flag::Zero));
+    assert_eq!(12, cycles);
+}
+
+#[test]
+fn test_0xCB86_RES_0_HL() {
+    let mut mem = TestBus::default();
+    let mut reg = Registers::default();
+
+    let addr: Address = 0x1000;
+    reg.set_reg16(RegisterPair::HL, addr);
+    mem.write(addr, 0xFF);
+    mem.write(reg.pc + 1, 0x86);
+
+    let cycles = execute_instruction(0xCB, &mut reg, &mut mem).unwrap();
+
+    assert_eq!(0xFE, mem.read(addr));
+    assert_eq!(16, cycles);
+}
+
+#[test]
+fn test_0xCBFF_SET_7_A() {
+    let mut mem = TestBus::default();
+    let mut reg = Registers::default();
+
+    mem.write(reg.pc + 1, 0xFF);
+
+    execute_instruction(0xCB, &mut reg, &mut mem).unwrap();
+
+    assert_eq!(0x80, reg.get_reg8(Register::A));
+}
+
+#[test]
+fn test_illegal_opcodes() {
+    let mut mem = TestBus::default();
+    let mut reg = Registers::default();
+
+    for code in [
+        0xD3, 0xDB, 0xDD, 0xE3, 0xE4, 0xEB, 0xEC, 0xED, 0xF4, 0xFC, 0xFD,
+    ] {
+        let result = execute_instruction(code, &mut reg, &mut mem);
+
+        assert_eq!(
+            Err(CpuError::IllegalOpcode {
+                opcode: code as u8,
+                address: 0x0000
+            }),
+            result
+        );
+    }
+    assert_eq!(0x0000, reg.pc);
+}
+
+#[test]
+fn test_memory_accesses_fit_in_cycles() {
+    for code in 0x00..=0xFF {
+        for prefixed in 0x00..=0xFF {
+            let mut mem = TestBus::default();
+            let mut reg = Registers::default();
+            mem.write(0x0001, prefixed);
+
+            // Every access to the bus takes an M-cycle, an instruction can't make more of them
+            // than its cycle count allows
+            let mut bus = TimedBus::new(&mut mem, true);
+            bus.read(reg.pc);
+            if let Ok(cycles) = super::execute_instruction(code, &mut reg, &mut bus) {
+                bus.finish(cycles);
+            }
+
+            if code != 0xCB {
+                break;
+            }
+        }
+    }
+}
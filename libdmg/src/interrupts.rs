@@ -0,0 +1,126 @@
+use super::data::Address;
+use super::memory::Bus;
+
+pub const INTERRUPT_ENABLE: Address = 0xFFFF;
+pub const INTERRUPT_FLAG: Address = 0xFF0F;
+
+// Number of cycles taken to dispatch an interrupt to its handler
+pub const DISPATCH_CYCLES: u8 = 20;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Interrupt {
+    VBlank,
+    LcdStat,
+    Timer,
+    Serial,
+    Joypad,
+}
+
+// Highest priority first, matching the bit order in IE and IF
+const PRIORITY: [Interrupt; 5] = [
+    Interrupt::VBlank,
+    Interrupt::LcdStat,
+    Interrupt::Timer,
+    Interrupt::Serial,
+    Interrupt::Joypad,
+];
+
+impl Interrupt {
+    pub fn mask(&self) -> u8 {
+        match self {
+            Interrupt::VBlank => 0b0000_0001,
+            Interrupt::LcdStat => 0b0000_0010,
+            Interrupt::Timer => 0b0000_0100,
+            Interrupt::Serial => 0b0000_1000,
+            Interrupt::Joypad => 0b0001_0000,
+        }
+    }
+
+    pub fn vector(&self) -> Address {
+        match self {
+            Interrupt::VBlank => 0x0040,
+            Interrupt::LcdStat => 0x0048,
+            Interrupt::Timer => 0x0050,
+            Interrupt::Serial => 0x0058,
+            Interrupt::Joypad => 0x0060,
+        }
+    }
+}
+
+pub fn request_interrupt<B: Bus>(mem: &mut B, interrupt: Interrupt) {
+    let flags = mem.read(INTERRUPT_FLAG);
+    mem.write(INTERRUPT_FLAG, flags | interrupt.mask());
+}
+
+pub fn acknowledge_interrupt<B: Bus>(mem: &mut B, interrupt: Interrupt) {
+    let flags = mem.read(INTERRUPT_FLAG);
+    mem.write(INTERRUPT_FLAG, flags & !interrupt.mask());
+}
+
+// Interrupts that are both requested and enabled, regardless of IME
+pub fn pending_interrupts<B: Bus>(mem: &B) -> u8 {
+    mem.read(INTERRUPT_ENABLE) & mem.read(INTERRUPT_FLAG) & 0x1F
+}
+
+// The highest priority interrupt that is both requested and enabled
+pub fn next_interrupt<B: Bus>(mem: &B) -> Option<Interrupt> {
+    let pending = pending_interrupts(mem);
+    PRIORITY
+        .iter()
+        .find(|interrupt| pending & interrupt.mask() != 0)
+        .copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::MemoryBus;
+
+    #[test]
+    fn test_request_interrupt() {
+        let mut mem = MemoryBus::default();
+
+        request_interrupt(&mut mem, Interrupt::Timer);
+        request_interrupt(&mut mem, Interrupt::Joypad);
+
+        assert_eq!(0b0001_0100, mem.read(INTERRUPT_FLAG) & 0x1F);
+
+        acknowledge_interrupt(&mut mem, Interrupt::Timer);
+
+        assert_eq!(0b0001_0000, mem.read(INTERRUPT_FLAG) & 0x1F);
+    }
+
+    #[test]
+    fn test_pending_interrupts() {
+        let mut mem = MemoryBus::default();
+
+        request_interrupt(&mut mem, Interrupt::Timer);
+        request_interrupt(&mut mem, Interrupt::Serial);
+
+        assert_eq!(0, pending_interrupts(&mem));
+        assert_eq!(None, next_interrupt(&mem));
+
+        mem.write(INTERRUPT_ENABLE, 0xFF);
+
+        assert_eq!(0b0000_1100, pending_interrupts(&mem));
+    }
+
+    #[test]
+    fn test_next_interrupt_priority() {
+        let mut mem = MemoryBus::default();
+        mem.write(INTERRUPT_ENABLE, 0xFF);
+
+        request_interrupt(&mut mem, Interrupt::Joypad);
+        assert_eq!(Some(Interrupt::Joypad), next_interrupt(&mem));
+
+        request_interrupt(&mut mem, Interrupt::LcdStat);
+        assert_eq!(Some(Interrupt::LcdStat), next_interrupt(&mem));
+
+        request_interrupt(&mut mem, Interrupt::VBlank);
+        assert_eq!(Some(Interrupt::VBlank), next_interrupt(&mem));
+
+        // Disabled interrupts are skipped
+        mem.write(INTERRUPT_ENABLE, Interrupt::Joypad.mask());
+        assert_eq!(Some(Interrupt::Joypad), next_interrupt(&mem));
+    }
+}
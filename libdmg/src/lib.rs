@@ -1,6 +1,12 @@
+pub mod cartridge;
 pub mod cpu;
 pub mod data;
+mod dma;
+pub mod error;
 mod flags;
 mod instructions;
-mod memory;
+pub mod interrupts;
+pub mod memory;
+pub mod model;
+pub mod ppu;
 mod registers;
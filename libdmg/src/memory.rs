@@ -1,64 +1,744 @@
-use std::vec;
-
+use super::cartridge::Cartridge;
 use super::data::Address;
+use super::dma::{OamDma, DMA};
+use super::error::{BootRomError, CartridgeError};
+use super::interrupts::INTERRUPT_FLAG;
+use super::model::Model;
+use super::ppu::{self, Ppu};
+
+pub const DIV: Address = 0xFF04;
+// Writing to BANK unmaps the boot ROM for good
+pub const BANK: Address = 0xFF50;
+
+// Every memory access takes one M-cycle, or 4 T-cycles
+pub const M_CYCLE: u8 = 4;
+
+const WRAM_SIZE: usize = 0x2000;
+const IO_SIZE: usize = 0x80;
+const HRAM_SIZE: usize = 0x7F;
 
-const MEM_SIZE: usize = 0x10000;
+// What the data bus floats to when nothing drives it
+pub(crate) const OPEN_BUS: u8 = 0xFF;
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Debug)]
 pub struct MemoryBus {
-    memory: Vec<u8>,
+    // Nothing drives the bus in the cartridge's address ranges until one is inserted
+    cartridge: Option<Cartridge>,
+    // Mapped over the cartridge until the boot ROM writes to BANK
+    boot_rom: Option<Vec<u8>>,
+    ppu: Ppu,
+    oam_dma: OamDma,
+    // T-cycles towards the next M-cycle of OAM DMA
+    dma_cycles: u8,
+    wram: Vec<u8>,
+    io: Vec<u8>,
+    hram: Vec<u8>,
+    interrupt_enable: u8,
+    // DIV is the upper byte of a 16-bit counter incremented every T-cycle
+    divider: u16,
+    // The CGB only registers are unmapped on the DMG
+    cgb_mode: bool,
 }
 
 impl MemoryBus {
-    pub fn write(&mut self, addr: Address, data: u8) {
-        self.memory[addr as usize] = data;
+    // Insert a cartridge for a ROM image, with the memory bank controller named in its header
+    pub fn load_rom(&mut self, rom: Vec<u8>) -> Result<(), CartridgeError> {
+        self.load_cartridge(Cartridge::new(rom)?);
+        Ok(())
+    }
+
+    pub fn load_cartridge(&mut self, cartridge: Cartridge) {
+        self.cartridge = Some(cartridge);
+    }
+
+    pub fn cartridge(&self) -> Option<&Cartridge> {
+        self.cartridge.as_ref()
+    }
+
+    pub fn cartridge_mut(&mut self) -> Option<&mut Cartridge> {
+        self.cartridge.as_mut()
+    }
+
+    pub fn ppu(&self) -> &Ppu {
+        &self.ppu
+    }
+
+    pub fn ppu_mut(&mut self) -> &mut Ppu {
+        &mut self.ppu
+    }
+
+    pub fn set_cgb_mode(&mut self, cgb_mode: bool) {
+        self.cgb_mode = cgb_mode;
+        self.ppu.set_cgb_mode(cgb_mode);
+    }
+
+    // Map a boot ROM for the model over the start of the cartridge, to run from 0x0000
+    pub fn load_boot_rom(&mut self, model: Model, boot_rom: Vec<u8>) -> Result<(), BootRomError> {
+        if boot_rom.len() != model.boot_rom_size() {
+            return Err(BootRomError::SizeMismatch {
+                model,
+                expected: model.boot_rom_size(),
+                actual: boot_rom.len(),
+            });
+        }
+        self.boot_rom = Some(boot_rom);
+        Ok(())
+    }
+
+    pub fn boot_rom_mapped(&self) -> bool {
+        self.boot_rom.is_some()
+    }
+
+    // Set up the I/O registers as the model's boot ROM leaves them, for starting straight at the
+    // cartridge without one
+    pub fn skip_boot(&mut self, model: Model) {
+        self.boot_rom = None;
+
+        let mut registers = vec![
+            (0xFF00, 0xCF),
+            (0xFF02, 0x7E),
+            (0xFF07, 0xF8),
+            (0xFF0F, 0xE1),
+            (0xFF10, 0x80),
+            (0xFF11, 0xBF),
+            (0xFF12, 0xF3),
+            (0xFF13, 0xFF),
+            (0xFF14, 0xBF),
+            (0xFF16, 0x3F),
+            (0xFF18, 0xFF),
+            (0xFF19, 0xBF),
+            (0xFF1A, 0x7F),
+            (0xFF1B, 0xFF),
+            (0xFF1C, 0x9F),
+            (0xFF1D, 0xFF),
+            (0xFF1E, 0xBF),
+            (0xFF20, 0xFF),
+            (0xFF23, 0xBF),
+            (0xFF24, 0x77),
+            (0xFF25, 0xF3),
+            (0xFF26, 0xF1),
+            (0xFF40, 0x91),
+            (0xFF47, 0xFC),
+            (BANK, 0x01),
+        ];
+        match model {
+            // The SGB's boot ROM leaves the APU switched off
+            Model::Sgb | Model::Sgb2 => registers.push((0xFF26, 0xF0)),
+            Model::Cgb | Model::Agb => registers.push((0xFF02, 0x7F)),
+            _ => {}
+        }
+        for (addr, data) in registers {
+            self.write(addr, data);
+        }
+        // Writing DMA would start a transfer, only the value it was last written with is left
+        self.oam_dma = match model {
+            Model::Cgb | Model::Agb => OamDma::new(0x00),
+            _ => OamDma::new(0xFF),
+        };
+        self.interrupt_enable = 0x00;
+        self.ppu.skip_boot();
+
+        // How far DIV has counted depends on how long the boot ROM took. The SGB waits on the
+        // SNES and the CGB on the logo animation, so only the DMG's are predictable
+        self.divider = match model {
+            Model::Dmg0 => 0x1830,
+            Model::Dmg | Model::Mgb => 0xABCC,
+            _ => 0x0000,
+        };
+    }
+
+    // While OAM DMA runs the CPU can't reach OAM, or anything on the same bus as the transfer's
+    // source. VRAM has a bus of its own and everything else apart from I/O and HRAM shares the
+    // other, so reading there sees the byte the transfer is copying instead
+    fn dma_conflict(&self, addr: Address) -> bool {
+        let vram = |addr| matches!(addr, 0x8000..=0x9FFF);
+        self.oam_dma.active()
+            && match addr {
+                0xFE00..=0xFEFF => true,
+                0xFF00..=0xFFFF => false,
+                _ => vram(addr) == vram(self.oam_dma.source()),
+            }
+    }
+
+    fn step_dma(&mut self) {
+        if let Some((source, offset)) = self.oam_dma.step() {
+            // Sources past WRAM read WRAM again rather than OAM and I/O
+            let source = match source {
+                0xE000..=0xFFFF => source - 0x2000,
+                _ => source,
+            };
+            let data = self.read_unblocked(source);
+            self.oam_dma.set_data(data);
+            self.ppu.write_oam_dma(offset, data);
+        }
+    }
+
+    fn read_unblocked(&self, addr: Address) -> u8 {
+        if let Some(boot_rom) = &self.boot_rom {
+            // The CGB's boot ROM leaves a gap for the cartridge header at 0x0100-0x01FF
+            if matches!(addr, 0x0000..=0x00FF | 0x0200..=0x08FF) && (addr as usize) < boot_rom.len()
+            {
+                return boot_rom[addr as usize];
+            }
+        }
+
+        match addr {
+            0x0000..=0x7FFF => match &self.cartridge {
+                Some(cartridge) => cartridge.read_rom(addr),
+                None => OPEN_BUS,
+            },
+            0x8000..=0x9FFF => self.ppu.read_vram(addr),
+            0xA000..=0xBFFF => match &self.cartridge {
+                Some(cartridge) => cartridge.read_ram(addr),
+                None => OPEN_BUS,
+            },
+            0xC000..=0xDFFF => self.wram[addr as usize - 0xC000],
+            0xE000..=0xFDFF => self.wram[addr as usize - 0xE000],
+            0xFE00..=0xFE9F => self.ppu.read_oam(addr),
+            // The unusable area reads as 0 on the DMG
+            0xFEA0..=0xFEFF => 0x00,
+            DIV => (self.divider >> 8) as u8,
+            DMA => self.oam_dma.register(),
+            ppu::LCDC..=ppu::LY | ppu::LYC | ppu::BGP..=ppu::WX => self.ppu.read_register(addr),
+            0xFF00..=0xFF7F => self.io[addr as usize - 0xFF00] | self.unused_io_bits(addr),
+            0xFF80..=0xFFFE => self.hram[addr as usize - 0xFF80],
+            0xFFFF => self.interrupt_enable,
+        }
+    }
+
+    // Bits of each I/O register that aren't connected to anything and always read as 1, unmapped
+    // and write only registers read as all 1s
+    fn unused_io_bits(&self, addr: Address) -> u8 {
+        match addr {
+            // P1, SC
+            0xFF00 => 0xC0,
+            0xFF02 if self.cgb_mode => 0x7C,
+            0xFF02 => 0x7E,
+            // SB, DIV, TIMA, TMA, TAC, IF
+            0xFF01 | 0xFF04..=0xFF06 => 0x00,
+            0xFF07 => 0xF8,
+            0xFF0F => 0xE0,
+            // Sound channels, the lengths and frequencies are write only
+            0xFF10 => 0x80,
+            0xFF11 | 0xFF16 => 0x3F,
+            0xFF12 | 0xFF17 | 0xFF21 | 0xFF22 => 0x00,
+            0xFF14 | 0xFF19 | 0xFF1E | 0xFF23 => 0xBF,
+            0xFF1A => 0x7F,
+            0xFF1C => 0x9F,
+            // NR50, NR51, NR52 and wave RAM
+            0xFF24 | 0xFF25 => 0x00,
+            0xFF26 => 0x70,
+            0xFF30..=0xFF3F => 0x00,
+            // LCD registers
+            0xFF41 => 0x80,
+            0xFF40 | 0xFF42..=0xFF4B => 0x00,
+            // KEY1, VBK, HDMA5, RP, palettes and SVBK
+            0xFF4D if self.cgb_mode => 0x7E,
+            0xFF4F if self.cgb_mode => 0xFE,
+            0xFF55 if self.cgb_mode => 0x00,
+            0xFF56 if self.cgb_mode => 0x3C,
+            0xFF68 | 0xFF6A if self.cgb_mode => 0x40,
+            0xFF69 | 0xFF6B if self.cgb_mode => 0x00,
+            0xFF70 if self.cgb_mode => 0xF8,
+            _ => 0xFF,
+        }
+    }
+}
+
+// Everything the CPU can read from and write to. Implement this to run the CPU against a memory
+// map of your own, the interrupt registers IE (0xFFFF) and IF (0xFF0F) are expected to be readable
+// and writable through it
+pub trait Bus {
+    // Reads can happen outside of a bus cycle, e.g. when the CPU checks for pending interrupts, so
+    // they shouldn't have side effects
+    fn read(&self, addr: Address) -> u8;
+    fn write(&mut self, addr: Address, data: u8);
+    // Called as the CPU spends cycles, with the number of T-cycles passed since the last call, to
+    // advance everything else connected to the bus
+    fn tick(&mut self, _cycles: u8) {}
+}
+
+impl Bus for MemoryBus {
+    fn write(&mut self, addr: Address, data: u8) {
+        if self.dma_conflict(addr) {
+            return;
+        }
+
+        match addr {
+            // Writes to ROM set the memory bank controller's registers
+            0x0000..=0x7FFF => {
+                if let Some(cartridge) = &mut self.cartridge {
+                    cartridge.write_rom(addr, data);
+                }
+            }
+            0x8000..=0x9FFF => self.ppu.write_vram(addr, data),
+            0xA000..=0xBFFF => {
+                if let Some(cartridge) = &mut self.cartridge {
+                    cartridge.write_ram(addr, data);
+                }
+            }
+            0xC000..=0xDFFF => self.wram[addr as usize - 0xC000] = data,
+            // Echo RAM mirrors 0xC000-0xDDFF
+            0xE000..=0xFDFF => self.wram[addr as usize - 0xE000] = data,
+            0xFE00..=0xFE9F => self.ppu.write_oam(addr, data),
+            0xFEA0..=0xFEFF => {}
+            // Writing any value to DIV resets the whole counter
+            DIV => self.divider = 0,
+            DMA => self.oam_dma.start(data),
+            BANK => {
+                self.boot_rom = None;
+                self.io[addr as usize - 0xFF00] = data;
+            }
+            ppu::LCDC..=ppu::LY | ppu::LYC | ppu::BGP..=ppu::WX => {
+                let interrupts = self.ppu.write_register(addr, data);
+                self.io[INTERRUPT_FLAG as usize - 0xFF00] |= interrupts;
+            }
+            0xFF00..=0xFF7F => self.io[addr as usize - 0xFF00] = data,
+            0xFF80..=0xFFFE => self.hram[addr as usize - 0xFF80] = data,
+            0xFFFF => self.interrupt_enable = data,
+        }
     }
 
-    pub fn read(&self, addr: Address) -> u8 {
-        self.memory[addr as usize]
+    fn read(&self, addr: Address) -> u8 {
+        match self.dma_conflict(addr) {
+            true if (0xFE00..=0xFEFF).contains(&addr) => OPEN_BUS,
+            true => self.oam_dma.data(),
+            false => self.read_unblocked(addr),
+        }
+    }
+
+    fn tick(&mut self, cycles: u8) {
+        self.divider = self.divider.wrapping_add(cycles as u16);
+
+        let dma_cycles = self.dma_cycles as u16 + cycles as u16;
+        for _ in 0..dma_cycles / M_CYCLE as u16 {
+            self.step_dma();
+        }
+        self.dma_cycles = (dma_cycles % M_CYCLE as u16) as u8;
+
+        let interrupts = self.ppu.tick(cycles);
+        self.io[INTERRUPT_FLAG as usize - 0xFF00] |= interrupts;
+        if let Some(cartridge) = &mut self.cartridge {
+            cartridge.tick(cycles);
+        }
     }
 }
 
 impl Default for MemoryBus {
     fn default() -> MemoryBus {
         MemoryBus {
-            memory: vec![0; MEM_SIZE],
+            cartridge: None,
+            boot_rom: None,
+            ppu: Ppu::default(),
+            oam_dma: OamDma::default(),
+            dma_cycles: 0,
+            wram: vec![0; WRAM_SIZE],
+            io: vec![0; IO_SIZE],
+            hram: vec![0; HRAM_SIZE],
+            interrupt_enable: 0,
+            divider: 0,
+            cgb_mode: false,
+        }
+    }
+}
+
+// The bus as seen by the CPU while it runs an instruction or dispatches an interrupt. When ticking
+// per access, every read, write and internal M-cycle advances the rest of the system as it happens
+// so the timing of each access within an instruction is visible. Otherwise nothing moves until
+// the whole instruction is finished
+pub(crate) struct TimedBus<'a, B: Bus> {
+    memory: &'a mut B,
+    tick_per_access: bool,
+    elapsed: u8,
+}
+
+impl<'a, B: Bus> TimedBus<'a, B> {
+    pub fn new(memory: &'a mut B, tick_per_access: bool) -> TimedBus<'a, B> {
+        TimedBus {
+            memory,
+            tick_per_access,
+            elapsed: 0,
+        }
+    }
+
+    pub fn read(&mut self, addr: Address) -> u8 {
+        self.cycle();
+        self.memory.read(addr)
+    }
+
+    pub fn write(&mut self, addr: Address, data: u8) {
+        self.cycle();
+        self.memory.write(addr, data);
+    }
+
+    // An M-cycle spent on work inside the CPU without touching memory
+    pub fn idle(&mut self) {
+        self.cycle();
+    }
+
+    // Look at the bus without taking any time, e.g. for the interrupt lines
+    pub fn memory(&mut self) -> &mut B {
+        self.memory
+    }
+
+    // Advance the system through whatever is left of an operation that takes the given number
+    // of cycles in total
+    pub fn finish(self, cycles: u8) {
+        debug_assert!(
+            self.elapsed <= cycles,
+            "{} cycles of accesses in a {} cycle operation",
+            self.elapsed,
+            cycles
+        );
+        let remaining = match self.tick_per_access {
+            true => cycles.saturating_sub(self.elapsed),
+            false => cycles,
+        };
+        self.memory.tick(remaining);
+    }
+
+    fn cycle(&mut self) {
+        self.elapsed = self.elapsed.saturating_add(M_CYCLE);
+        if self.tick_per_access {
+            self.memory.tick(M_CYCLE);
         }
     }
 }
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod tests {
     use super::*;
+    use crate::model::{CGB_BOOT_ROM_SIZE, DMG_BOOT_ROM_SIZE};
 
     #[test]
     fn test_write() {
         let mut mem = MemoryBus::default();
 
-        let addresses = vec![0x0000, 0x00FF, 0xFFFF];
+        let addresses = vec![0x8000, 0xC000, 0xDFFF, 0xFE00, 0xFF80, 0xFFFF];
         let values = vec![0x0F, 0xF0, 0xAA];
 
         for addr in &addresses {
             for val in &values {
                 mem.write(*addr, *val);
-                assert_eq!(*val, mem.memory[*addr as usize])
+                assert_eq!(*val, mem.read(*addr))
             }
         }
     }
 
     #[test]
-    fn test_read() {
+    fn test_read_rom() {
+        let mut rom = vec![0x00; 0x4000];
+        rom[0x0001] = 0x3C;
         let mut mem = MemoryBus::default();
+        mem.load_rom(rom).unwrap();
 
-        let addresses = vec![0x0000, 0x00FF, 0xFFFF];
-        let values = vec![0x0F, 0xF0, 0xAA];
+        assert_eq!(0x3C, mem.read(0x0001));
+        // Past the end of the ROM
+        assert_eq!(0xFF, mem.read(0x4000));
+        assert_eq!(0xFF, mem.read(0x7FFF));
+    }
 
-        for addr in &addresses {
-            for val in &values {
-                mem.memory[*addr as usize] = *val;
-                let result = mem.read(*addr);
-                assert_eq!(result, *val)
-            }
+    #[test]
+    fn test_no_cartridge() {
+        let mut mem = MemoryBus::default();
+
+        mem.write(0x2000, 0x01);
+
+        assert_eq!(0xFF, mem.read(0x0000));
+        assert_eq!(0xFF, mem.read(0x4000));
+    }
+
+    #[test]
+    fn test_bank_switching() {
+        // MBC1 with 64KiB of ROM
+        let mut rom = vec![0x00; 0x10000];
+        rom[0x0147] = 0x01;
+        rom[0x0148] = 0x01;
+        rom[0xC000] = 0xAA;
+        let mut mem = MemoryBus::default();
+        mem.load_rom(rom).unwrap();
+
+        assert_eq!(0x00, mem.read(0x4000));
+        mem.write(0x2000, 0x03);
+        assert_eq!(0xAA, mem.read(0x4000));
+    }
+
+    #[test]
+    fn test_write_rom() {
+        let mut rom = vec![0x00; 0x8000];
+        rom[0x0001] = 0x3C;
+        let mut mem = MemoryBus::default();
+        mem.load_rom(rom).unwrap();
+
+        mem.write(0x0001, 0xAA);
+
+        assert_eq!(0x3C, mem.read(0x0001));
+    }
+
+    #[test]
+    fn test_external_ram_absent() {
+        let mut mem = MemoryBus::default();
+
+        mem.write(0xA000, 0xAA);
+
+        assert_eq!(0xFF, mem.read(0xA000));
+    }
+
+    #[test]
+    fn test_echo_ram() {
+        let mut mem = MemoryBus::default();
+
+        mem.write(0xC123, 0xAA);
+        assert_eq!(0xAA, mem.read(0xE123));
+
+        mem.write(0xFDFF, 0xBB);
+        assert_eq!(0xBB, mem.read(0xDDFF));
+    }
+
+    #[test]
+    fn test_unusable() {
+        let mut mem = MemoryBus::default();
+
+        mem.write(0xFEA0, 0xAA);
+
+        assert_eq!(0x00, mem.read(0xFEA0));
+        assert_eq!(0x00, mem.read(0xFE9F));
+    }
+
+    #[test]
+    fn test_unused_io_bits() {
+        let mut mem = MemoryBus::default();
+
+        // IF only has 5 interrupt lines
+        mem.write(0xFF0F, 0x01);
+        assert_eq!(0xE1, mem.read(0xFF0F));
+
+        // STAT bit 7
+        mem.write(0xFF41, 0x00);
+        assert_eq!(0x80, mem.read(0xFF41));
+
+        // Fully used registers read back what was written
+        mem.write(0xFF47, 0xE4);
+        assert_eq!(0xE4, mem.read(0xFF47));
+
+        // Unmapped registers read as all 1s
+        mem.write(0xFF03, 0x00);
+        assert_eq!(0xFF, mem.read(0xFF03));
+    }
+
+    #[test]
+    fn test_cgb_io() {
+        let mut mem = MemoryBus::default();
+
+        // KEY1 only exists on the CGB
+        mem.write(0xFF4D, 0x00);
+        assert_eq!(0xFF, mem.read(0xFF4D));
+
+        mem.set_cgb_mode(true);
+        assert_eq!(0x7E, mem.read(0xFF4D));
+    }
+
+    #[test]
+    fn test_boot_rom() {
+        let mut mem = MemoryBus::default();
+        mem.load_rom(vec![0x00; 0x8000]).unwrap();
+
+        assert_eq!(
+            Err(BootRomError::SizeMismatch {
+                model: Model::Dmg,
+                expected: DMG_BOOT_ROM_SIZE,
+                actual: CGB_BOOT_ROM_SIZE
+            }),
+            mem.load_boot_rom(Model::Dmg, vec![0x22; CGB_BOOT_ROM_SIZE])
+        );
+
+        mem.load_boot_rom(Model::Dmg, vec![0x22; DMG_BOOT_ROM_SIZE])
+            .unwrap();
+        assert_eq!(0x22, mem.read(0x0000));
+        assert_eq!(0x22, mem.read(0x00FF));
+        assert_eq!(0x00, mem.read(0x0100));
+
+        mem.write(BANK, 0x01);
+        assert_eq!(false, mem.boot_rom_mapped());
+        assert_eq!(0x00, mem.read(0x0000));
+    }
+
+    #[test]
+    fn test_cgb_boot_rom() {
+        let mut mem = MemoryBus::default();
+        mem.load_rom(vec![0x00; 0x8000]).unwrap();
+        mem.load_boot_rom(Model::Cgb, vec![0x22; CGB_BOOT_ROM_SIZE])
+            .unwrap();
+
+        // The cartridge header shows through the middle
+        assert_eq!(0x22, mem.read(0x00FF));
+        assert_eq!(0x00, mem.read(0x0100));
+        assert_eq!(0x00, mem.read(0x01FF));
+        assert_eq!(0x22, mem.read(0x0200));
+        assert_eq!(0x22, mem.read(0x08FF));
+        assert_eq!(0x00, mem.read(0x0900));
+    }
+
+    #[test]
+    fn test_skip_boot() {
+        let mut mem = MemoryBus::default();
+        mem.load_boot_rom(Model::Dmg, vec![0x22; DMG_BOOT_ROM_SIZE])
+            .unwrap();
+
+        mem.skip_boot(Model::Dmg);
+        assert_eq!(false, mem.boot_rom_mapped());
+        assert_eq!(0xAB, mem.read(DIV));
+        assert_eq!(0x91, mem.read(0xFF40));
+        assert_eq!(0x85, mem.read(0xFF41));
+        assert_eq!(0xFC, mem.read(0xFF47));
+        assert_eq!(0xF1, mem.read(0xFF26));
+        assert_eq!(0xE1, mem.read(0xFF0F));
+
+        // DMA is left as it was written without a transfer running
+        assert_eq!(0xFF, mem.read(DMA));
+        mem.tick(8);
+        assert_eq!(0x00, mem.read(0xFE00));
+
+        mem.skip_boot(Model::Sgb);
+        assert_eq!(0xF0, mem.read(0xFF26));
+        mem.skip_boot(Model::Dmg0);
+        assert_eq!(0x18, mem.read(DIV));
+    }
+
+    #[test]
+    fn test_ppu() {
+        let mut mem = MemoryBus::default();
+        mem.write(ppu::LCDC, 0x91);
+        assert_eq!(0x91, mem.read(ppu::LCDC));
+
+        // VBlank is requested through IF after 144 lines
+        for _ in 0..144 * ppu::DOTS_PER_LINE as u32 / 4 {
+            mem.tick(4);
+        }
+        assert_eq!(144, mem.read(ppu::LY));
+        assert_eq!(0xE1, mem.read(INTERRUPT_FLAG));
+
+        // LY can't be written
+        mem.write(ppu::LY, 0x00);
+        assert_eq!(144, mem.read(ppu::LY));
+    }
+
+    fn run_m_cycles(mem: &mut MemoryBus, m_cycles: u32) {
+        for _ in 0..m_cycles {
+            mem.tick(M_CYCLE);
         }
     }
+
+    #[test]
+    fn test_oam_dma() {
+        let mut mem = MemoryBus::default();
+        for offset in 0..0xA0 {
+            mem.write(0xC100 + offset, offset as u8);
+        }
+        mem.write(0x8000, 0xAA);
+        mem.write(0xFF80, 0xBB);
+
+        // The transfer starts copying on the second M-cycle
+        mem.write(DMA, 0xC1);
+        mem.tick(4);
+        assert_eq!(0x00, mem.read(0xC000));
+        mem.tick(4);
+        assert_eq!(0xC1, mem.read(DMA));
+
+        // Only the other bus, I/O and HRAM can be reached
+        assert_eq!(0x00, mem.read(0xC000));
+        assert_eq!(0x00, mem.read(0x0000));
+        assert_eq!(0xFF, mem.read(0xFE00));
+        assert_eq!(0xAA, mem.read(0x8000));
+        assert_eq!(0xBB, mem.read(0xFF80));
+        mem.tick(4);
+        assert_eq!(0x01, mem.read(0xC000));
+        mem.write(0xC000, 0xCC);
+
+        run_m_cycles(&mut mem, 158);
+        assert_eq!(0x9F, mem.read(0xC000));
+        mem.tick(4);
+        assert_eq!(0x00, mem.read(0xFE00));
+        assert_eq!(0x9F, mem.read(0xFE9F));
+        assert_eq!(0x00, mem.read(0xC000));
+    }
+
+    #[test]
+    fn test_oam_dma_sources() {
+        let mut mem = MemoryBus::default();
+        mem.write(0xDE00, 0xAA);
+        mem.write(0x9F00, 0xBB);
+
+        // Sources past WRAM read from WRAM
+        mem.write(DMA, 0xFE);
+        run_m_cycles(&mut mem, 162);
+        assert_eq!(0xAA, mem.read(0xFE00));
+
+        // Copying from VRAM only gets in the way of VRAM
+        mem.write(DMA, 0x9F);
+        mem.tick(8);
+        assert_eq!(0xBB, mem.read(0x8000));
+        assert_eq!(0xAA, mem.read(0xDE00));
+    }
+
+    #[test]
+    fn test_oam_dma_restart() {
+        let mut mem = MemoryBus::default();
+        mem.write(0xC100, 0x11);
+        mem.write(0xC200, 0x22);
+
+        mem.write(DMA, 0xC1);
+        run_m_cycles(&mut mem, 10);
+        mem.write(DMA, 0xC2);
+
+        // OAM stays blocked while the second transfer starts, then it starts over from the
+        // beginning
+        mem.tick(4);
+        assert_eq!(0xFF, mem.read(0xFE00));
+        run_m_cycles(&mut mem, 161);
+        assert_eq!(0x22, mem.read(0xFE00));
+    }
+
+    #[test]
+    fn test_divider() {
+        let mut mem = MemoryBus::default();
+
+        mem.tick(255);
+        assert_eq!(0x00, mem.read(DIV));
+        mem.tick(1);
+        assert_eq!(0x01, mem.read(DIV));
+
+        mem.write(DIV, 0xAB);
+        assert_eq!(0x00, mem.read(DIV));
+    }
+
+    #[test]
+    fn test_timed_bus_tick_per_access() {
+        let mut mem = MemoryBus::default();
+
+        let mut bus = TimedBus::new(&mut mem, true);
+        bus.read(0x0000);
+        bus.write(0xC000, 0xAA);
+        bus.idle();
+        assert_eq!(12, bus.memory().divider);
+        bus.finish(16);
+
+        assert_eq!(16, mem.divider);
+        assert_eq!(0xAA, mem.read(0xC000));
+    }
+
+    #[test]
+    fn test_timed_bus_tick_per_instruction() {
+        let mut mem = MemoryBus::default();
+
+        let mut bus = TimedBus::new(&mut mem, false);
+        bus.read(0x0000);
+        bus.idle();
+        assert_eq!(0, bus.memory().divider);
+        assert_eq!(8, bus.elapsed);
+        bus.finish(16);
+
+        assert_eq!(16, mem.divider);
+    }
 }
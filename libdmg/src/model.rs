@@ -0,0 +1,86 @@
+use std::fmt;
+
+// The boot ROM is mapped over the start of the cartridge until it's done. The CGB's is larger and
+// leaves a gap at 0x0100-0x01FF for the cartridge header it checks
+pub const DMG_BOOT_ROM_SIZE: usize = 0x0100;
+pub const CGB_BOOT_ROM_SIZE: usize = 0x0900;
+
+// The Game Boy models, which differ mostly in what their boot ROMs leave behind for the cartridge
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub enum Model {
+    // The original DMG running the first revision of its boot ROM
+    Dmg0,
+    #[default]
+    Dmg,
+    // Game Boy Pocket and Game Boy Light
+    Mgb,
+    Sgb,
+    Sgb2,
+    Cgb,
+    // Game Boy Advance, which runs Game Boy games on its own CGB compatible hardware
+    Agb,
+}
+
+impl Model {
+    pub fn from_name(name: &str) -> Option<Model> {
+        match name.to_ascii_lowercase().as_str() {
+            "dmg0" => Some(Model::Dmg0),
+            "dmg" => Some(Model::Dmg),
+            "mgb" => Some(Model::Mgb),
+            "sgb" => Some(Model::Sgb),
+            "sgb2" => Some(Model::Sgb2),
+            "cgb" => Some(Model::Cgb),
+            "agb" => Some(Model::Agb),
+            _ => None,
+        }
+    }
+
+    // Whether the model has the CGB's hardware, even if it's running a DMG game
+    pub fn is_cgb(&self) -> bool {
+        matches!(self, Model::Cgb | Model::Agb)
+    }
+
+    pub fn boot_rom_size(&self) -> usize {
+        match self.is_cgb() {
+            true => CGB_BOOT_ROM_SIZE,
+            false => DMG_BOOT_ROM_SIZE,
+        }
+    }
+}
+
+impl fmt::Display for Model {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Model::Dmg0 => "DMG0",
+            Model::Dmg => "DMG",
+            Model::Mgb => "MGB",
+            Model::Sgb => "SGB",
+            Model::Sgb2 => "SGB2",
+            Model::Cgb => "CGB",
+            Model::Agb => "AGB",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_name() {
+        assert_eq!(Some(Model::Sgb2), Model::from_name("sgb2"));
+        assert_eq!(Some(Model::Dmg0), Model::from_name("DMG0"));
+        assert_eq!(None, Model::from_name("gba"));
+        assert_eq!("AGB", Model::Agb.to_string());
+    }
+
+    #[test]
+    fn test_boot_rom_size() {
+        assert_eq!(false, Model::Sgb.is_cgb());
+        assert_eq!(DMG_BOOT_ROM_SIZE, Model::Mgb.boot_rom_size());
+        assert_eq!(true, Model::Agb.is_cgb());
+        assert_eq!(CGB_BOOT_ROM_SIZE, Model::Cgb.boot_rom_size());
+    }
+}
@@ -0,0 +1,1024 @@
+use super::data::Address;
+use super::interrupts::Interrupt;
+use super::memory::OPEN_BUS;
+
+mod fifo;
+mod frame;
+mod sprites;
+
+use fifo::PixelFifo;
+pub use frame::{FrameBuffer, Palette};
+use sprites::Sprite;
+
+pub const SCREEN_WIDTH: usize = 160;
+pub const SCREEN_HEIGHT: usize = 144;
+
+pub const DOTS_PER_LINE: u16 = 456;
+pub const LINES_PER_FRAME: u8 = 154;
+
+pub const LCDC: Address = 0xFF40;
+pub const STAT: Address = 0xFF41;
+pub const SCY: Address = 0xFF42;
+pub const SCX: Address = 0xFF43;
+pub const LY: Address = 0xFF44;
+pub const LYC: Address = 0xFF45;
+pub const BGP: Address = 0xFF47;
+pub const OBP0: Address = 0xFF48;
+pub const OBP1: Address = 0xFF49;
+pub const WY: Address = 0xFF4A;
+pub const WX: Address = 0xFF4B;
+
+const VRAM_SIZE: usize = 0x2000;
+const OAM_SIZE: usize = 0xA0;
+
+const OAM_SCAN_DOTS: u16 = 80;
+// Drawing takes at least this long, plus a penalty for anything that stalls the pixel output
+const DRAWING_DOTS: u16 = 172;
+
+// LCDC bits
+const LCD_ENABLE: u8 = 0x80;
+const WINDOW_TILE_MAP: u8 = 0x40;
+const WINDOW_ENABLE: u8 = 0x20;
+const TILE_DATA: u8 = 0x10;
+const BG_TILE_MAP: u8 = 0x08;
+const OBJ_SIZE: u8 = 0x04;
+const OBJ_ENABLE: u8 = 0x02;
+// On the DMG this blanks both the background and the window
+const BG_ENABLE: u8 = 0x01;
+
+// STAT bits, the interrupt sources are the only writable ones
+const LYC_INTERRUPT: u8 = 0x40;
+const OAM_SCAN_INTERRUPT: u8 = 0x20;
+const VBLANK_INTERRUPT: u8 = 0x10;
+const HBLANK_INTERRUPT: u8 = 0x08;
+const LYC_EQUAL: u8 = 0x04;
+const STAT_WRITABLE: u8 = 0x78;
+
+// The window is drawn from WX - 7, so WX 7 puts it at the left edge of the screen
+const WINDOW_X_OFFSET: u8 = 7;
+
+// On line 153 LY already reads 0 a few dots in, and LYC is compared against that
+const LAST_LINE_LY_DOTS: u16 = 4;
+
+// How closely drawing follows the hardware
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub enum Accuracy {
+    // Draw each line in one go at the end of mode 3. Fast, but changes to the registers in the
+    // middle of a line only show up on the next one
+    #[default]
+    Scanline,
+    // Shift pixels out a dot at a time through a FIFO as the hardware does, so mid-line changes
+    // land on the right pixel and mode 3 takes as long as it really would
+    PixelFifo,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Mode {
+    HBlank = 0,
+    VBlank = 1,
+    OamScan = 2,
+    Drawing = 3,
+}
+
+// The picture processing unit, drawing a line at a time into a frame of shade indices from 0
+// (lightest) to 3 (darkest) after going through the palettes. It owns VRAM and OAM, which the CPU
+// can't reach while they're being read for drawing
+#[derive(Debug)]
+pub struct Ppu {
+    vram: Vec<u8>,
+    oam: Vec<u8>,
+    lcdc: u8,
+    // Only the interrupt source bits, the rest of STAT is worked out as it's read
+    stat: u8,
+    scy: u8,
+    scx: u8,
+    lyc: u8,
+    bgp: u8,
+    obp0: u8,
+    obp1: u8,
+    wy: u8,
+    wx: u8,
+    mode: Mode,
+    line: u8,
+    dot: u16,
+    // Where drawing finishes on the current line
+    drawing_end: u16,
+    // The LY=LYC flag is only updated while the LCD is on, and keeps its last value when it's off
+    lyc_equal: bool,
+    // The STAT interrupt is requested when any of its enabled sources becomes active, and not
+    // again until all of them have gone inactive
+    stat_line: bool,
+    // The window only shows up once LY has matched WY during the frame, and then draws its own
+    // lines in order however much WY changes afterwards
+    window_triggered: bool,
+    window_line: u8,
+    // The sprites picked by the OAM scan for the current line, in OAM order
+    sprites: Vec<Sprite>,
+    // The CGB orders overlapping sprites differently
+    cgb_mode: bool,
+    accuracy: Accuracy,
+    fifo: PixelFifo,
+    frame: Vec<u8>,
+    // The frame finished at the last VBlank, until it's taken
+    finished_frame: Option<FrameBuffer>,
+}
+
+impl Ppu {
+    pub fn mode(&self) -> Mode {
+        self.mode
+    }
+
+    pub fn ly(&self) -> u8 {
+        match (self.line, self.dot) {
+            (153, dot) if dot >= LAST_LINE_LY_DOTS => 0,
+            (line, _) => line,
+        }
+    }
+
+    pub fn accuracy(&self) -> Accuracy {
+        self.accuracy
+    }
+
+    pub fn set_accuracy(&mut self, accuracy: Accuracy) {
+        self.accuracy = accuracy;
+    }
+
+    pub(crate) fn set_cgb_mode(&mut self, cgb_mode: bool) {
+        self.cgb_mode = cgb_mode;
+    }
+
+    pub fn lcd_enabled(&self) -> bool {
+        self.lcdc & LCD_ENABLE != 0
+    }
+
+    // The frame as it's being drawn, a row at a time from the top left
+    pub fn frame(&self) -> &[u8] {
+        &self.frame
+    }
+
+    // Whether a frame has been finished since the last one was taken
+    pub fn frame_ready(&self) -> bool {
+        self.finished_frame.is_some()
+    }
+
+    // Takes the frame finished at the last VBlank, so each one is only handed out once. Frames
+    // that aren't taken before the next VBlank are dropped, and none are finished while the LCD
+    // is off
+    pub fn take_frame(&mut self) -> Option<FrameBuffer> {
+        self.finished_frame.take()
+    }
+
+    pub(crate) fn read_register(&self, addr: Address) -> u8 {
+        match addr {
+            LCDC => self.lcdc,
+            STAT => {
+                let lyc_equal = match self.lyc_equal {
+                    true => LYC_EQUAL,
+                    false => 0,
+                };
+                // The mode reads as 0 while the LCD is off
+                let mode = match self.lcd_enabled() {
+                    true => self.mode as u8,
+                    false => 0,
+                };
+                0x80 | self.stat | lyc_equal | mode
+            }
+            SCY => self.scy,
+            SCX => self.scx,
+            LY => self.ly(),
+            LYC => self.lyc,
+            BGP => self.bgp,
+            OBP0 => self.obp0,
+            OBP1 => self.obp1,
+            WY => self.wy,
+            WX => self.wx,
+            _ => OPEN_BUS,
+        }
+    }
+
+    // Returns the interrupts requested by the write, since changing LYC or the STAT sources can
+    // raise the STAT interrupt straight away
+    pub(crate) fn write_register(&mut self, addr: Address, data: u8) -> u8 {
+        match addr {
+            LCDC => self.write_lcdc(data),
+            STAT => self.stat = data & STAT_WRITABLE,
+            SCY => self.scy = data,
+            SCX => self.scx = data,
+            // LY is read only
+            LY => {}
+            LYC => self.lyc = data,
+            BGP => self.bgp = data,
+            OBP0 => self.obp0 = data,
+            OBP1 => self.obp1 = data,
+            WY => self.wy = data,
+            WX => self.wx = data,
+            _ => {}
+        }
+        match self.lcd_enabled() {
+            true => self.update_stat_line(),
+            false => 0,
+        }
+    }
+
+    fn write_lcdc(&mut self, data: u8) {
+        let was_enabled = self.lcd_enabled();
+        self.lcdc = data;
+        match (was_enabled, self.lcd_enabled()) {
+            // Switching off resets the line and leaves a blank screen
+            (true, false) => {
+                self.line = 0;
+                self.dot = 0;
+                self.mode = Mode::HBlank;
+                self.frame.fill(0);
+            }
+            (false, true) => self.start_frame(),
+            _ => {}
+        }
+    }
+
+    pub(crate) fn read_vram(&self, addr: Address) -> u8 {
+        match self.mode {
+            Mode::Drawing if self.lcd_enabled() => OPEN_BUS,
+            _ => self.vram[addr as usize & (VRAM_SIZE - 1)],
+        }
+    }
+
+    pub(crate) fn write_vram(&mut self, addr: Address, data: u8) {
+        if self.mode != Mode::Drawing || !self.lcd_enabled() {
+            self.vram[addr as usize & (VRAM_SIZE - 1)] = data;
+        }
+    }
+
+    pub(crate) fn read_oam(&self, addr: Address) -> u8 {
+        match self.mode {
+            Mode::OamScan | Mode::Drawing if self.lcd_enabled() => OPEN_BUS,
+            _ => self.oam[addr as usize - 0xFE00],
+        }
+    }
+
+    pub(crate) fn write_oam(&mut self, addr: Address, data: u8) {
+        if !matches!(self.mode, Mode::OamScan | Mode::Drawing) || !self.lcd_enabled() {
+            self.oam[addr as usize - 0xFE00] = data;
+        }
+    }
+
+    // OAM DMA writes get through whatever the PPU is doing
+    pub(crate) fn write_oam_dma(&mut self, offset: u8, data: u8) {
+        self.oam[offset as usize] = data;
+    }
+
+    // Puts the PPU where the boot ROM leaves it, in the last line of VBlank with LY already
+    // reading 0
+    pub(crate) fn skip_boot(&mut self) {
+        self.line = LINES_PER_FRAME - 1;
+        self.dot = LAST_LINE_LY_DOTS;
+        self.mode = Mode::VBlank;
+        self.lyc_equal = self.ly() == self.lyc;
+        self.stat_line = true;
+    }
+
+    // Advances by the given number of dots, returning the mask of interrupts requested
+    pub(crate) fn tick(&mut self, cycles: u8) -> u8 {
+        if !self.lcd_enabled() {
+            return 0;
+        }
+
+        let mut interrupts = 0;
+        for _ in 0..cycles {
+            interrupts |= self.tick_dot();
+        }
+        interrupts
+    }
+
+    fn tick_dot(&mut self) -> u8 {
+        let mut interrupts = 0;
+        self.dot += 1;
+
+        if self.dot == DOTS_PER_LINE {
+            self.dot = 0;
+            self.line += 1;
+            match self.line {
+                LINES_PER_FRAME => self.start_frame(),
+                line if line as usize == SCREEN_HEIGHT => {
+                    self.mode = Mode::VBlank;
+                    self.finished_frame = Some(FrameBuffer::new(self.frame.clone()));
+                    interrupts |= Interrupt::VBlank.mask();
+                }
+                line if (line as usize) < SCREEN_HEIGHT => self.start_line(),
+                _ => {}
+            }
+        } else if self.mode == Mode::OamScan && self.dot == OAM_SCAN_DOTS {
+            self.mode = Mode::Drawing;
+            self.scan_oam();
+            match self.accuracy {
+                // Fine scrolling throws away the first few pixels of the line, and sprites stall
+                // the fetcher
+                Accuracy::Scanline => {
+                    self.drawing_end = OAM_SCAN_DOTS
+                        + DRAWING_DOTS
+                        + (self.scx & 0x07) as u16
+                        + self.sprite_penalty()
+                }
+                Accuracy::PixelFifo => self.start_fifo_line(),
+            }
+        } else if self.mode == Mode::Drawing {
+            let finished = match self.accuracy {
+                Accuracy::Scanline if self.dot == self.drawing_end => {
+                    self.render_line();
+                    true
+                }
+                Accuracy::Scanline => false,
+                Accuracy::PixelFifo => self.tick_fifo(),
+            };
+            if finished {
+                self.mode = Mode::HBlank;
+            }
+        }
+
+        interrupts | self.update_stat_line()
+    }
+
+    fn start_frame(&mut self) {
+        self.line = 0;
+        self.dot = 0;
+        self.window_triggered = false;
+        self.window_line = 0;
+        self.start_line();
+    }
+
+    fn start_line(&mut self) {
+        self.mode = Mode::OamScan;
+        if self.line == self.wy {
+            self.window_triggered = true;
+        }
+    }
+
+    // Works out the STAT interrupt line from the current state, returning the STAT interrupt if
+    // it has just gone high
+    fn update_stat_line(&mut self) -> u8 {
+        self.lyc_equal = self.ly() == self.lyc;
+        let line = (self.lyc_equal && self.stat & LYC_INTERRUPT != 0)
+            || match self.mode {
+                Mode::HBlank => self.stat & HBLANK_INTERRUPT != 0,
+                Mode::VBlank => self.stat & VBLANK_INTERRUPT != 0,
+                Mode::OamScan => self.stat & OAM_SCAN_INTERRUPT != 0,
+                Mode::Drawing => false,
+            };
+
+        let rising = line && !self.stat_line;
+        self.stat_line = line;
+        match rising {
+            true => Interrupt::LcdStat.mask(),
+            false => 0,
+        }
+    }
+
+    fn render_line(&mut self) {
+        let y = self.line as usize;
+        let mut colors = [0; SCREEN_WIDTH];
+
+        if self.lcdc & BG_ENABLE != 0 {
+            self.render_background(&mut colors);
+            self.render_window(&mut colors);
+        }
+
+        let mut row = [0; SCREEN_WIDTH];
+        self.render_sprites(&colors, &mut row);
+        self.frame[y * SCREEN_WIDTH..(y + 1) * SCREEN_WIDTH].copy_from_slice(&row);
+    }
+
+    fn render_background(&self, colors: &mut [u8; SCREEN_WIDTH]) {
+        let map = match self.lcdc & BG_TILE_MAP {
+            0 => 0x9800,
+            _ => 0x9C00,
+        };
+        let y = self.line.wrapping_add(self.scy);
+        for (x, color) in colors.iter_mut().enumerate() {
+            *color = self.tile_map_pixel(map, (x as u8).wrapping_add(self.scx), y);
+        }
+    }
+
+    fn render_window(&mut self, colors: &mut [u8; SCREEN_WIDTH]) {
+        if self.lcdc & WINDOW_ENABLE == 0 || !self.window_triggered {
+            return;
+        }
+        let start = self.wx as i16 - WINDOW_X_OFFSET as i16;
+        if start >= SCREEN_WIDTH as i16 {
+            return;
+        }
+
+        let map = match self.lcdc & WINDOW_TILE_MAP {
+            0 => 0x9800,
+            _ => 0x9C00,
+        };
+        for (x, color) in colors.iter_mut().enumerate().skip(start.max(0) as usize) {
+            let window_x = (x as i16 - start) as u8;
+            *color = self.tile_map_pixel(map, window_x, self.window_line);
+        }
+        self.window_line += 1;
+    }
+
+    // The colour index of a pixel in a 256x256 tile map
+    fn tile_map_pixel(&self, map: Address, x: u8, y: u8) -> u8 {
+        let index = self.vram_byte(map + (y as Address / 8) * 32 + x as Address / 8);
+        let (low, high) = self.tile_row(index, y % 8);
+        let bit = 7 - x % 8;
+        (high >> bit & 0x01) << 1 | (low >> bit & 0x01)
+    }
+
+    // The two bit planes of a row of a background or window tile. Tiles 0-127 come from 0x9000 or
+    // 0x8000 depending on LCDC, 128-255 always come from 0x8800
+    fn tile_row(&self, index: u8, row: u8) -> (u8, u8) {
+        let base = match (self.lcdc & TILE_DATA, index) {
+            (0, 0x00..=0x7F) => 0x9000,
+            _ => 0x8000,
+        };
+        let addr = base + index as Address * 16 + row as Address * 2;
+        (self.vram_byte(addr), self.vram_byte(addr + 1))
+    }
+
+    fn vram_byte(&self, addr: Address) -> u8 {
+        self.vram[addr as usize & (VRAM_SIZE - 1)]
+    }
+}
+
+impl Default for Ppu {
+    fn default() -> Ppu {
+        Ppu {
+            vram: vec![0; VRAM_SIZE],
+            oam: vec![0; OAM_SIZE],
+            lcdc: 0,
+            stat: 0,
+            scy: 0,
+            scx: 0,
+            lyc: 0,
+            bgp: 0,
+            obp0: 0,
+            obp1: 0,
+            wy: 0,
+            wx: 0,
+            mode: Mode::HBlank,
+            line: 0,
+            dot: 0,
+            drawing_end: 0,
+            lyc_equal: false,
+            stat_line: false,
+            window_triggered: false,
+            window_line: 0,
+            sprites: Vec::with_capacity(sprites::MAX_SPRITES_PER_LINE),
+            cgb_mode: false,
+            accuracy: Accuracy::default(),
+            fifo: PixelFifo::default(),
+            frame: vec![0; SCREEN_WIDTH * SCREEN_HEIGHT],
+            finished_frame: None,
+        }
+    }
+}
+
+// Looks up a colour index in a palette register, which holds 2 bits for each
+fn shade(palette: u8, color: u8) -> u8 {
+    palette >> (color * 2) & 0x03
+}
+
+#[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
+mod tests {
+    use super::*;
+
+    const DOTS_PER_FRAME: u32 = DOTS_PER_LINE as u32 * LINES_PER_FRAME as u32;
+
+    fn run(ppu: &mut Ppu, dots: u32) -> u8 {
+        let mut interrupts = 0;
+        for _ in 0..dots / 4 {
+            interrupts |= ppu.tick(4);
+        }
+        interrupts | ppu.tick((dots % 4) as u8)
+    }
+
+    fn enable(ppu: &mut Ppu) {
+        ppu.write_register(BGP, 0xE4);
+        ppu.write_register(LCDC, LCD_ENABLE | TILE_DATA | BG_ENABLE);
+    }
+
+    // Tile 1 is solid colour 1 and tile 2 solid colour 3, with tile 1 at the top left of both maps
+    const ACCURACIES: [Accuracy; 2] = [Accuracy::Scanline, Accuracy::PixelFifo];
+
+    fn tiles(accuracy: Accuracy) -> Ppu {
+        let mut ppu = Ppu::default();
+        ppu.set_accuracy(accuracy);
+        for row in 0..8 {
+            ppu.write_vram(0x8010 + row * 2, 0xFF);
+            ppu.write_vram(0x8020 + row * 2, 0xFF);
+            ppu.write_vram(0x8021 + row * 2, 0xFF);
+        }
+        ppu.write_vram(0x9800, 0x01);
+        ppu.write_vram(0x9C00, 0x02);
+        ppu
+    }
+
+    fn pixel(ppu: &Ppu, x: usize, y: usize) -> u8 {
+        ppu.frame()[y * SCREEN_WIDTH + x]
+    }
+
+    #[test]
+    fn test_mode_timing() {
+        let mut ppu = Ppu::default();
+        enable(&mut ppu);
+        assert_eq!(Mode::OamScan, ppu.mode());
+
+        run(&mut ppu, 80);
+        assert_eq!(Mode::Drawing, ppu.mode());
+        run(&mut ppu, 172);
+        assert_eq!(Mode::HBlank, ppu.mode());
+        run(&mut ppu, 204);
+        assert_eq!(Mode::OamScan, ppu.mode());
+        assert_eq!(1, ppu.ly());
+
+        // VBlank starts after 144 lines and lasts 10
+        let interrupts = run(&mut ppu, 143 * DOTS_PER_LINE as u32);
+        assert_eq!(Mode::VBlank, ppu.mode());
+        assert_eq!(144, ppu.ly());
+        assert_eq!(Interrupt::VBlank.mask(), interrupts);
+
+        run(&mut ppu, 10 * DOTS_PER_LINE as u32);
+        assert_eq!(Mode::OamScan, ppu.mode());
+        assert_eq!(0, ppu.ly());
+        assert_eq!(0x82, ppu.read_register(STAT) & 0x83);
+    }
+
+    #[test]
+    fn test_fine_scroll_drawing_length() {
+        for accuracy in ACCURACIES {
+            let mut ppu = Ppu::default();
+            ppu.set_accuracy(accuracy);
+            ppu.write_register(SCX, 0x03);
+            enable(&mut ppu);
+
+            run(&mut ppu, 80 + 172);
+            assert_eq!(Mode::Drawing, ppu.mode());
+            run(&mut ppu, 3);
+            assert_eq!(Mode::HBlank, ppu.mode());
+        }
+    }
+
+    #[test]
+    fn test_take_frame() {
+        let mut ppu = tiles(Accuracy::Scanline);
+        enable(&mut ppu);
+        assert_eq!(false, ppu.frame_ready());
+
+        run(&mut ppu, SCREEN_HEIGHT as u32 * DOTS_PER_LINE as u32);
+        assert_eq!(true, ppu.frame_ready());
+        let frame = ppu.take_frame().unwrap();
+        assert_eq!(1, frame.pixel(0, 0));
+        assert_eq!(0, frame.pixel(8, 0));
+        assert_eq!(false, ppu.frame_ready());
+        assert_eq!(None, ppu.take_frame());
+
+        // One frame per VBlank
+        run(&mut ppu, DOTS_PER_FRAME - 1);
+        assert_eq!(false, ppu.frame_ready());
+        run(&mut ppu, 1);
+        assert_eq!(true, ppu.frame_ready());
+    }
+
+    #[test]
+    fn test_last_line() {
+        let mut ppu = Ppu::default();
+        enable(&mut ppu);
+
+        run(&mut ppu, 153 * DOTS_PER_LINE as u32);
+        assert_eq!(153, ppu.ly());
+        run(&mut ppu, 4);
+        assert_eq!(0, ppu.ly());
+        assert_eq!(Mode::VBlank, ppu.mode());
+    }
+
+    #[test]
+    fn test_lcd_off() {
+        let mut ppu = Ppu::default();
+        enable(&mut ppu);
+        run(&mut ppu, 3 * DOTS_PER_LINE as u32);
+
+        ppu.write_register(LCDC, 0x00);
+        assert_eq!(0, ppu.ly());
+        assert_eq!(0x80, ppu.read_register(STAT) & 0x83);
+        assert_eq!(0, run(&mut ppu, DOTS_PER_FRAME));
+        assert_eq!(0, ppu.ly());
+    }
+
+    #[test]
+    fn test_lyc_interrupt() {
+        let mut ppu = Ppu::default();
+        ppu.write_register(LYC, 2);
+        ppu.write_register(STAT, LYC_INTERRUPT);
+        enable(&mut ppu);
+
+        assert_eq!(0, run(&mut ppu, 2 * DOTS_PER_LINE as u32 - 4));
+        assert_eq!(0x00, ppu.read_register(STAT) & LYC_EQUAL);
+        assert_eq!(Interrupt::LcdStat.mask(), run(&mut ppu, 4));
+        assert_eq!(LYC_EQUAL, ppu.read_register(STAT) & LYC_EQUAL);
+
+        // Matching LY again by writing LYC doesn't raise it twice while the line is still high
+        assert_eq!(0, ppu.write_register(LYC, 2));
+        run(&mut ppu, DOTS_PER_LINE as u32);
+        assert_eq!(Interrupt::LcdStat.mask(), ppu.write_register(LYC, 3));
+    }
+
+    #[test]
+    fn test_stat_blocking() {
+        let mut ppu = Ppu::default();
+        ppu.write_register(STAT, HBLANK_INTERRUPT | OAM_SCAN_INTERRUPT);
+        enable(&mut ppu);
+
+        // HBlank runs straight into the next line's OAM scan without the line going low
+        assert_eq!(Interrupt::LcdStat.mask(), run(&mut ppu, 252));
+        assert_eq!(0, run(&mut ppu, 204));
+        assert_eq!(Mode::OamScan, ppu.mode());
+        assert_eq!(0, run(&mut ppu, 80));
+        assert_eq!(Interrupt::LcdStat.mask(), run(&mut ppu, 172));
+    }
+
+    #[test]
+    fn test_vram_oam_access() {
+        let mut ppu = Ppu::default();
+        ppu.write_vram(0x8000, 0xAA);
+        ppu.write_oam(0xFE00, 0xBB);
+        enable(&mut ppu);
+
+        assert_eq!(0xAA, ppu.read_vram(0x8000));
+        assert_eq!(OPEN_BUS, ppu.read_oam(0xFE00));
+        run(&mut ppu, 80);
+        assert_eq!(OPEN_BUS, ppu.read_vram(0x8000));
+        ppu.write_vram(0x8000, 0x00);
+        run(&mut ppu, 172);
+        assert_eq!(0xAA, ppu.read_vram(0x8000));
+        assert_eq!(0xBB, ppu.read_oam(0xFE00));
+    }
+
+    #[test]
+    fn test_background() {
+        for accuracy in ACCURACIES {
+            let mut ppu = tiles(accuracy);
+            ppu.write_register(SCX, 2);
+            ppu.write_register(SCY, 2);
+            enable(&mut ppu);
+            run(&mut ppu, DOTS_PER_FRAME);
+
+            assert_eq!(1, pixel(&ppu, 0, 0));
+            assert_eq!(1, pixel(&ppu, 5, 5));
+            assert_eq!(0, pixel(&ppu, 6, 0));
+            assert_eq!(0, pixel(&ppu, 0, 6));
+
+            // Scrolling wraps around the 256x256 map
+            ppu.write_register(SCX, 252);
+            run(&mut ppu, DOTS_PER_FRAME);
+            assert_eq!(0, pixel(&ppu, 3, 0));
+            assert_eq!(1, pixel(&ppu, 4, 0));
+            assert_eq!(1, pixel(&ppu, 11, 0));
+
+            // Palettes are applied to the colour index
+            ppu.write_register(BGP, 0x0C);
+            run(&mut ppu, DOTS_PER_FRAME);
+            assert_eq!(3, pixel(&ppu, 4, 0));
+            ppu.write_register(LCDC, LCD_ENABLE | TILE_DATA);
+            run(&mut ppu, DOTS_PER_FRAME);
+            assert_eq!(0, pixel(&ppu, 4, 0));
+        }
+    }
+
+    #[test]
+    fn test_signed_tile_data() {
+        for accuracy in ACCURACIES {
+            let mut ppu = tiles(accuracy);
+            for row in 0..8 {
+                ppu.write_vram(0x9010 + row * 2, 0x00);
+                ppu.write_vram(0x9011 + row * 2, 0xFF);
+            }
+            ppu.write_register(BGP, 0xE4);
+            ppu.write_register(LCDC, LCD_ENABLE | BG_ENABLE);
+            run(&mut ppu, DOTS_PER_FRAME);
+
+            assert_eq!(2, pixel(&ppu, 0, 0));
+        }
+    }
+
+    #[test]
+    fn test_window() {
+        for accuracy in ACCURACIES {
+            let mut ppu = tiles(accuracy);
+            ppu.write_register(WY, 8);
+            ppu.write_register(WX, 7 + 16);
+            ppu.write_register(BGP, 0xE4);
+            ppu.write_register(
+                LCDC,
+                LCD_ENABLE | WINDOW_TILE_MAP | WINDOW_ENABLE | TILE_DATA | BG_ENABLE,
+            );
+            run(&mut ppu, DOTS_PER_FRAME);
+
+            assert_eq!(1, pixel(&ppu, 7, 7));
+            assert_eq!(0, pixel(&ppu, 20, 7));
+            assert_eq!(0, pixel(&ppu, 15, 8));
+            assert_eq!(3, pixel(&ppu, 16, 8));
+            assert_eq!(3, pixel(&ppu, 23, 15));
+            assert_eq!(0, pixel(&ppu, 24, 16));
+        }
+    }
+
+    #[test]
+    fn test_window_line_counter() {
+        for accuracy in ACCURACIES {
+            let mut ppu = tiles(accuracy);
+            ppu.write_register(WX, 7);
+            ppu.write_register(BGP, 0xE4);
+            ppu.write_register(
+                LCDC,
+                LCD_ENABLE | WINDOW_TILE_MAP | WINDOW_ENABLE | TILE_DATA | BG_ENABLE,
+            );
+
+            // Hiding the window for a few lines holds its line counter, so it carries on where it
+            // left off rather than skipping those lines
+            run(&mut ppu, 4 * DOTS_PER_LINE as u32);
+            ppu.write_register(WX, 200);
+            run(&mut ppu, 4 * DOTS_PER_LINE as u32);
+            ppu.write_register(WX, 7);
+            run(&mut ppu, 4 * DOTS_PER_LINE as u32);
+
+            assert_eq!(3, pixel(&ppu, 0, 3));
+            assert_eq!(1, pixel(&ppu, 0, 4));
+            assert_eq!(3, pixel(&ppu, 0, 8));
+            assert_eq!(3, pixel(&ppu, 0, 11));
+        }
+    }
+
+    #[test]
+    fn test_fifo_mid_line_palette() {
+        let mut ppu = tiles(Accuracy::PixelFifo);
+        for x in 0..32 {
+            ppu.write_vram(0x9800 + x, 0x02);
+        }
+        enable(&mut ppu);
+
+        // Pixels start coming out 12 dots into mode 3, one per dot
+        run(&mut ppu, 80 + 12 + 40);
+        ppu.write_register(BGP, 0x00);
+        run(&mut ppu, DOTS_PER_LINE as u32);
+
+        assert_eq!(3, pixel(&ppu, 39, 0));
+        assert_eq!(0, pixel(&ppu, 40, 0));
+        assert_eq!(0, pixel(&ppu, 159, 0));
+    }
+
+    #[test]
+    fn test_fifo_window_penalty() {
+        let mut ppu = tiles(Accuracy::PixelFifo);
+        ppu.write_register(WX, 7 + 80);
+        ppu.write_register(BGP, 0xE4);
+        ppu.write_register(
+            LCDC,
+            LCD_ENABLE | WINDOW_TILE_MAP | WINDOW_ENABLE | TILE_DATA | BG_ENABLE,
+        );
+
+        // Fetching the first window tile holds up drawing
+        run(&mut ppu, 80 + 172);
+        assert_eq!(Mode::Drawing, ppu.mode());
+        run(&mut ppu, 6);
+        assert_eq!(Mode::HBlank, ppu.mode());
+        assert_eq!(0, pixel(&ppu, 79, 0));
+        assert_eq!(3, pixel(&ppu, 80, 0));
+    }
+
+    #[test]
+    fn test_fifo_window_disabled_by_bg() {
+        let mut ppu = tiles(Accuracy::PixelFifo);
+        ppu.write_register(WX, 7 + 80);
+        ppu.write_register(BGP, 0xE4);
+        ppu.write_register(LCDC, LCD_ENABLE | WINDOW_ENABLE | TILE_DATA);
+
+        // Clearing the BG enable bit on the DMG takes the window with it, along with its penalty
+        run(&mut ppu, 80 + 172);
+        assert_eq!(Mode::HBlank, ppu.mode());
+    }
+
+    // On top of the background tiles, tile 3 has a top row of colour 3 over rows split between
+    // colour 1 on the left and 2 on the right, tiles 4 and 5 are solid colour 2 and 1, and tile 6
+    // is transparent on the left and colour 1 on the right
+    fn sprite_tiles(accuracy: Accuracy) -> Ppu {
+        let mut ppu = tiles(accuracy);
+        ppu.write_vram(0x8030, 0xFF);
+        ppu.write_vram(0x8031, 0xFF);
+        for row in 1..8 {
+            ppu.write_vram(0x8030 + row * 2, 0xF0);
+            ppu.write_vram(0x8031 + row * 2, 0x0F);
+        }
+        for row in 0..8 {
+            ppu.write_vram(0x8041 + row * 2, 0xFF);
+            ppu.write_vram(0x8050 + row * 2, 0xFF);
+            ppu.write_vram(0x8060 + row * 2, 0x0F);
+        }
+        ppu
+    }
+
+    // Writes straight to OAM, which the CPU can't reach while the LCD is on
+    fn sprite(ppu: &mut Ppu, index: usize, y: u8, x: u8, tile: u8, attributes: u8) {
+        ppu.oam[index * 4..index * 4 + 4].copy_from_slice(&[y, x, tile, attributes]);
+    }
+
+    fn draw_sprites(ppu: &mut Ppu, lcdc: u8) {
+        ppu.write_register(LCDC, 0x00);
+        ppu.write_register(BGP, 0xE4);
+        ppu.write_register(OBP0, 0xE4);
+        ppu.write_register(OBP1, 0x1B);
+        ppu.write_register(LCDC, LCD_ENABLE | TILE_DATA | lcdc);
+        run(ppu, DOTS_PER_FRAME);
+    }
+
+    #[test]
+    fn test_sprites() {
+        for accuracy in ACCURACIES {
+            let mut ppu = sprite_tiles(accuracy);
+            sprite(&mut ppu, 0, 24, 24, 3, 0x00);
+            draw_sprites(&mut ppu, OBJ_ENABLE | BG_ENABLE);
+
+            assert_eq!(3, pixel(&ppu, 16, 8));
+            assert_eq!(1, pixel(&ppu, 16, 9));
+            assert_eq!(2, pixel(&ppu, 20, 9));
+            assert_eq!(0, pixel(&ppu, 15, 9));
+            assert_eq!(0, pixel(&ppu, 24, 9));
+            assert_eq!(0, pixel(&ppu, 16, 16));
+
+            draw_sprites(&mut ppu, BG_ENABLE);
+            assert_eq!(0, pixel(&ppu, 16, 9));
+
+            // The second palette
+            sprite(&mut ppu, 0, 24, 24, 3, 0x10);
+            draw_sprites(&mut ppu, OBJ_ENABLE | BG_ENABLE);
+            assert_eq!(2, pixel(&ppu, 16, 9));
+            assert_eq!(1, pixel(&ppu, 20, 9));
+        }
+    }
+
+    #[test]
+    fn test_sprite_flip() {
+        for accuracy in ACCURACIES {
+            let mut ppu = sprite_tiles(accuracy);
+            sprite(&mut ppu, 0, 24, 24, 3, 0x20);
+            draw_sprites(&mut ppu, OBJ_ENABLE | BG_ENABLE);
+            assert_eq!(2, pixel(&ppu, 16, 9));
+            assert_eq!(1, pixel(&ppu, 20, 9));
+
+            sprite(&mut ppu, 0, 24, 24, 3, 0x40);
+            draw_sprites(&mut ppu, OBJ_ENABLE | BG_ENABLE);
+            assert_eq!(1, pixel(&ppu, 16, 8));
+            assert_eq!(3, pixel(&ppu, 16, 15));
+
+            sprite(&mut ppu, 0, 24, 24, 3, 0x60);
+            draw_sprites(&mut ppu, OBJ_ENABLE | BG_ENABLE);
+            assert_eq!(2, pixel(&ppu, 16, 8));
+            assert_eq!(3, pixel(&ppu, 16, 15));
+        }
+    }
+
+    #[test]
+    fn test_tall_sprites() {
+        for accuracy in ACCURACIES {
+            let mut ppu = sprite_tiles(accuracy);
+            sprite(&mut ppu, 0, 24, 24, 5, 0x00);
+            draw_sprites(&mut ppu, OBJ_ENABLE | BG_ENABLE);
+            assert_eq!(1, pixel(&ppu, 16, 8));
+            assert_eq!(0, pixel(&ppu, 16, 16));
+
+            // The bottom bit of the tile index is ignored, so tile 5 is the bottom half of 4
+            draw_sprites(&mut ppu, OBJ_SIZE | OBJ_ENABLE | BG_ENABLE);
+            assert_eq!(2, pixel(&ppu, 16, 8));
+            assert_eq!(1, pixel(&ppu, 16, 16));
+            assert_eq!(1, pixel(&ppu, 16, 23));
+            assert_eq!(0, pixel(&ppu, 16, 24));
+
+            // Flipping swaps the two tiles
+            sprite(&mut ppu, 0, 24, 24, 5, 0x40);
+            draw_sprites(&mut ppu, OBJ_SIZE | OBJ_ENABLE | BG_ENABLE);
+            assert_eq!(1, pixel(&ppu, 16, 8));
+            assert_eq!(2, pixel(&ppu, 16, 23));
+        }
+    }
+
+    #[test]
+    fn test_sprite_priority() {
+        for accuracy in ACCURACIES {
+            // The DMG draws the leftmost sprite on top
+            let mut ppu = sprite_tiles(accuracy);
+            sprite(&mut ppu, 0, 24, 20, 4, 0x00);
+            sprite(&mut ppu, 1, 24, 16, 5, 0x00);
+            draw_sprites(&mut ppu, OBJ_ENABLE | BG_ENABLE);
+            assert_eq!(1, pixel(&ppu, 8, 8));
+            assert_eq!(1, pixel(&ppu, 12, 8));
+            assert_eq!(2, pixel(&ppu, 18, 8));
+
+            // The CGB only goes by OAM
+            ppu.set_cgb_mode(true);
+            draw_sprites(&mut ppu, OBJ_ENABLE | BG_ENABLE);
+            assert_eq!(1, pixel(&ppu, 8, 8));
+            assert_eq!(2, pixel(&ppu, 12, 8));
+            ppu.set_cgb_mode(false);
+
+            // With the same X, the first in OAM is on top
+            sprite(&mut ppu, 0, 24, 16, 4, 0x00);
+            draw_sprites(&mut ppu, OBJ_ENABLE | BG_ENABLE);
+            assert_eq!(2, pixel(&ppu, 8, 8));
+
+            // Colour 0 is transparent, showing the sprite underneath
+            sprite(&mut ppu, 0, 24, 16, 6, 0x00);
+            sprite(&mut ppu, 1, 24, 16, 4, 0x00);
+            draw_sprites(&mut ppu, OBJ_ENABLE | BG_ENABLE);
+            assert_eq!(2, pixel(&ppu, 8, 8));
+            assert_eq!(1, pixel(&ppu, 12, 8));
+        }
+    }
+
+    #[test]
+    fn test_bg_over_obj() {
+        for accuracy in ACCURACIES {
+            // The background has colour 1 in the top left tile and 0 elsewhere
+            let mut ppu = sprite_tiles(accuracy);
+            sprite(&mut ppu, 0, 16, 8, 4, 0x80);
+            sprite(&mut ppu, 1, 16, 16, 4, 0x80);
+            draw_sprites(&mut ppu, OBJ_ENABLE | BG_ENABLE);
+            assert_eq!(1, pixel(&ppu, 0, 0));
+            assert_eq!(2, pixel(&ppu, 8, 0));
+
+            draw_sprites(&mut ppu, OBJ_ENABLE);
+            assert_eq!(2, pixel(&ppu, 0, 0));
+
+            sprite(&mut ppu, 0, 16, 8, 4, 0x00);
+            draw_sprites(&mut ppu, OBJ_ENABLE | BG_ENABLE);
+            assert_eq!(2, pixel(&ppu, 0, 0));
+        }
+    }
+
+    #[test]
+    fn test_sprites_per_line() {
+        for accuracy in ACCURACIES {
+            // The first sprite is off screen but still counts towards the 10
+            let mut ppu = sprite_tiles(accuracy);
+            sprite(&mut ppu, 0, 24, 0, 4, 0x00);
+            for index in 1..=10 {
+                sprite(&mut ppu, index, 24, 8 + 8 * index as u8, 4, 0x00);
+            }
+            draw_sprites(&mut ppu, OBJ_ENABLE | BG_ENABLE);
+
+            assert_eq!(2, pixel(&ppu, 8, 8));
+            assert_eq!(2, pixel(&ppu, 79, 8));
+            assert_eq!(0, pixel(&ppu, 80, 8));
+        }
+    }
+
+    #[test]
+    fn test_sprite_height_change() {
+        for accuracy in ACCURACIES {
+            let mut ppu = sprite_tiles(accuracy);
+            sprite(&mut ppu, 0, 16, 8, 5, 0x40);
+            ppu.write_register(BGP, 0xE4);
+            ppu.write_register(OBP0, 0xE4);
+            ppu.write_register(
+                LCDC,
+                LCD_ENABLE | TILE_DATA | OBJ_SIZE | OBJ_ENABLE | BG_ENABLE,
+            );
+
+            // The OAM scan picks the sprite up as 16 tall on line 8, then it's fetched as 8 tall
+            run(&mut ppu, 8 * DOTS_PER_LINE as u32 + 81);
+            assert_eq!(Mode::Drawing, ppu.mode());
+            ppu.write_register(LCDC, LCD_ENABLE | TILE_DATA | OBJ_ENABLE | BG_ENABLE);
+            run(&mut ppu, DOTS_PER_LINE as u32);
+
+            assert_eq!(1, pixel(&ppu, 0, 8));
+        }
+    }
+
+    #[test]
+    fn test_sprite_drawing_length() {
+        // Each sprite takes 6 dots, and the first on a background tile also waits for up to 5
+        // while the tile is fetched
+        let cases: [(&[u8], u16); 5] = [
+            (&[0], 11),
+            (&[8], 11),
+            (&[13], 6),
+            (&[8, 8], 17),
+            (&[168], 0),
+        ];
+        for accuracy in ACCURACIES {
+            for (xs, penalty) in cases {
+                let mut ppu = sprite_tiles(accuracy);
+                for (index, x) in xs.iter().enumerate() {
+                    sprite(&mut ppu, index, 16, *x, 4, 0x00);
+                }
+                ppu.write_register(LCDC, LCD_ENABLE | TILE_DATA | OBJ_ENABLE | BG_ENABLE);
+
+                run(&mut ppu, 80 + 172 + penalty as u32 - 1);
+                assert_eq!(Mode::Drawing, ppu.mode(), "{:?} {:?}", accuracy, xs);
+                run(&mut ppu, 1);
+                assert_eq!(Mode::HBlank, ppu.mode(), "{:?} {:?}", accuracy, xs);
+            }
+        }
+    }
+}
@@ -0,0 +1,237 @@
+use std::collections::VecDeque;
+
+use super::sprites::{self, Sprite, SpritePixel};
+use super::*;
+
+// Each step of the fetcher takes 2 dots, apart from pushing which is retried every dot until the
+// FIFO has room
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+enum FetcherStep {
+    #[default]
+    Tile,
+    DataLow,
+    DataHigh,
+    Push,
+}
+
+// The background FIFO and the fetcher that fills it a tile at a time, along with the sprite FIFO
+// that's mixed in as pixels come out. Pixels are shifted out one per dot while the background
+// FIFO isn't empty, so anything that stalls the fetcher makes drawing longer
+#[derive(Debug, Default)]
+pub(super) struct PixelFifo {
+    pixels: VecDeque<u8>,
+    step: FetcherStep,
+    // Whether the current step has used its first dot
+    step_started: bool,
+    // Which tile along the line the fetcher is on, counted from the left of the background or
+    // window
+    tile_x: u8,
+    tile: u8,
+    low: u8,
+    high: u8,
+    // The first tile of each line is fetched twice, the first time is thrown away
+    warmed_up: bool,
+    // Pixels still to be thrown away before drawing starts, for fine scrolling
+    discard: u8,
+    x: u8,
+    fetching_window: bool,
+    // Whether the window was drawn on this line, which moves its line counter on
+    window_drawn: bool,
+    sprite_pixels: VecDeque<SpritePixel>,
+    // The line's sprites in the order they're reached, and how many have been fetched
+    sprites: Vec<Sprite>,
+    fetched_sprites: usize,
+    // Dots left until the sprite being fetched is merged in, drawing is stalled until then
+    sprite_dots: u16,
+}
+
+impl Ppu {
+    pub(super) fn start_fifo_line(&mut self) {
+        let mut sprites = self.sprites.clone();
+        sprites.sort_by_key(|sprite| sprite.x);
+        self.fifo = PixelFifo {
+            pixels: VecDeque::with_capacity(16),
+            discard: self.scx & 0x07,
+            sprite_pixels: VecDeque::with_capacity(8),
+            sprites,
+            ..PixelFifo::default()
+        };
+    }
+
+    // Runs the fetcher and shifts out a pixel for one dot of drawing, returning whether the line
+    // is finished
+    pub(super) fn tick_fifo(&mut self) -> bool {
+        if self.fifo.sprite_dots == 0 {
+            self.check_window_start();
+            self.tick_fetcher();
+            self.start_sprite_fetch();
+        } else {
+            // The background fetcher carries on with its tile while the sprite is fetched
+            self.tick_fetcher();
+        }
+        if self.fifo.sprite_dots > 0 {
+            self.fifo.sprite_dots -= 1;
+            if self.fifo.sprite_dots == 0 {
+                self.merge_sprite();
+            }
+            return false;
+        }
+
+        if let Some(color) = self.fifo.pixels.pop_front() {
+            if self.fifo.discard > 0 {
+                self.fifo.discard -= 1;
+            } else {
+                // The enable bits and palettes are looked at as each pixel comes out
+                let color = match self.lcdc & BG_ENABLE {
+                    0 => 0,
+                    _ => color,
+                };
+                let sprite = self.fifo.sprite_pixels.pop_front();
+                let index = self.line as usize * SCREEN_WIDTH + self.fifo.x as usize;
+                self.frame[index] = self.mix(color, sprite);
+                self.fifo.x += 1;
+            }
+        }
+
+        if self.fifo.x as usize == SCREEN_WIDTH {
+            if self.fifo.window_drawn {
+                self.window_line += 1;
+            }
+            return true;
+        }
+        false
+    }
+
+    // The window takes over once the pixel it starts at is reached, throwing away what's in the
+    // FIFO and restarting the fetcher, which stalls drawing while the first window tile is
+    // fetched
+    fn check_window_start(&mut self) {
+        let window_enabled = self.lcdc & WINDOW_ENABLE != 0 && self.lcdc & BG_ENABLE != 0;
+        if self.fifo.fetching_window || !window_enabled || !self.window_triggered {
+            return;
+        }
+        if self.fifo.x as u16 + WINDOW_X_OFFSET as u16 != self.wx as u16
+            && !(self.fifo.x == 0 && self.wx < WINDOW_X_OFFSET)
+        {
+            return;
+        }
+
+        self.fifo.pixels.clear();
+        self.fifo.step = FetcherStep::Tile;
+        self.fifo.step_started = false;
+        self.fifo.tile_x = 0;
+        self.fifo.fetching_window = true;
+        self.fifo.window_drawn = true;
+        // With WX below 7 the window starts partly off the left edge
+        self.fifo.discard = WINDOW_X_OFFSET.saturating_sub(self.wx);
+    }
+
+    // Sprites are fetched once drawing reaches their left edge, or straight away for those hanging
+    // off the left of the screen, but only once there's background to mix them with
+    fn start_sprite_fetch(&mut self) {
+        if self.lcdc & OBJ_ENABLE == 0 || self.fifo.pixels.is_empty() || self.fifo.discard > 0 {
+            return;
+        }
+        match self.fifo.sprites.get(self.fifo.fetched_sprites) {
+            Some(sprite) if sprite.starts_at(self.fifo.x) => {
+                self.fifo.sprite_dots = sprites::sprite_fetch_dots(self.fetcher_dots());
+            }
+            _ => {}
+        }
+    }
+
+    // How many dots the background fetcher needs before it has a tile ready to push
+    fn fetcher_dots(&self) -> u16 {
+        let steps = match self.fifo.step {
+            FetcherStep::Tile => 3,
+            FetcherStep::DataLow => 2,
+            FetcherStep::DataHigh => 1,
+            FetcherStep::Push => 0,
+        };
+        match (steps, self.fifo.step_started) {
+            (0, _) => 0,
+            (steps, started) => steps * 2 - started as u16,
+        }
+    }
+
+    // Mixes a fetched sprite into the sprite FIFO, where it only fills pixels that aren't already
+    // taken by a sprite that wins over it
+    fn merge_sprite(&mut self) {
+        let sprite = self.fifo.sprites[self.fifo.fetched_sprites];
+        self.fifo.fetched_sprites += 1;
+
+        let pixels = self.sprite_pixels(&sprite);
+        let mut fifo = std::mem::take(&mut self.fifo.sprite_pixels);
+        for (i, pixel) in pixels[sprite.passed_pixels(self.fifo.x)..]
+            .iter()
+            .enumerate()
+        {
+            match fifo.get_mut(i) {
+                Some(old) if self.sprite_wins(pixel, old) => *old = *pixel,
+                Some(_) => {}
+                None => fifo.push_back(*pixel),
+            }
+        }
+        self.fifo.sprite_pixels = fifo;
+    }
+
+    fn tick_fetcher(&mut self) {
+        if self.fifo.step == FetcherStep::Push {
+            if self.fifo.pixels.is_empty() {
+                for bit in (0..8).rev() {
+                    let color = (self.fifo.high >> bit & 0x01) << 1 | (self.fifo.low >> bit & 0x01);
+                    self.fifo.pixels.push_back(color);
+                }
+                self.fifo.tile_x = self.fifo.tile_x.wrapping_add(1);
+                self.fifo.step = FetcherStep::Tile;
+            }
+            return;
+        }
+
+        // Everything is read on the second dot of each step
+        if !self.fifo.step_started {
+            self.fifo.step_started = true;
+            return;
+        }
+        self.fifo.step_started = false;
+
+        let (map_x, y, map) = match self.fifo.fetching_window {
+            true => (
+                self.fifo.tile_x,
+                self.window_line,
+                self.lcdc & WINDOW_TILE_MAP,
+            ),
+            false => (
+                (self.scx >> 3).wrapping_add(self.fifo.tile_x) & 0x1F,
+                self.line.wrapping_add(self.scy),
+                self.lcdc & BG_TILE_MAP,
+            ),
+        };
+        let map = match map {
+            0 => 0x9800,
+            _ => 0x9C00,
+        };
+
+        self.fifo.step = match self.fifo.step {
+            FetcherStep::Tile => {
+                self.fifo.tile = self.vram_byte(map + (y as Address / 8) * 32 + map_x as Address);
+                FetcherStep::DataLow
+            }
+            FetcherStep::DataLow => {
+                self.fifo.low = self.tile_row(self.fifo.tile, y % 8).0;
+                FetcherStep::DataHigh
+            }
+            FetcherStep::DataHigh => {
+                self.fifo.high = self.tile_row(self.fifo.tile, y % 8).1;
+                match self.fifo.warmed_up {
+                    true => FetcherStep::Push,
+                    false => {
+                        self.fifo.warmed_up = true;
+                        FetcherStep::Tile
+                    }
+                }
+            }
+            FetcherStep::Push => FetcherStep::Push,
+        };
+    }
+}
@@ -0,0 +1,121 @@
+use super::{SCREEN_HEIGHT, SCREEN_WIDTH};
+
+// Colours for the 4 shades from lightest to darkest, as RGBA
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub enum Palette {
+    // The green tint of the original DMG screen
+    #[default]
+    Green,
+    Grayscale,
+    // The Game Boy Pocket's greyer, higher contrast screen
+    Pocket,
+    Custom([[u8; 4]; 4]),
+}
+
+impl Palette {
+    pub fn from_name(name: &str) -> Option<Palette> {
+        match name.to_ascii_lowercase().as_str() {
+            "green" => Some(Palette::Green),
+            "grayscale" | "greyscale" => Some(Palette::Grayscale),
+            "pocket" => Some(Palette::Pocket),
+            _ => None,
+        }
+    }
+
+    pub fn colors(&self) -> [[u8; 4]; 4] {
+        match self {
+            Palette::Green => [
+                [0x9B, 0xBC, 0x0F, 0xFF],
+                [0x8B, 0xAC, 0x0F, 0xFF],
+                [0x30, 0x62, 0x30, 0xFF],
+                [0x0F, 0x38, 0x0F, 0xFF],
+            ],
+            Palette::Grayscale => [
+                [0xFF, 0xFF, 0xFF, 0xFF],
+                [0xAA, 0xAA, 0xAA, 0xFF],
+                [0x55, 0x55, 0x55, 0xFF],
+                [0x00, 0x00, 0x00, 0xFF],
+            ],
+            Palette::Pocket => [
+                [0xC4, 0xCF, 0xA1, 0xFF],
+                [0x8B, 0x95, 0x6D, 0xFF],
+                [0x4D, 0x53, 0x3C, 0xFF],
+                [0x1F, 0x1F, 0x1F, 0xFF],
+            ],
+            Palette::Custom(colors) => *colors,
+        }
+    }
+}
+
+// A finished frame as shade indices from 0 (lightest) to 3 (darkest), a row at a time from the
+// top left
+#[derive(Clone, Debug, PartialEq)]
+pub struct FrameBuffer {
+    pixels: Vec<u8>,
+}
+
+impl FrameBuffer {
+    pub(super) fn new(pixels: Vec<u8>) -> FrameBuffer {
+        debug_assert_eq!(SCREEN_WIDTH * SCREEN_HEIGHT, pixels.len());
+        FrameBuffer { pixels }
+    }
+
+    pub fn pixels(&self) -> &[u8] {
+        &self.pixels
+    }
+
+    pub fn pixel(&self, x: usize, y: usize) -> u8 {
+        self.pixels[y * SCREEN_WIDTH + x]
+    }
+
+    // The frame as 4 bytes of RGBA for each pixel, ready to upload as a texture
+    pub fn to_rgba(&self, palette: Palette) -> Vec<u8> {
+        let colors = palette.colors();
+        self.pixels
+            .iter()
+            .flat_map(|&shade| colors[shade as usize])
+            .collect()
+    }
+}
+
+impl Default for FrameBuffer {
+    fn default() -> FrameBuffer {
+        FrameBuffer::new(vec![0; SCREEN_WIDTH * SCREEN_HEIGHT])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_name() {
+        assert_eq!(Some(Palette::Green), Palette::from_name("green"));
+        assert_eq!(Some(Palette::Grayscale), Palette::from_name("Greyscale"));
+        assert_eq!(Some(Palette::Pocket), Palette::from_name("pocket"));
+        assert_eq!(None, Palette::from_name("cgb"));
+    }
+
+    #[test]
+    fn test_to_rgba() {
+        let mut pixels = vec![0; SCREEN_WIDTH * SCREEN_HEIGHT];
+        pixels[1] = 3;
+        pixels[SCREEN_WIDTH] = 2;
+        let frame = FrameBuffer::new(pixels);
+        assert_eq!(3, frame.pixel(1, 0));
+        assert_eq!(2, frame.pixel(0, 1));
+
+        let rgba = frame.to_rgba(Palette::Grayscale);
+        assert_eq!(SCREEN_WIDTH * SCREEN_HEIGHT * 4, rgba.len());
+        assert_eq!([0xFF, 0xFF, 0xFF, 0xFF], rgba[0..4]);
+        assert_eq!([0x00, 0x00, 0x00, 0xFF], rgba[4..8]);
+
+        let palette = Palette::Custom([[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12], [0; 4]]);
+        let rgba = frame.to_rgba(palette);
+        assert_eq!([1, 2, 3, 4], rgba[0..4]);
+        assert_eq!(
+            [9, 10, 11, 12],
+            rgba[SCREEN_WIDTH * 4..SCREEN_WIDTH * 4 + 4]
+        );
+    }
+}
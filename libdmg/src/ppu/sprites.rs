@@ -0,0 +1,210 @@
+use super::*;
+
+pub(super) const MAX_SPRITES_PER_LINE: usize = 10;
+const SPRITE_COUNT: usize = OAM_SIZE / 4;
+
+// Positions in OAM are offset so sprites can hang off the top and left of the screen
+const SPRITE_Y_OFFSET: u8 = 16;
+const SPRITE_X_OFFSET: u8 = 8;
+
+// Fetching a sprite stalls drawing for this long, after the background fetcher has finished the
+// tile it's on
+const SPRITE_FETCH_DOTS: u16 = 6;
+const MAX_SPRITE_WAIT_DOTS: u16 = 5;
+
+// Attribute bits
+const BG_OVER_OBJ: u8 = 0x80;
+const Y_FLIP: u8 = 0x40;
+const X_FLIP: u8 = 0x20;
+const DMG_PALETTE: u8 = 0x10;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub(super) struct Sprite {
+    pub y: u8,
+    pub x: u8,
+    pub tile: u8,
+    pub attributes: u8,
+    // Position in OAM, lower wins
+    pub index: u8,
+}
+
+// A sprite's pixel waiting to be mixed with the background
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub(super) struct SpritePixel {
+    pub color: u8,
+    pub attributes: u8,
+    pub index: u8,
+}
+
+impl Ppu {
+    pub(super) fn sprite_height(&self) -> u8 {
+        match self.lcdc & OBJ_SIZE {
+            0 => 8,
+            _ => 16,
+        }
+    }
+
+    // Picks the first 10 sprites in OAM that cover the current line. Sprites off the sides of the
+    // screen still count towards the limit
+    pub(super) fn scan_oam(&mut self) {
+        let height = self.sprite_height();
+        let line = self.line + SPRITE_Y_OFFSET;
+        self.sprites.clear();
+
+        for index in 0..SPRITE_COUNT {
+            let entry = &self.oam[index * 4..index * 4 + 4];
+            if (entry[0]..entry[0].saturating_add(height)).contains(&line) {
+                self.sprites.push(Sprite {
+                    y: entry[0],
+                    x: entry[1],
+                    tile: entry[2],
+                    attributes: entry[3],
+                    index: index as u8,
+                });
+                if self.sprites.len() == MAX_SPRITES_PER_LINE {
+                    break;
+                }
+            }
+        }
+    }
+
+    // The line's sprites from highest priority to lowest. The DMG favours the leftmost and then
+    // the first in OAM, the CGB only goes by OAM
+    pub(super) fn sprites_by_priority(&self) -> Vec<Sprite> {
+        let mut sprites = self.sprites.clone();
+        if !self.cgb_mode {
+            sprites.sort_by_key(|sprite| sprite.x);
+        }
+        sprites
+    }
+
+    // The pixels of a sprite on the current line from left to right, after flipping
+    pub(super) fn sprite_pixels(&self, sprite: &Sprite) -> [SpritePixel; 8] {
+        // The height can change between the OAM scan and the fetch, leaving the line past the
+        // bottom of the sprite, so only the bits that fit the current height are used
+        let height = self.sprite_height();
+        let mut row = (self.line + SPRITE_Y_OFFSET - sprite.y) & (height - 1);
+        if sprite.attributes & Y_FLIP != 0 {
+            row = height - 1 - row;
+        }
+        // Tall sprites ignore the bottom bit of the tile index and use the pair of tiles
+        let tile = match height {
+            16 => sprite.tile & 0xFE,
+            _ => sprite.tile,
+        };
+        // Sprites always use the tiles from 0x8000
+        let addr = 0x8000 + tile as Address * 16 + row as Address * 2;
+        let (mut low, mut high) = (self.vram_byte(addr), self.vram_byte(addr + 1));
+        if sprite.attributes & X_FLIP != 0 {
+            low = low.reverse_bits();
+            high = high.reverse_bits();
+        }
+
+        let mut pixels = [SpritePixel::default(); 8];
+        for (bit, pixel) in (0..8).rev().zip(pixels.iter_mut()) {
+            *pixel = SpritePixel {
+                color: (high >> bit & 0x01) << 1 | (low >> bit & 0x01),
+                attributes: sprite.attributes,
+                index: sprite.index,
+            };
+        }
+        pixels
+    }
+
+    // Whether a sprite pixel replaces one already there from another sprite, which only happens
+    // where the first is transparent, or on the CGB when the new one comes earlier in OAM
+    pub(super) fn sprite_wins(&self, new: &SpritePixel, old: &SpritePixel) -> bool {
+        old.color == 0 || (self.cgb_mode && new.color != 0 && new.index < old.index)
+    }
+
+    // The shade of a pixel given the background's colour index and the sprite's pixel there.
+    // Sprite colour 0 is transparent, and sprites with BG over OBJ set hide behind background
+    // colours 1-3
+    pub(super) fn mix(&self, bg_color: u8, sprite: Option<SpritePixel>) -> u8 {
+        match sprite {
+            Some(pixel)
+                if self.lcdc & OBJ_ENABLE != 0
+                    && pixel.color != 0
+                    && (pixel.attributes & BG_OVER_OBJ == 0 || bg_color == 0) =>
+            {
+                let palette = match pixel.attributes & DMG_PALETTE {
+                    0 => self.obp0,
+                    _ => self.obp1,
+                };
+                shade(palette, pixel.color)
+            }
+            _ => shade(self.bgp, bg_color),
+        }
+    }
+
+    // Roughly how much longer sprites make drawing for the scanline renderer. Each sprite takes 6
+    // dots to fetch, and the first on each background tile waits for the fetcher to finish that
+    // tile first, longer the further left it is
+    pub(super) fn sprite_penalty(&self) -> u16 {
+        if self.lcdc & OBJ_ENABLE == 0 {
+            return 0;
+        }
+
+        let mut penalty = 0;
+        let mut waited_tiles = Vec::with_capacity(MAX_SPRITES_PER_LINE);
+        for sprite in self.sprites_by_priority() {
+            if sprite.x as usize >= SCREEN_WIDTH + SPRITE_X_OFFSET as usize {
+                continue;
+            }
+            let pixel = sprite.x as u16 + (self.scx & 0x07) as u16;
+            let tile = pixel / 8;
+            if !waited_tiles.contains(&tile) {
+                waited_tiles.push(tile);
+                penalty += MAX_SPRITE_WAIT_DOTS.saturating_sub(pixel % 8);
+            }
+            penalty += SPRITE_FETCH_DOTS;
+        }
+        penalty
+    }
+
+    // Draws the sprites over a whole line of background colour indices for the scanline renderer
+    pub(super) fn render_sprites(&self, colors: &[u8; SCREEN_WIDTH], row: &mut [u8]) {
+        let mut line: [Option<SpritePixel>; SCREEN_WIDTH] = [None; SCREEN_WIDTH];
+        if self.lcdc & OBJ_ENABLE != 0 {
+            for sprite in self.sprites_by_priority() {
+                let left = sprite.x as isize - SPRITE_X_OFFSET as isize;
+                for (offset, pixel) in self.sprite_pixels(&sprite).into_iter().enumerate() {
+                    let x = left + offset as isize;
+                    if !(0..SCREEN_WIDTH as isize).contains(&x) {
+                        continue;
+                    }
+                    let slot = &mut line[x as usize];
+                    match slot {
+                        Some(old) if !self.sprite_wins(&pixel, old) => {}
+                        _ => *slot = Some(pixel),
+                    }
+                }
+            }
+        }
+
+        for (x, pixel) in row.iter_mut().enumerate() {
+            *pixel = self.mix(colors[x], line[x]);
+        }
+    }
+}
+
+impl Sprite {
+    // How many of the sprite's pixels are left of x, either already drawn or off the left edge
+    // of the screen
+    pub(super) fn passed_pixels(&self, x: u8) -> usize {
+        (x as usize + SPRITE_X_OFFSET as usize)
+            .saturating_sub(self.x as usize)
+            .min(8)
+    }
+
+    // Whether the sprite's fetch should start before the pixel at x is drawn
+    pub(super) fn starts_at(&self, x: u8) -> bool {
+        self.x as u16 <= x as u16 + SPRITE_X_OFFSET as u16
+    }
+}
+
+// How long a sprite fetch stalls the pixel FIFO for, given how many dots the background fetcher
+// still needs before it has a tile ready. Its last dot overlaps with the sprite fetch
+pub(super) fn sprite_fetch_dots(fetcher_dots: u16) -> u16 {
+    SPRITE_FETCH_DOTS + fetcher_dots.saturating_sub(1).min(MAX_SPRITE_WAIT_DOTS)
+}
@@ -1,3 +1,5 @@
+use super::model::Model;
+
 #[derive(Copy, Clone, Debug, Default, PartialEq)]
 struct FlagsRegister {
     zero: bool,
@@ -36,6 +38,18 @@ impl std::convert::From<u8> for FlagsRegister {
     }
 }
 
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub enum PowerMode {
+    #[default]
+    Running,
+    // Entered by HALT, wakes up as soon as an interrupt is pending
+    Halted,
+    // Entered by STOP, wakes up when a button is pressed
+    Stopped,
+    // Entered by running an illegal opcode, never wakes up
+    Locked,
+}
+
 pub enum Flag {
     Zero,
     Subtract,
@@ -55,9 +69,50 @@ pub struct Registers {
     flags: FlagsRegister,
     pub pc: u16,
     pub sp: u16,
+    pub ime: bool,
+    // Set by EI, IME is only enabled once the following instruction has finished
+    pub ime_scheduled: bool,
+    pub power_mode: PowerMode,
+    // Set by HALT when it fails to halt, the next opcode fetch doesn't increment PC
+    pub halt_bug: bool,
 }
 
 impl Registers {
+    // What the boot ROM leaves behind when it jumps to the cartridge at 0x0100. The DMG and MGB
+    // leave H and C set unless the header checksum is 0. CGB models running a DMG game leave values
+    // in B and HL that depend on the title, the common case is used here
+    pub fn post_boot(model: Model, cgb_game: bool, header_checksum: u8) -> Registers {
+        let checksum_flags = match header_checksum {
+            0x00 => 0x80,
+            _ => 0xB0,
+        };
+        let [a, f, b, c, d, e, h, l] = match model {
+            Model::Dmg0 => [0x01, 0x00, 0xFF, 0x13, 0x00, 0xC1, 0x84, 0x03],
+            Model::Dmg => [0x01, checksum_flags, 0x00, 0x13, 0x00, 0xD8, 0x01, 0x4D],
+            Model::Mgb => [0xFF, checksum_flags, 0x00, 0x13, 0x00, 0xD8, 0x01, 0x4D],
+            Model::Sgb => [0x01, 0x00, 0x00, 0x14, 0x00, 0x00, 0xC0, 0x60],
+            Model::Sgb2 => [0xFF, 0x00, 0x00, 0x14, 0x00, 0x00, 0xC0, 0x60],
+            Model::Cgb if cgb_game => [0x11, 0x80, 0x00, 0x00, 0xFF, 0x56, 0x00, 0x0D],
+            Model::Cgb => [0x11, 0x80, 0x00, 0x00, 0x00, 0x08, 0x00, 0x7C],
+            Model::Agb if cgb_game => [0x11, 0x00, 0x01, 0x00, 0xFF, 0x56, 0x00, 0x0D],
+            Model::Agb => [0x11, 0x00, 0x01, 0x00, 0x00, 0x08, 0x00, 0x7C],
+        };
+
+        Registers {
+            a,
+            b,
+            c,
+            d,
+            e,
+            h,
+            l,
+            flags: FlagsRegister::from(f),
+            pc: 0x0100,
+            sp: 0xFFFE,
+            ..Registers::default()
+        }
+    }
+
     pub fn get_reg8(&self, reg: Register) -> u8 {
         match reg {
             Register::A => self.a,
@@ -65,7 +120,7 @@ impl Registers {
             Register::C => self.c,
             Register::D => self.d,
             Register::E => self.e,
-            Register::F => u8::from(self.flags),
+            Register::F => self.get_flags(),
             Register::H => self.h,
             Register::L => self.l,
         }
@@ -73,6 +128,7 @@ impl Registers {
 
     pub fn get_reg16(&self, reg: RegisterPair) -> u16 {
         match reg {
+            RegisterPair::AF => (self.a as u16) << 8 | self.get_reg8(Register::F) as u16,
             RegisterPair::BC => (self.b as u16) << 8 | self.c as u16,
             RegisterPair::DE => (self.d as u16) << 8 | self.e as u16,
             RegisterPair::HL => (self.h as u16) << 8 | self.l as u16,
@@ -94,6 +150,10 @@ impl Registers {
 
     pub fn set_reg16(&mut self, reg: RegisterPair, value: u16) {
         match reg {
+            RegisterPair::AF => {
+                self.a = ((value & 0xFF00) >> 8) as u8;
+                self.set_flags((value & 0xFF) as u8);
+            }
             RegisterPair::BC => {
                 self.b = ((value & 0xFF00) >> 8) as u8;
                 self.c = (value & 0xFF) as u8;
@@ -127,7 +187,7 @@ impl Registers {
         }
     }
 
-    pub fn get_flags(&mut self) -> u8 {
+    pub fn get_flags(&self) -> u8 {
         u8::from(self.flags)
     }
 
@@ -150,6 +210,7 @@ pub enum Register {
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum RegisterPair {
+    AF,
     BC,
     DE,
     HL,
@@ -179,6 +240,10 @@ mod tests {
             },
             pc: 0x0000,
             sp: 0x0000,
+            ime: false,
+            ime_scheduled: false,
+            power_mode: PowerMode::Running,
+            halt_bug: false,
         };
 
         let result = reg.get_reg8(Register::A);
@@ -208,6 +273,10 @@ mod tests {
             },
             pc: 0x0000,
             sp: 0x0000,
+            ime: false,
+            ime_scheduled: false,
+            power_mode: PowerMode::Running,
+            halt_bug: false,
         };
 
         let result = reg.get_reg16(RegisterPair::BC);
@@ -235,6 +304,10 @@ mod tests {
             },
             pc: 0x0000,
             sp: 0x0000,
+            ime: false,
+            ime_scheduled: false,
+            power_mode: PowerMode::Running,
+            halt_bug: false,
         };
 
         reg.set_reg8(Register::A, expected);
@@ -265,6 +338,10 @@ mod tests {
             },
             pc: 0x0000,
             sp: 0x0000,
+            ime: false,
+            ime_scheduled: false,
+            power_mode: PowerMode::Running,
+            halt_bug: false,
         };
 
         reg.set_reg16(RegisterPair::BC, expected);
@@ -293,6 +370,10 @@ mod tests {
             },
             pc: 0x0000,
             sp: 0x0000,
+            ime: false,
+            ime_scheduled: false,
+            power_mode: PowerMode::Running,
+            halt_bug: false,
         };
 
         let result = reg.get_flag(Flag::Zero);
@@ -318,6 +399,10 @@ mod tests {
             },
             pc: 0x0000,
             sp: 0x0000,
+            ime: false,
+            ime_scheduled: false,
+            power_mode: PowerMode::Running,
+            halt_bug: false,
         };
 
         let result = reg.get_flag(Flag::Subtract);
@@ -343,6 +428,10 @@ mod tests {
             },
             pc: 0x0000,
             sp: 0x0000,
+            ime: false,
+            ime_scheduled: false,
+            power_mode: PowerMode::Running,
+            halt_bug: false,
         };
 
         let result = reg.get_flag(Flag::HalfCarry);
@@ -368,6 +457,10 @@ mod tests {
             },
             pc: 0x0000,
             sp: 0x0000,
+            ime: false,
+            ime_scheduled: false,
+            power_mode: PowerMode::Running,
+            halt_bug: false,
         };
 
         let result = reg.get_flag(Flag::Carry);
@@ -393,6 +486,10 @@ mod tests {
             },
             pc: 0x0000,
             sp: 0x0000,
+            ime: false,
+            ime_scheduled: false,
+            power_mode: PowerMode::Running,
+            halt_bug: false,
         };
 
         reg.set_flag(Flag::Zero, true);
@@ -418,6 +515,10 @@ mod tests {
             },
             pc: 0x0000,
             sp: 0x0000,
+            ime: false,
+            ime_scheduled: false,
+            power_mode: PowerMode::Running,
+            halt_bug: false,
         };
 
         reg.set_flag(Flag::Subtract, true);
@@ -443,6 +544,10 @@ mod tests {
             },
             pc: 0x0000,
             sp: 0x0000,
+            ime: false,
+            ime_scheduled: false,
+            power_mode: PowerMode::Running,
+            halt_bug: false,
         };
 
         reg.set_flag(Flag::HalfCarry, true);
@@ -468,6 +573,10 @@ mod tests {
             },
             pc: 0x0000,
             sp: 0x0000,
+            ime: false,
+            ime_scheduled: false,
+            power_mode: PowerMode::Running,
+            halt_bug: false,
         };
 
         reg.set_flag(Flag::Carry, true);
@@ -505,7 +614,7 @@ mod tests {
     #[test]
     fn test_get_flags() {
         let expected: u8 = 0b11000000;
-        let mut reg: Registers = Registers {
+        let reg: Registers = Registers {
             a: 0,
             b: 0,
             c: 0,
@@ -521,6 +630,10 @@ mod tests {
             },
             pc: 0x0000,
             sp: 0x0000,
+            ime: false,
+            ime_scheduled: false,
+            power_mode: PowerMode::Running,
+            halt_bug: false,
         };
 
         let result = reg.get_flags();
@@ -547,6 +660,10 @@ mod tests {
             },
             pc: 0x0000,
             sp: 0x0000,
+            ime: false,
+            ime_scheduled: false,
+            power_mode: PowerMode::Running,
+            halt_bug: false,
         };
 
         reg.set_flags(test);
@@ -556,4 +673,28 @@ mod tests {
         assert!(!reg.flags.half_carry);
         assert!(!reg.flags.carry);
     }
+
+    #[test]
+    fn test_post_boot() {
+        let reg = Registers::post_boot(Model::Dmg, false, 0x42);
+        assert_eq!(0x01B0, reg.get_reg16(RegisterPair::AF));
+        assert_eq!(0x0013, reg.get_reg16(RegisterPair::BC));
+        assert_eq!(0x00D8, reg.get_reg16(RegisterPair::DE));
+        assert_eq!(0x014D, reg.get_reg16(RegisterPair::HL));
+        assert_eq!(0x0100, reg.pc);
+        assert_eq!(0xFFFE, reg.sp);
+
+        // Only the DMG and MGB look at the header checksum
+        let reg = Registers::post_boot(Model::Mgb, false, 0x00);
+        assert_eq!(0xFF80, reg.get_reg16(RegisterPair::AF));
+        let reg = Registers::post_boot(Model::Sgb, false, 0x42);
+        assert_eq!(0x0100, reg.get_reg16(RegisterPair::AF));
+
+        let reg = Registers::post_boot(Model::Cgb, true, 0x42);
+        assert_eq!(0x1180, reg.get_reg16(RegisterPair::AF));
+        assert_eq!(0xFF56, reg.get_reg16(RegisterPair::DE));
+        let reg = Registers::post_boot(Model::Agb, false, 0x42);
+        assert_eq!(0x0100, reg.get_reg16(RegisterPair::BC));
+        assert_eq!(0x007C, reg.get_reg16(RegisterPair::HL));
+    }
 }